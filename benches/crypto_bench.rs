@@ -21,7 +21,7 @@ fn bench_encrypt_decrypt(c: &mut Criterion) {
     for size in sizes {
         let payload = generate_payload(size);
         let identity = x25519::Identity::generate();
-        let recipients = vec![identity.to_public()];
+        let recipients = vec![identity.to_public().into()];
 
         group.throughput(Throughput::Bytes(size as u64));
 
@@ -58,7 +58,7 @@ fn bench_encrypt(c: &mut Criterion) {
     for size in sizes {
         let payload = generate_payload(size);
         let identity = x25519::Identity::generate();
-        let recipients = vec![identity.to_public()];
+        let recipients = vec![identity.to_public().into()];
 
         group.throughput(Throughput::Bytes(size as u64));
 
@@ -89,7 +89,7 @@ fn bench_decrypt(c: &mut Criterion) {
     let cipher = Age;
     let sizes = [32, 256, 1024, 4096, 16384];
     let identity = x25519::Identity::generate();
-    let recipients = vec![identity.to_public()];
+    let recipients = vec![identity.to_public().into()];
 
     for size in sizes {
         let payload = generate_payload(size);
@@ -127,7 +127,7 @@ fn bench_recipient_scaling(c: &mut Criterion) {
 
     for count in recipient_counts {
         let recipients: Vec<_> = (0..count)
-            .map(|_| x25519::Identity::generate().to_public())
+            .map(|_| x25519::Identity::generate().to_public().into())
             .collect();
 
         group.bench_with_input(
@@ -0,0 +1,83 @@
+use age::x25519;
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use dugout::bench::{Age, Cipher};
+use dugout::Vault;
+use std::time::Duration;
+
+const SECRET_COUNTS: [usize; 3] = [20, 100, 250];
+
+/// Secret pairs with realistic short values.
+fn generate_pairs(count: usize) -> Vec<(String, String)> {
+    (0..count)
+        .map(|i| (format!("SECRET_{:04}", i), format!("value-{:032}", i)))
+        .collect()
+}
+
+/// Baseline: decrypt and re-encrypt every secret one after another.
+fn bench_reencrypt_serial(c: &mut Criterion) {
+    let mut group = c.benchmark_group("reencrypt_all");
+    group.sample_size(10);
+    group.warm_up_time(Duration::from_secs(1));
+    group.measurement_time(Duration::from_secs(5));
+
+    let cipher = Age;
+    let identity = x25519::Identity::generate();
+    let recipients = vec![
+        identity.to_public().into(),
+        x25519::Identity::generate().to_public().into(),
+    ];
+
+    for count in SECRET_COUNTS {
+        let ciphertexts: Vec<String> = generate_pairs(count)
+            .iter()
+            .map(|(_, value)| cipher.encrypt(value, &recipients[..1]).unwrap())
+            .collect();
+
+        group.bench_with_input(
+            BenchmarkId::new("serial", count),
+            &ciphertexts,
+            |b, ciphertexts| {
+                b.iter(|| {
+                    for ciphertext in ciphertexts {
+                        let plaintext = cipher.decrypt(black_box(ciphertext), &identity).unwrap();
+                        black_box(cipher.encrypt(&plaintext, &recipients).unwrap());
+                    }
+                });
+            },
+        );
+    }
+
+    group.finish();
+}
+
+/// `Vault::reencrypt_all`, which spreads large vaults across threads.
+fn bench_reencrypt_vault(c: &mut Criterion) {
+    let mut group = c.benchmark_group("reencrypt_all");
+    group.sample_size(10);
+    group.warm_up_time(Duration::from_secs(1));
+    group.measurement_time(Duration::from_secs(5));
+
+    // Keep keys and the vault file out of the real home and working directory
+    let home = tempfile::TempDir::new().unwrap();
+    std::env::set_var("DUGOUT_HOME", home.path());
+    std::env::set_var("DUGOUT_NO_KEYCHAIN", "1");
+
+    for count in SECRET_COUNTS {
+        let dir = tempfile::TempDir::new().unwrap();
+        std::env::set_current_dir(dir.path()).unwrap();
+
+        let mut vault = Vault::init("bench", None).unwrap();
+        vault.set_many(&generate_pairs(count), false).unwrap();
+        let bob = x25519::Identity::generate().to_public().to_string();
+        vault.add_recipient("bob", &bob).unwrap();
+
+        group.bench_function(BenchmarkId::new("vault", count), |b| {
+            b.iter(|| vault.reencrypt_all().unwrap());
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_reencrypt_serial, bench_reencrypt_vault);
+criterion_main!(benches);
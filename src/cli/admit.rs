@@ -1,14 +1,114 @@
 //! Admit command - approve an access request.
 
-use crate::cli::output;
+use std::io::{self, IsTerminal};
+
+use dialoguer::Confirm;
+
+use crate::cli::{output, progress};
+use crate::core::domain::{AccessRequest, Recipient};
 use crate::core::vault::Vault;
-use crate::error::Result;
+use crate::error::{ConfigError, Result};
 
 /// Admit a team member from a pending request.
-pub fn execute(name: &str, vault: Option<String>) -> Result<()> {
+///
+/// Shows the request's key fingerprint and asks for confirmation unless
+/// `yes`. Non-admins get a warning, or an error with `strict`. A request
+/// with a bad signature is refused; with `verify`, so is an unsigned one.
+pub fn execute(
+    name: &str,
+    strict: bool,
+    verify: bool,
+    yes: bool,
+    vault: Option<String>,
+) -> Result<()> {
     let vault_name = crate::cli::resolve::resolve_vault(vault.as_deref())?;
     let mut v = Vault::open_vault(vault_name.as_deref())?;
-    v.admit(name)?;
+    progress::attach(&mut v);
+    check_admin(&v, strict)?;
+
+    let request = v.pending_request(name)?;
+    show(&request, verify)?;
+    confirm(&format!("Admit {}?", name), yes)?;
+
+    v.admit(name, verify)?;
     output::success(&format!("admitted {}", name));
     Ok(())
 }
+
+/// Admit everyone with a pending request.
+pub fn execute_all(strict: bool, verify: bool, yes: bool, vault: Option<String>) -> Result<()> {
+    let vault_name = crate::cli::resolve::resolve_vault(vault.as_deref())?;
+    let mut v = Vault::open_vault(vault_name.as_deref())?;
+    progress::attach(&mut v);
+    check_admin(&v, strict)?;
+
+    let requests = v.pending_requests()?;
+    if requests.is_empty() {
+        output::data("no pending requests");
+        return Ok(());
+    }
+    for request in &requests {
+        show(request, verify)?;
+    }
+    confirm(&format!("Admit all {}?", requests.len()), yes)?;
+
+    let admitted = v.admit_all(verify)?;
+    output::success(&format!(
+        "admitted {} ({})",
+        admitted.len(),
+        admitted.join(", ")
+    ));
+    Ok(())
+}
+
+/// Check a request and print its fingerprint.
+fn show(request: &AccessRequest, verify: bool) -> Result<()> {
+    request.verify(verify)?;
+    let recipient = Recipient::new(request.name().to_string(), request.public_key().to_string())?;
+    let status = if request.is_signed() {
+        "signed"
+    } else {
+        "unsigned"
+    };
+    output::kv(
+        request.name(),
+        format!("{} ({})", recipient.fingerprint(), status),
+    );
+    Ok(())
+}
+
+/// Warn when a non-admin admits, or refuse with `strict`.
+fn check_admin(v: &Vault, strict: bool) -> Result<()> {
+    if v.can_admit() {
+        return Ok(());
+    }
+    let actor = v.current_member().unwrap_or("you").to_string();
+    if strict {
+        return Err(ConfigError::NotAdmin(actor).into());
+    }
+    output::warn(&format!(
+        "{} is not an admin of this vault; admitting anyway",
+        actor
+    ));
+    Ok(())
+}
+
+/// Ask before admitting, unless `yes`.
+///
+/// Without a terminal there's no one to check the fingerprint, so `yes` is required.
+fn confirm(prompt: &str, yes: bool) -> Result<()> {
+    if yes {
+        return Ok(());
+    }
+    if !io::stdin().is_terminal() {
+        return Err(ConfigError::AdmitUnconfirmed.into());
+    }
+    if !Confirm::new()
+        .with_prompt(prompt)
+        .default(false)
+        .interact()?
+    {
+        return Err(ConfigError::AdmitUnconfirmed.into());
+    }
+    Ok(())
+}
@@ -0,0 +1,31 @@
+//! Access command - check every recipient can decrypt every secret.
+
+use crate::cli::output;
+use crate::core::vault::Vault;
+use crate::error::{Error, Result};
+
+/// Report recipients that secrets were not encrypted for.
+pub fn execute(vault: Option<String>) -> Result<()> {
+    let vault_name = crate::cli::resolve::resolve_vault(vault.as_deref())?;
+    let v = Vault::open_vault(vault_name.as_deref())?;
+    let missing = v.verify_recipients()?;
+
+    if missing.is_empty() {
+        output::success(&format!(
+            "{} recipients can decrypt all {} secrets",
+            v.config().recipients.len(),
+            v.config().secrets.len()
+        ));
+        return Ok(());
+    }
+
+    for (member, secrets) in &missing {
+        output::list_item(&format!("{}: {}", member, secrets.join(", ")));
+    }
+    output::hint("run: dugout sync --force");
+    Err(Error::Other(format!(
+        "{} of {} recipients can't decrypt every secret",
+        missing.len(),
+        v.config().recipients.len()
+    )))
+}
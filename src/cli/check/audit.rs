@@ -1,8 +1,10 @@
-//! Audit command - scan git history for leaked secrets.
+//! Audit command - scan the vault and git history for problems.
 
 use crate::cli::output;
+use crate::core::config::Config;
 use crate::core::domain::audit;
-use crate::error::Result;
+use crate::core::vault::Vault;
+use crate::error::{Error, Result};
 use std::process::{Command, Stdio};
 
 /// Check if we're in a git repository.
@@ -16,15 +18,60 @@ fn is_git_repo() -> bool {
         .unwrap_or(false)
 }
 
-/// Scan git history for leaked secrets.
-pub fn execute() -> Result<()> {
+/// Load the current vault's config, if there is a (single) vault.
+///
+/// Returns the config, its path, and the vault name.
+fn load_config(vault: Option<String>) -> Option<(Config, String, Option<String>)> {
+    let vault_name = crate::cli::resolve::resolve_vault(vault.as_deref()).ok()?;
+    let config = Config::load_from(vault_name.as_deref()).ok()?;
+    let path = Config::config_path_for(vault_name.as_deref());
+    Some((config, path.display().to_string(), vault_name))
+}
+
+/// Scan the vault, working tree, and git history for leaked secrets.
+///
+/// Leak rules are the built-in credential shapes plus any `[audit.rules]`
+/// from the vault config. With `staged`, only the changes staged for the
+/// next commit are scanned, and high-severity findings make the command
+/// fail so a pre-commit hook blocks the commit.
+pub fn execute(staged: bool, vault: Option<String>) -> Result<()> {
+    let config = load_config(vault);
+
+    // Check the vault file for mixed ciphertext formats
+    if let Some((config, path, vault_name)) = &config {
+        let ciphertexts = config.secrets.values().map(String::as_str);
+        if let Some(finding) = audit::scan_ciphertext_formats(path, ciphertexts) {
+            output::warn(&format!("{}", finding));
+            output::hint("run: dugout sync --force");
+        }
+        for finding in audit::scan_case_duplicates(path, config.secrets.keys().map(String::as_str))
+        {
+            output::warn(&format!("{}", finding));
+            output::hint("run: dugout secrets dedup");
+        }
+        check_coverage(config, path, vault_name.as_deref());
+    }
+
     // Check if we're in a git repository
     if !is_git_repo() {
         output::warn("not a git repository");
         return Ok(());
     }
 
-    let findings = audit::scan_git_history()?;
+    let custom = config
+        .and_then(|(config, _, _)| config.audit)
+        .map(|audit| audit.rules)
+        .unwrap_or_default();
+    let rules = audit::Rule::load(&custom)?;
+
+    if staged {
+        return check_staged(&rules);
+    }
+
+    let mut findings = audit::scan_working_tree(&rules)?;
+    findings.extend(audit::scan_recent_history(&rules)?);
+    findings.extend(audit::scan_git_history()?);
+    findings.sort_by(|a, b| b.severity.cmp(&a.severity));
 
     if findings.is_empty() {
         output::success("no issues found");
@@ -48,3 +95,52 @@ pub fn execute() -> Result<()> {
 
     Ok(())
 }
+
+/// Report members some secrets aren't encrypted for.
+///
+/// Needs an identity that can open the vault; skipped otherwise.
+fn check_coverage(config: &Config, path: &str, vault_name: Option<&str>) {
+    let Ok(v) = Vault::open_vault(vault_name) else {
+        return;
+    };
+    let Ok(coverage) = v.recipient_coverage() else {
+        return;
+    };
+    let members: Vec<String> = config.recipients.keys().cloned().collect();
+    let findings = audit::scan_recipient_coverage(path, &members, &coverage);
+    for finding in &findings {
+        output::warn(&format!("{}", finding));
+    }
+    if !findings.is_empty() {
+        output::hint("run: dugout sync --force");
+    }
+}
+
+/// Scan staged changes, failing if any high-severity finding is present.
+fn check_staged(rules: &[audit::Rule]) -> Result<()> {
+    let findings = audit::scan_staged(rules)?;
+    if findings.is_empty() {
+        output::success("no issues in staged changes");
+        return Ok(());
+    }
+
+    for finding in &findings {
+        output::list_item(&format!("{}", finding));
+    }
+
+    let high = findings
+        .iter()
+        .filter(|f| f.severity == audit::Severity::High)
+        .count();
+    if high == 0 {
+        output::warn(&format!(
+            "{} potential issues in staged changes",
+            findings.len()
+        ));
+        return Ok(());
+    }
+    Err(Error::Other(format!(
+        "{} high-severity findings in staged changes\n  → Unstage or remove them before committing",
+        high
+    )))
+}
@@ -1,10 +1,12 @@
 //! Diagnostic and check commands.
 //!
-//! Status overview and git history auditing.
+//! Status overview, recipient access, and git history auditing.
 
+mod access;
 mod audit;
 mod status;
 
 // Re-export command functions
+pub use access::execute as access;
 pub use audit::execute as audit;
 pub use status::execute as status;
@@ -1,23 +1,45 @@
 //! Status command - show quick status overview.
 
+use std::path::Path;
+
 use crate::cli::output;
+use crate::core::constants;
+use crate::core::domain::{audit, StoredFormat};
 use crate::core::vault::Vault;
 use crate::error::Result;
 
 /// Show quick status overview.
-pub fn execute(vault: Option<String>) -> Result<()> {
+///
+/// With `json`, prints a machine-readable summary instead.
+pub fn execute(json: bool, vault: Option<String>) -> Result<()> {
     let vault_name = crate::cli::resolve::resolve_vault(vault.as_deref())?;
     let v = Vault::open_vault(vault_name.as_deref())?;
 
+    if json {
+        let result = serde_json::json!({
+            "schema_version": crate::cli::schema::SCHEMA_VERSION,
+            "vault": vault_name.as_deref().unwrap_or("default"),
+            "cipher": v.backend_name(),
+            "secret_count": v.config().secrets.len(),
+            "recipient_count": v.config().recipients.len(),
+            "needs_sync": v.needs_sync(),
+            "has_env": Path::new(constants::ENV_FILE).exists(),
+        });
+        println!("{}", serde_json::to_string_pretty(&result)?);
+        return Ok(());
+    }
+
     // Project name
     let vault_display = vault_name
         .as_ref()
         .map(|n| format!(".dugout.{}.toml", n))
         .unwrap_or_else(|| ".dugout.toml".to_string());
-    output::kv("vault", vault_display);
+    output::kv("vault", &vault_display);
 
     // Cipher backend
-    let backend_name = if v.config().has_kms() {
+    let backend_name = if v.config().transit().is_some() {
+        "hybrid (age + vault transit)"
+    } else if v.config().has_kms() {
         "hybrid (age + kms)"
     } else {
         "age"
@@ -25,8 +47,66 @@ pub fn execute(vault: Option<String>) -> Result<()> {
     output::kv("cipher", backend_name);
 
     // Secret count
-    let secret_count = v.list().len();
-    output::kv("secrets", secret_count);
+    let secrets = v.list();
+    output::kv("secrets", secrets.len());
+
+    // Hybrid envelopes by KMS provider, read from the stored ciphertext
+    let mut providers: std::collections::BTreeMap<String, usize> = Default::default();
+    let mut envelopes = 0;
+    for secret in &secrets {
+        if let StoredFormat::HybridEnvelope(provider) = secret.stored_format() {
+            envelopes += 1;
+            let name = provider.unwrap_or_else(|| "age only".to_string());
+            *providers.entry(name).or_default() += 1;
+        }
+    }
+    if envelopes > 0 {
+        let breakdown: Vec<String> = providers
+            .iter()
+            .map(|(name, count)| format!("{}: {}", name, count))
+            .collect();
+        output::kv(
+            "envelopes",
+            format!("{} ({})", envelopes, breakdown.join(", ")),
+        );
+    }
+
+    if let Some(finding) =
+        audit::scan_ciphertext_formats(&vault_display, secrets.iter().map(|s| s.encrypted()))
+    {
+        output::warn(&finding.pattern);
+        output::hint("run: dugout sync --force");
+    }
+
+    let keys = secrets.iter().map(|s| s.key());
+    for finding in audit::scan_case_duplicates(&vault_display, keys) {
+        output::warn(&finding.pattern);
+        output::hint("run: dugout secrets dedup");
+    }
+
+    if v.recipients_hash_missing() {
+        output::warn("recipients_hash is missing, so sync status can't be trusted");
+        output::hint("run: dugout secrets fix-hash");
+    }
+
+    if let Some((synced, configured)) = v.backend_mismatch() {
+        output::warn(&format!(
+            "cipher changed from {} to {} since the last sync",
+            synced, configured
+        ));
+        output::hint("run: dugout sync --force");
+    }
+
+    // Age of the most and least recently changed secrets
+    let mut timestamps: Vec<_> = secrets
+        .iter()
+        .filter_map(|s| v.updated_at(s.key()))
+        .collect();
+    timestamps.sort();
+    if let (Some(oldest), Some(newest)) = (timestamps.first(), timestamps.last()) {
+        output::kv("last change", output::age(*newest));
+        output::kv("oldest", output::age(*oldest));
+    }
 
     // Team member count
     let team_count = v.recipients().len();
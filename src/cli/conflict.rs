@@ -0,0 +1,86 @@
+//! Resolve command - merge a conflicted vault file.
+
+use std::io::{self, IsTerminal};
+
+use dialoguer::Select;
+
+use crate::cli::output;
+use crate::core::constants;
+use crate::core::domain::Side;
+use crate::core::vault::Vault;
+use crate::error::{Error, Result};
+
+/// Resolve git merge conflicts in the vault file.
+///
+/// Keys changed to different values on both sides, or changed on one side
+/// and removed on the other, are taken from `--ours` or `--theirs` when
+/// given, otherwise prompted for interactively.
+pub fn execute(ours: bool, theirs: bool, vault: Option<String>) -> Result<()> {
+    let vault_name = crate::cli::resolve::resolve_vault(vault.as_deref())?;
+
+    let resolution = Vault::resolve_conflicts(vault_name.as_deref(), |key| {
+        if ours {
+            return Ok(Side::Ours);
+        }
+        if theirs {
+            return Ok(Side::Theirs);
+        }
+        if !io::stdin().is_terminal() {
+            return Err(Error::Other(format!(
+                "{} differs between the two sides; pass --ours or --theirs",
+                key
+            )));
+        }
+        let choice = Select::new()
+            .with_prompt(format!("{} differs between the two sides", key))
+            .items(&["keep ours", "keep theirs"])
+            .default(0)
+            .interact()?;
+        Ok(if choice == 0 {
+            Side::Ours
+        } else {
+            Side::Theirs
+        })
+    })?;
+
+    for key in &resolution.added {
+        output::list_item(&format!("+ {}", key));
+    }
+    for key in &resolution.removed {
+        output::list_item(&format!("- {} (removed on one side)", key));
+    }
+    for key in &resolution.identical {
+        output::list_item(&format!("= {} (same value on both sides)", key));
+    }
+    for (key, side) in &resolution.chosen {
+        let side = match side {
+            Side::Ours => "ours",
+            Side::Theirs => "theirs",
+        };
+        output::list_item(&format!("~ {} (kept {})", key, side));
+    }
+    for name in &resolution.removed_recipients {
+        output::list_item(&format!("- {} (recipient removed on one side)", name));
+    }
+    if !resolution.unverified.is_empty() {
+        output::warn(&format!(
+            "kept {} (only on one side, and there's no merge base to show whether the other side removed them)",
+            resolution.unverified.join(", ")
+        ));
+        output::hint(
+            "re-run the merge with `git config merge.conflictStyle diff3` to detect removals",
+        );
+    }
+    if resolution.reencrypted {
+        output::hint("re-encrypted secrets for the merged recipients");
+    }
+    output::success("resolved merge conflicts");
+    let mut paths = vec![constants::vault_path(vault_name.as_deref())];
+    let sidecar = constants::sidecar_path(vault_name.as_deref());
+    if sidecar.exists() {
+        paths.push(sidecar);
+    }
+    let paths: Vec<String> = paths.iter().map(|p| p.display().to_string()).collect();
+    output::hint(&format!("run: git add {}", paths.join(" ")));
+    Ok(())
+}
@@ -0,0 +1,61 @@
+//! Encrypt and decrypt commands - standalone age utilities.
+//!
+//! Work without a vault: `encrypt` targets explicit recipients, `decrypt`
+//! uses the current identity (`DUGOUT_IDENTITY`/`DUGOUT_IDENTITY_FILE`, then
+//! the global identity).
+
+use std::io::{self, Read};
+
+use zeroize::Zeroizing;
+
+use crate::cli::output;
+use crate::core::cipher::{self, Age, Cipher};
+use crate::core::domain::Identity;
+use crate::core::store;
+use crate::error::{Error, Result, StoreError};
+
+/// Encrypt a value (or stdin) to one or more age recipients.
+pub fn encrypt(recipients: &[String], value: Option<String>) -> Result<()> {
+    let recipients = recipients
+        .iter()
+        .map(|r| cipher::parse_recipient(r))
+        .collect::<Result<Vec<_>>>()?;
+
+    let plaintext = match value {
+        Some(value) if value != "-" => Zeroizing::new(value),
+        _ => read_stdin()?,
+    };
+
+    let ciphertext = Age.encrypt(&plaintext, &recipients)?;
+    output::raw(&ciphertext);
+    Ok(())
+}
+
+/// Decrypt age ciphertext from stdin (or a file) with the current identity.
+pub fn decrypt(input: Option<String>) -> Result<()> {
+    let ciphertext = match input.as_deref() {
+        None | Some("-") => read_stdin()?,
+        Some(path) => Zeroizing::new(std::fs::read_to_string(path)?),
+    };
+
+    // A set but unusable DUGOUT_IDENTITY is reported rather than skipped
+    let identity = match Identity::try_from_env()? {
+        Some(identity) => identity,
+        None => store::load_global_identity().map_err(|e| match e {
+            Error::Store(StoreError::NoPrivateKey(_)) => Error::Other(
+                "no identity found: set DUGOUT_IDENTITY or run `dugout setup`".to_string(),
+            ),
+            e => e,
+        })?,
+    };
+
+    let plaintext = Zeroizing::new(Age.decrypt(&ciphertext, identity.as_age())?);
+    output::raw(&plaintext);
+    Ok(())
+}
+
+fn read_stdin() -> Result<Zeroizing<String>> {
+    let mut input = Zeroizing::new(String::new());
+    io::stdin().read_to_string(&mut input)?;
+    Ok(input)
+}
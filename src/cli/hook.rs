@@ -0,0 +1,73 @@
+//! Shell hook command.
+//!
+//! Prints a shell function that loads a project's secrets on `cd` and
+//! unloads them again on leaving. Loading only happens in projects that
+//! opted in with an autoload marker.
+
+use crate::cli::{output, HookShell};
+use crate::core::constants::AUTOLOAD_MARKER;
+use crate::error::Result;
+
+const BASH_HOOK: &str = r#"_dugout_hook() {
+  if [ -n "${_DUGOUT_DIR:-}" ]; then
+    case "$PWD/" in
+      "$_DUGOUT_DIR"/*) return ;;
+    esac
+    for _dugout_key in ${_DUGOUT_KEYS:-}; do unset "$_dugout_key"; done
+    unset _dugout_key _DUGOUT_KEYS _DUGOUT_DIR
+  fi
+  if [ -f .dugout.toml ] && [ -f {marker} ]; then
+    eval "$(command dugout env --export --shell bash 2>/dev/null)"
+    _DUGOUT_DIR="$PWD"
+  fi
+}
+if [[ ";${PROMPT_COMMAND:-};" != *";_dugout_hook;"* ]]; then
+  PROMPT_COMMAND="_dugout_hook${PROMPT_COMMAND:+;$PROMPT_COMMAND}"
+fi
+"#;
+
+const ZSH_HOOK: &str = r#"_dugout_hook() {
+  if [[ -n "${_DUGOUT_DIR:-}" ]]; then
+    [[ "$PWD/" == "$_DUGOUT_DIR"/* ]] && return
+    for _dugout_key in ${=_DUGOUT_KEYS:-}; do unset "$_dugout_key"; done
+    unset _dugout_key _DUGOUT_KEYS _DUGOUT_DIR
+  fi
+  if [[ -f .dugout.toml && -f {marker} ]]; then
+    eval "$(command dugout env --export --shell zsh 2>/dev/null)"
+    _DUGOUT_DIR="$PWD"
+  fi
+}
+autoload -Uz add-zsh-hook
+add-zsh-hook chpwd _dugout_hook
+_dugout_hook
+"#;
+
+const FISH_HOOK: &str = r#"function _dugout_hook --on-variable PWD
+    if set -q _DUGOUT_DIR
+        if string match -q -- "$_DUGOUT_DIR/*" "$PWD/"
+            return
+        end
+        for key in $_DUGOUT_KEYS
+            set -e $key
+        end
+        set -e _DUGOUT_KEYS
+        set -e _DUGOUT_DIR
+    end
+    if test -f .dugout.toml -a -f {marker}
+        command dugout env --export --shell fish 2>/dev/null | source
+        set -g _DUGOUT_DIR $PWD
+    end
+end
+_dugout_hook
+"#;
+
+/// Print the auto-load hook for the given shell.
+pub fn execute(shell: HookShell) -> Result<()> {
+    let script = match shell {
+        HookShell::Bash => BASH_HOOK,
+        HookShell::Zsh => ZSH_HOOK,
+        HookShell::Fish => FISH_HOOK,
+    };
+    output::raw(&script.replace("{marker}", AUTOLOAD_MARKER));
+    Ok(())
+}
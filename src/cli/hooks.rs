@@ -0,0 +1,56 @@
+//! Hooks command - trust a vault's `[hooks]` commands on this machine.
+//!
+//! Hooks come from the committed config, so they only run after the user
+//! has reviewed and allowed them (see [`crate::core::hooks`]).
+
+use crate::cli::output;
+use crate::core::config::Config;
+use crate::core::hooks;
+use crate::error::Result;
+
+/// Allow the selected vault's hook commands to run in this project.
+pub fn allow(vault: Option<String>) -> Result<()> {
+    let vault_name = crate::cli::resolve::resolve_vault(vault.as_deref())?;
+    let config = Config::load_from(vault_name.as_deref())?;
+
+    let commands = config
+        .hooks
+        .as_ref()
+        .map(|h| h.commands())
+        .unwrap_or_default();
+    if commands.is_empty() {
+        output::hint("no hooks configured");
+        return Ok(());
+    }
+    for (event, command) in commands {
+        if hooks::allow(command)? {
+            output::success(&format!("allowed {}: {}", event, command));
+        } else {
+            output::hint(&format!("{} already allowed: {}", event, command));
+        }
+    }
+    Ok(())
+}
+
+/// Stop the selected vault's hook commands from running in this project.
+pub fn deny(vault: Option<String>) -> Result<()> {
+    let vault_name = crate::cli::resolve::resolve_vault(vault.as_deref())?;
+    let config = Config::load_from(vault_name.as_deref())?;
+
+    let commands = config
+        .hooks
+        .as_ref()
+        .map(|h| h.commands())
+        .unwrap_or_default();
+    let mut denied = 0;
+    for (event, command) in commands {
+        if hooks::deny(command)? {
+            output::success(&format!("denied {}: {}", event, command));
+            denied += 1;
+        }
+    }
+    if denied == 0 {
+        output::hint("no allowed hooks");
+    }
+    Ok(())
+}
@@ -3,14 +3,19 @@
 use tracing::info;
 
 use crate::cli::output;
+use crate::core::config::Config;
 use crate::core::vault::Vault;
-use crate::error::Result;
+use crate::error::{ConfigError, Result};
 
 /// Initialize dugout in the current directory.
+///
+/// Refuses when a parent directory already has a `.dugout.toml`, since a
+/// nested vault is usually a mistake; `here` creates it anyway.
 pub fn execute(
     name: Option<String>,
     _no_banner: bool,
     kms: Option<String>,
+    here: bool,
     vault: Option<String>,
 ) -> Result<()> {
     // Validate vault name for init (rejects "default" as reserved)
@@ -19,6 +24,20 @@ pub fn execute(
     }
     let vault_name = vault;
 
+    // An existing vault here is reported by init itself
+    if !Config::exists_for(vault_name.as_deref()) {
+        if let Some(parent) = Config::find_in_ancestors() {
+            let parent = parent.display().to_string();
+            if !here {
+                return Err(ConfigError::ParentVault(parent).into());
+            }
+            output::warn(&format!(
+                "creating a nested vault; parent vault at {}",
+                parent
+            ));
+        }
+    }
+
     let name = name.unwrap_or_else(whoami::username);
 
     info!("Initializing for user: {}", name);
@@ -28,9 +47,10 @@ pub fn execute(
         .map(|n| format!(".dugout.{}.toml", n))
         .unwrap_or_else(|| ".dugout.toml".to_string());
 
-    let _vault = Vault::init_vault(vault_name.as_deref(), &name, kms.clone())?;
+    // A vault profile may enable hybrid mode even without --kms
+    let v = Vault::init_vault(vault_name.as_deref(), &name, kms)?;
 
-    if kms.is_some() {
+    if v.config().has_kms() {
         output::success(&format!(
             "initialized {} (hybrid: age + kms)",
             vault_display
@@ -5,7 +5,7 @@ use std::io::{self, IsTerminal};
 
 use crate::cli::output;
 use crate::core::config::Config;
-use crate::core::domain::Identity;
+use crate::core::domain::{AccessRequest, Identity};
 use crate::core::store;
 use crate::core::vault::validate_member_name;
 use crate::error::Result;
@@ -48,7 +48,12 @@ pub fn execute(name: Option<String>, vault: Option<String>) -> Result<()> {
 
     validate_member_name(&name)?;
 
-    let pubkey = Identity::load_global_pubkey()?;
+    let identity = store::load_global_identity()?;
+    let pubkey = identity.public_key();
+    let request = match identity.as_x25519() {
+        Some(x25519) => AccessRequest::signed(vault_name.as_deref(), &name, x25519)?,
+        None => AccessRequest::unsigned(vault_name.as_deref(), &name, &pubkey),
+    };
 
     // Create vault-specific requests directory
     let request_dir = crate::core::constants::request_dir(vault_name.as_deref());
@@ -58,7 +63,7 @@ pub fn execute(name: Option<String>, vault: Option<String>) -> Result<()> {
     let request_path = request_dir.join(format!("{}.pub", name));
     if request_path.exists() {
         let existing = std::fs::read_to_string(&request_path)?;
-        let existing_key = existing.trim();
+        let existing_key = existing.lines().next().unwrap_or("").trim();
         if existing_key == pubkey {
             // Same key - idempotent, already requested
             output::warn("request already exists with your key");
@@ -78,12 +83,19 @@ pub fn execute(name: Option<String>, vault: Option<String>) -> Result<()> {
     }
 
     // Write request file
-    std::fs::write(&request_path, format!("{}\n", pubkey))?;
+    std::fs::write(&request_path, request.to_file())?;
 
     output::success("created access request");
+    if !request.is_signed() {
+        output::note("SSH keys can't sign requests; `admit --verify` will refuse this one");
+    }
     // Use forward slashes for consistent cross-platform output
     let display_path = request_path.display().to_string().replace('\\', "/");
     output::hint(&format!("share {} with an admin", display_path));
+    output::hint(&format!(
+        "key fingerprint: {} (tell the admin so they can check it)",
+        crate::core::domain::key_fingerprint(&pubkey)
+    ));
 
     Ok(())
 }
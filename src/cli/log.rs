@@ -0,0 +1,92 @@
+//! Log command - export the local operation log.
+//!
+//! Events carry metadata only (actor, action, key names), never secret values.
+
+use chrono::{DateTime, NaiveDate, Utc};
+
+use crate::cli::{output, LogFormat};
+use crate::core::config::Config;
+use crate::core::domain::LogEvent;
+use crate::core::oplog;
+use crate::error::{Error, Result};
+
+/// Export operation log events, optionally filtered by time.
+pub fn export(
+    format: LogFormat,
+    since: Option<&str>,
+    syslog: bool,
+    vault: Option<String>,
+) -> Result<()> {
+    let vault_name = crate::cli::resolve::resolve_vault(vault.as_deref())?;
+    let config = Config::load_from(vault_name.as_deref())?;
+    let since = since.map(parse_since).transpose()?;
+
+    let events: Vec<LogEvent> = oplog::read(&config.project_id())?
+        .into_iter()
+        .filter(|event| match since {
+            Some(since) => event.time().is_some_and(|t| t >= since),
+            None => true,
+        })
+        .collect();
+
+    if syslog {
+        send_syslog(&events)?;
+        output::success(&format!("sent {} events to syslog", events.len()));
+        return Ok(());
+    }
+
+    match format {
+        LogFormat::Ndjson => {
+            for event in &events {
+                output::data(&serde_json::to_string(event)?);
+            }
+        }
+        LogFormat::Json => output::data(&serde_json::to_string_pretty(&events)?),
+    }
+    Ok(())
+}
+
+/// Parse `--since` as an RFC3339 timestamp or a `YYYY-MM-DD` date (UTC).
+fn parse_since(value: &str) -> Result<DateTime<Utc>> {
+    if let Ok(ts) = DateTime::parse_from_rfc3339(value) {
+        return Ok(ts.with_timezone(&Utc));
+    }
+    NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .ok()
+        .and_then(|date| date.and_hms_opt(0, 0, 0))
+        .map(|dt| dt.and_utc())
+        .ok_or_else(|| {
+            Error::Other(format!(
+                "invalid --since '{}': expected RFC3339 or YYYY-MM-DD",
+                value
+            ))
+        })
+}
+
+/// Send events to the local syslog daemon, one JSON message per event.
+#[cfg(unix)]
+fn send_syslog(events: &[LogEvent]) -> Result<()> {
+    use std::os::unix::net::UnixDatagram;
+
+    // facility auth (4), severity info (6)
+    const PRIORITY: u8 = 4 * 8 + 6;
+
+    let socket = UnixDatagram::unbound()?;
+    let target = ["/dev/log", "/var/run/syslog"]
+        .into_iter()
+        .find(|path| std::path::Path::new(path).exists())
+        .ok_or_else(|| Error::Other("no syslog socket found".to_string()))?;
+
+    for event in events {
+        let message = format!("<{}>dugout: {}", PRIORITY, serde_json::to_string(event)?);
+        socket.send_to(message.as_bytes(), target)?;
+    }
+    Ok(())
+}
+
+#[cfg(not(unix))]
+fn send_syslog(_events: &[LogEvent]) -> Result<()> {
+    Err(Error::Other(
+        "--syslog is only supported on unix".to_string(),
+    ))
+}
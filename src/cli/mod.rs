@@ -4,14 +4,24 @@ pub mod add;
 pub mod admit;
 pub mod banner;
 pub mod completions;
+pub mod conflict;
+pub mod crypt;
 pub mod dot;
+pub mod hook;
+pub mod hooks;
 pub mod init;
 pub mod knock;
+pub mod log;
 pub mod output;
 pub mod pending;
+pub mod progress;
+pub mod protect;
+pub mod render;
 pub mod resolve;
 pub mod run;
+pub mod schema;
 pub mod secrets;
+pub mod selftest;
 pub mod setup;
 pub mod shell;
 pub mod sync;
@@ -26,6 +36,9 @@ pub mod vault;
 #[cfg(target_os = "macos")]
 pub mod migrate_keychain;
 
+#[cfg(target_os = "windows")]
+pub mod migrate_credman;
+
 #[cfg(target_os = "macos")]
 pub mod reset_keychain;
 
@@ -54,10 +67,22 @@ pub struct Cli {
     #[arg(short = 'v', long, global = true)]
     pub verbose: bool,
 
+    /// Only print errors, warnings and data (no success messages or hints)
+    #[arg(short = 'q', long, global = true, conflicts_with = "verbose")]
+    pub quiet: bool,
+
     /// Select vault (e.g., "dev", "prod"). Uses .dugout.toml by default.
     #[arg(long = "vault", global = true, env = "DUGOUT_VAULT")]
     pub vault: Option<String>,
 
+    /// Confirm changes to protected vaults, and admits, without prompting
+    #[arg(short = 'y', long, global = true)]
+    pub yes: bool,
+
+    /// How to print a failure on stderr
+    #[arg(long, global = true, value_enum, default_value_t = ErrorFormat::Human)]
+    pub error_format: ErrorFormat,
+
     #[command(subcommand)]
     pub command: Command,
 }
@@ -76,10 +101,17 @@ pub enum Command {
         /// Write private key to path (use - for stdout)
         #[arg(short, long, value_name = "PATH")]
         output: Option<String>,
+        /// Encrypt the identity file with a passphrase
+        #[arg(long)]
+        passphrase: bool,
     },
 
     /// Print your public key
-    Whoami,
+    Whoami {
+        /// Also list this repo's vaults and whether you can open each
+        #[arg(long)]
+        all: bool,
+    },
 
     /// Initialize dugout in the current directory
     Init {
@@ -92,6 +124,9 @@ pub enum Command {
         /// KMS key for hybrid encryption (auto-detects AWS/GCP from format)
         #[arg(long, value_name = "KEY")]
         kms: Option<String>,
+        /// Create the vault even if a parent directory already has one
+        #[arg(long, visible_alias = "force")]
+        here: bool,
     },
 
     /// Add a secret interactively with hidden input
@@ -102,19 +137,53 @@ pub enum Command {
 
     /// Set a secret value
     Set {
-        /// Secret key (e.g., DATABASE_URL)
-        key: String,
-        /// Secret value
-        value: String,
+        /// KEY VALUE (use - to read the value from stdin), or KEY=VALUE pairs
+        #[arg(required = true, value_name = "KEY [VALUE] | KEY=VALUE...")]
+        args: Vec<String>,
+        /// Read the value from stdin until EOF
+        #[arg(long)]
+        stdin: bool,
+        /// Keep trailing newlines when reading from stdin
+        #[arg(long)]
+        no_trim: bool,
         /// Overwrite if exists
         #[arg(short, long)]
         force: bool,
+        /// Human-readable description of the secret
+        #[arg(long, value_name = "TEXT")]
+        desc: Option<String>,
+        /// Fetch the value from 1Password (`op://vault/item/field`)
+        #[cfg(feature = "external-fetch")]
+        #[arg(long, value_name = "REF", conflicts_with_all = ["stdin", "from_pass"])]
+        from_op: Option<String>,
+        /// Fetch the value from the Unix password store (`pass show PATH`)
+        #[cfg(feature = "external-fetch")]
+        #[arg(long, value_name = "PATH", conflicts_with = "stdin")]
+        from_pass: Option<String>,
     },
 
     /// Get a secret value
     Get {
         /// Secret key
         key: String,
+        /// Output as JSON: {"key": ..., "value": ...}
+        #[arg(long)]
+        json: bool,
+        /// Print the bare value (default)
+        #[arg(long, conflicts_with = "json")]
+        raw: bool,
+        /// Show only the first and last 2 characters (`ab****yz`)
+        #[arg(long, conflicts_with_all = ["json", "raw"])]
+        mask: bool,
+        /// Reuse a value read within this long (e.g. 5s, 500ms, 2m)
+        #[arg(long, value_name = "TTL", value_parser = parse_ttl)]
+        cache_ttl: Option<std::time::Duration>,
+    },
+
+    /// Edit a secret in $EDITOR
+    Edit {
+        /// Secret key
+        key: String,
     },
 
     /// Remove a secret
@@ -123,11 +192,62 @@ pub enum Command {
         key: String,
     },
 
+    /// Rename a secret key
+    Rename {
+        /// Current key
+        old: String,
+        /// New key
+        new: String,
+        /// Overwrite if the new key exists
+        #[arg(short, long)]
+        force: bool,
+    },
+
+    /// Move a secret into another vault
+    Mv {
+        /// Key to move
+        key: String,
+        /// Destination vault (`default` for .dugout.toml)
+        #[arg(long, value_name = "VAULT")]
+        to: String,
+        /// Overwrite if the destination has the key
+        #[arg(short, long)]
+        force: bool,
+    },
+
+    /// Copy a secret to a new key (no re-encryption)
+    Cp {
+        /// Key to copy
+        src: String,
+        /// New key
+        dst: String,
+        /// Overwrite if the new key exists
+        #[arg(short, long)]
+        force: bool,
+    },
+
+    /// Create an alias that resolves to another secret
+    Alias {
+        /// Alias name
+        name: String,
+        /// Secret (or alias) it points at
+        target: String,
+    },
+
     /// List all secret keys
     List {
         /// Output as JSON
         #[arg(long)]
         json: bool,
+        /// Sort order
+        #[arg(long, value_enum, default_value = "key")]
+        sort: ListSort,
+        /// Decrypt and show encrypted descriptions
+        #[arg(short, long)]
+        long: bool,
+        /// Group keys by prefix (`DB_HOST` under `DB`)
+        #[arg(long)]
+        group: bool,
     },
 
     /// Request access to a vault
@@ -142,7 +262,17 @@ pub enum Command {
     /// Approve an access request
     Admit {
         /// Name of the person to admit
-        name: String,
+        #[arg(required_unless_present = "all")]
+        name: Option<String>,
+        /// Admit everyone with a pending request, re-encrypting once
+        #[arg(long, conflicts_with = "name")]
+        all: bool,
+        /// Refuse unless you are an admin of the vault
+        #[arg(long)]
+        strict: bool,
+        /// Refuse requests without a valid signature
+        #[arg(long)]
+        verify: bool,
     },
 
     /// Re-encrypt secrets for the current recipient set
@@ -153,6 +283,19 @@ pub enum Command {
         /// Force re-encryption even if already in sync
         #[arg(long)]
         force: bool,
+        /// Remove team members whose expiry has passed
+        #[arg(long)]
+        prune_expired: bool,
+    },
+
+    /// Resolve git merge conflicts in the vault file
+    Resolve {
+        /// Keep our value for keys changed on both sides
+        #[arg(long, conflicts_with = "theirs")]
+        ours: bool,
+        /// Keep their value for keys changed on both sides
+        #[arg(long)]
+        theirs: bool,
     },
 
     /// Auto-detect project and run with secrets
@@ -160,14 +303,53 @@ pub enum Command {
     Dot,
 
     /// Run a command with secrets injected as env vars
+    ///
+    /// All secrets are injected unless --only or --except is given.
     Run {
+        /// Inject only these keys (comma-separated)
+        #[arg(
+            long,
+            value_name = "KEYS",
+            value_delimiter = ',',
+            conflicts_with = "except"
+        )]
+        only: Vec<String>,
+        /// Inject every key except these (comma-separated)
+        #[arg(long, value_name = "KEYS", value_delimiter = ',')]
+        except: Vec<String>,
         /// Command and arguments to run
         #[arg(trailing_var_arg = true)]
         command: Vec<String>,
     },
 
+    /// Fill a template's {{KEY}} placeholders with secrets
+    Render {
+        /// Template file
+        template: String,
+        /// Write here (mode 600) instead of stdout
+        #[arg(short, long, value_name = "PATH")]
+        output: Option<std::path::PathBuf>,
+        /// Leave placeholders for keys not in the vault instead of failing
+        #[arg(long)]
+        allow_missing: bool,
+    },
+
     /// Spawn a shell with secrets loaded as environment variables
-    Env,
+    Env {
+        /// Print export statements instead of spawning a shell
+        #[arg(long)]
+        export: bool,
+        /// Shell syntax for --export
+        #[arg(long, value_enum, default_value = "bash", requires = "export")]
+        shell: HookShell,
+    },
+
+    /// Print a shell hook that loads secrets on cd (opt in with .dugout/autoload)
+    ShellHook {
+        /// Shell to generate the hook for
+        #[arg(value_enum)]
+        shell: HookShell,
+    },
 
     /// Manage team members
     #[command(subcommand)]
@@ -177,6 +359,14 @@ pub enum Command {
     #[command(subcommand)]
     Secrets(SecretsCommand),
 
+    /// Operation log (export for SIEM ingestion)
+    #[command(subcommand)]
+    Log(LogCommand),
+
+    /// Trust or distrust the vault's `[hooks]` commands on this machine
+    #[command(subcommand)]
+    Hooks(HooksCommand),
+
     /// Run diagnostic checks (status, audit)
     #[command(subcommand)]
     Check(CheckCommand),
@@ -185,6 +375,31 @@ pub enum Command {
     #[command(subcommand)]
     Vault(VaultCommand),
 
+    /// Encrypt a value to age recipients (no vault needed)
+    Encrypt {
+        /// age public key to encrypt to (repeatable)
+        #[arg(short, long = "recipient", value_name = "KEY", required = true)]
+        recipients: Vec<String>,
+        /// Value to encrypt (omit or use - to read stdin)
+        value: Option<String>,
+    },
+
+    /// Decrypt age ciphertext with your identity (no vault needed)
+    Decrypt {
+        /// File to decrypt (omit or use - to read stdin)
+        input: Option<String>,
+    },
+
+    /// Verify encryption round-trips for each compiled-in backend
+    Selftest,
+
+    /// Print the JSON Schema for a command's --json output
+    Schema {
+        /// Command whose output to describe
+        #[arg(value_enum)]
+        command: SchemaTarget,
+    },
+
     /// Generate shell completions
     Completions {
         /// Shell to generate completions for
@@ -203,6 +418,17 @@ pub enum Command {
         force: bool,
     },
 
+    /// Migrate file-based identities to Windows Credential Manager (Windows only)
+    #[cfg(target_os = "windows")]
+    MigrateCredman {
+        /// Delete files after successful migration
+        #[arg(long)]
+        delete: bool,
+        /// Skip confirmation prompts
+        #[arg(short, long)]
+        force: bool,
+    },
+
     /// Remove identities from macOS Keychain (macOS only)
     #[cfg(target_os = "macos")]
     ResetKeychain {
@@ -226,6 +452,32 @@ pub enum Shell {
     PowerShell,
 }
 
+/// Supported shells for `dugout shell-hook` and `dugout env --export`.
+#[derive(clap::ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HookShell {
+    Bash,
+    Zsh,
+    Fish,
+}
+
+/// Formats for the error printed when a command fails.
+#[derive(clap::ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ErrorFormat {
+    /// A colored message with a hint
+    Human,
+    /// `{"error":{"kind":"...","message":"..."}}`
+    Json,
+}
+
+/// Sort orders for `dugout list`.
+#[derive(clap::ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ListSort {
+    /// Alphabetical by key name
+    Key,
+    /// Most recently modified first
+    Modified,
+}
+
 /// Team subcommands.
 #[derive(Subcommand)]
 pub enum TeamAction {
@@ -233,8 +485,15 @@ pub enum TeamAction {
     Add {
         /// Member name
         name: String,
-        /// age public key
-        key: String,
+        /// age public key, or an SSH ed25519 key (`ssh-ed25519 AAAA...`)
+        #[arg(required = true, num_args = 1.., value_name = "KEY")]
+        key: Vec<String>,
+        /// Stop encrypting for this member after a date (`2026-12-31`) or RFC3339 time
+        #[arg(long, value_name = "DATE", value_parser = parse_expiry)]
+        expires: Option<chrono::DateTime<chrono::Utc>>,
+        /// Note shown next to the member in `team list` (e.g. "CI deploy key")
+        #[arg(long)]
+        note: Option<String>,
     },
 
     /// List team members
@@ -249,6 +508,37 @@ pub enum TeamAction {
         /// Member name
         name: String,
     },
+
+    /// Print the team's public keys for other tools (e.g. `age -R`)
+    Export {
+        /// Output format
+        #[arg(long, value_enum, default_value = "age-recipients")]
+        format: TeamExportFormat,
+        /// Leave out the `# name` comment above each key
+        #[arg(long)]
+        no_comments: bool,
+    },
+
+    /// Add the recipients from a `team export --format json|toml` file
+    Import {
+        /// Roster file
+        file: String,
+        /// Keep current members and add the imported ones (default)
+        #[arg(long, conflicts_with = "replace")]
+        merge: bool,
+        /// Make the imported roster the whole team
+        #[arg(long)]
+        replace: bool,
+    },
+
+    /// Set whether a team member can admit others
+    Role {
+        /// Member name
+        name: String,
+        /// New role
+        #[arg(value_enum)]
+        role: RoleArg,
+    },
 }
 
 /// Secrets lifecycle subcommands.
@@ -258,32 +548,306 @@ pub enum SecretsCommand {
     Lock,
 
     /// Decrypt secrets to local .env file
-    Unlock,
+    Unlock {
+        /// Line ending for the written file (overrides `env_line_ending`)
+        #[arg(long, value_enum)]
+        line_ending: Option<LineEndingArg>,
+        /// When to quote values in the written file
+        #[arg(long, value_enum, default_value = "minimal")]
+        quote: QuoteArg,
+        /// Only write keys matching this glob (e.g. `DB_*`, `*_KEY`)
+        #[arg(long = "match", value_name = "PATTERN")]
+        pattern: Option<String>,
+        /// Write to this file instead of `.env` (parent directories are created)
+        #[arg(short, long, value_name = "PATH")]
+        output: Option<std::path::PathBuf>,
+        /// Overwrite the --output file if it exists
+        #[arg(short, long, requires = "output")]
+        force: bool,
+        /// Move an existing file to `<name>.bak` before writing
+        #[arg(long)]
+        backup: bool,
+    },
 
     /// Import secrets from a .env file
     Import {
-        /// Path to .env file
-        path: String,
+        /// Path to .env file (or cloud export, see --format); `-` reads stdin
+        path: Option<String>,
+
+        /// Where to import from
+        #[arg(long, value_enum, default_value = "file")]
+        from: ImportSource,
+
+        /// Secret name or ARN to fetch (with --from aws-sm)
+        #[arg(long, value_name = "NAME", conflicts_with = "item")]
+        secret_id: Option<String>,
+
+        /// 1Password item name or ID to fetch (with --from op)
+        #[arg(long, value_name = "ITEM")]
+        item: Option<String>,
+
+        /// Format of the file
+        #[arg(long, value_enum, default_value = "dotenv")]
+        format: ImportFormatArg,
+
+        /// Only import keys starting with this prefix
+        #[arg(long, value_name = "PREFIX")]
+        only_prefix: Option<String>,
+
+        /// Strip the prefix from imported key names
+        #[arg(long, requires = "only_prefix")]
+        strip: bool,
+
+        /// Expand ${VAR} and $VAR references to earlier entries in the file (dotenv only)
+        #[arg(long)]
+        expand: bool,
+
+        /// With --expand, treat undefined references as empty instead of failing
+        #[arg(long, requires = "expand")]
+        allow_undefined: bool,
+
+        /// Fail if a key appears more than once (default: the last one wins)
+        #[arg(long)]
+        strict: bool,
+
+        /// What to do with keys the vault already has
+        #[arg(long, value_enum, default_value = "error")]
+        on_conflict: OnConflictArg,
     },
 
-    /// Export secrets as .env format
-    Export,
+    /// Export secrets (dotenv by default)
+    Export {
+        /// Output format
+        #[arg(long, value_enum, default_value = "dotenv")]
+        format: ExportFormat,
+        /// When to quote values in dotenv output
+        #[arg(long, value_enum, default_value = "minimal")]
+        quote: QuoteArg,
+        /// Only export keys matching this glob (e.g. `DB_*`, `*_KEY`)
+        #[arg(long = "match", value_name = "PATTERN")]
+        pattern: Option<String>,
+        /// Secret name for `--format k8s`
+        #[arg(long, value_name = "NAME")]
+        name: Option<String>,
+        /// Namespace for `--format k8s`
+        #[arg(long, value_name = "NAMESPACE", default_value = "default")]
+        namespace: String,
+    },
 
     /// Show diff between .dugout.toml and .env
-    Diff,
+    Diff {
+        /// Keys or globs to exclude from the comparison (comma-separated)
+        #[arg(long, value_name = "KEYS", value_delimiter = ',')]
+        ignore: Vec<String>,
+        /// Exit with a non-zero status if vault and .env differ
+        #[arg(long)]
+        exit_code: bool,
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+        /// Include plaintext values in JSON output
+        #[arg(long, requires = "json")]
+        show_values: bool,
+        /// Compare with another vault instead of .env
+        #[arg(long, value_name = "VAULT")]
+        against: Option<String>,
+        /// Write keys added or changed in .env to the vault
+        #[arg(long, conflicts_with_all = ["against", "json", "exit_code"])]
+        apply: bool,
+        /// With --apply, also remove keys that aren't in .env
+        #[arg(long, requires = "apply")]
+        prune: bool,
+    },
 
     /// Rotate the project keypair and re-encrypt all secrets
-    Rotate,
+    Rotate {
+        /// Replace only these secrets with new values instead (comma-separated)
+        #[arg(long, value_name = "KEYS", value_delimiter = ',')]
+        only: Option<Vec<String>>,
+    },
+
+    /// Check that every secret decrypts with your identity
+    Verify,
+
+    /// Rewrite a missing or stale recipients hash without re-encrypting
+    FixHash,
+
+    /// Merge secret keys that differ only by case (e.g. Api_Key and API_KEY)
+    Dedup {
+        /// Keys to keep without prompting (comma-separated)
+        #[arg(long, value_name = "KEYS", value_delimiter = ',')]
+        keep: Vec<String>,
+    },
+}
+
+/// Operation log subcommands.
+#[derive(Subcommand)]
+pub enum LogCommand {
+    /// Export recorded operations (metadata only, never secret values)
+    Export {
+        /// Output format
+        #[arg(long, value_enum, default_value = "ndjson")]
+        format: LogFormat,
+        /// Only events at or after this time (RFC3339 or YYYY-MM-DD)
+        #[arg(long, value_name = "TIME")]
+        since: Option<String>,
+        /// Send events to the local syslog daemon instead of stdout
+        #[arg(long)]
+        syslog: bool,
+    },
+}
+
+/// Output formats for `dugout log export`.
+#[derive(clap::ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LogFormat {
+    /// A single JSON array
+    Json,
+    /// One JSON object per line
+    Ndjson,
+}
+
+/// Output formats for `dugout secrets export`.
+#[derive(clap::ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ExportFormat {
+    /// KEY=value lines
+    Dotenv,
+    /// A flat JSON object
+    Json,
+    /// A flat YAML map
+    Yaml,
+    /// export KEY='value' lines
+    Shell,
+    /// A dotenv-vault `.env.vault` file (AES-256-GCM, key in DOTENV_KEY)
+    EnvVault,
+    /// KEY=value lines for `docker run --env-file` (no quoting)
+    Docker,
+    /// A Kubernetes `v1/Secret` manifest (needs --name)
+    K8s,
+}
+
+/// Output formats for `dugout team export`.
+#[derive(clap::ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TeamExportFormat {
+    /// One public key per line, as read by `age -R`
+    AgeRecipients,
+    /// `{"recipients": {name: key}}`, as read by `team import`
+    Json,
+    /// A `[recipients]` table, as read by `team import`
+    Toml,
+}
+
+/// Roles for `dugout team role`.
+#[derive(clap::ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RoleArg {
+    /// Can admit new members
+    Admin,
+    /// Reads secrets
+    Member,
+}
+
+/// Line endings for `dugout secrets unlock --line-ending`.
+#[derive(clap::ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LineEndingArg {
+    /// \n
+    Lf,
+    /// \r\n
+    Crlf,
+    /// CRLF on Windows, LF elsewhere
+    Native,
+}
+
+/// File formats for `dugout secrets import --format`.
+#[derive(clap::ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ImportFormatArg {
+    /// KEY=VALUE lines
+    Dotenv,
+    /// JSON from `aws secretsmanager get-secret-value`
+    #[value(name = "aws-secretsmanager")]
+    AwsSecretsManager,
+    /// JSON from `gcloud secrets versions access --format json`
+    GcpSecretManager,
+    /// A dotenv-vault `.env.vault` file (reads the key from DOTENV_KEY)
+    EnvVault,
+}
+
+/// Policies for `dugout secrets import --on-conflict`.
+#[derive(clap::ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OnConflictArg {
+    /// Fail without importing anything, like `set` without --force
+    Error,
+    /// Keep the stored value
+    Skip,
+    /// Replace the stored value
+    Overwrite,
+}
+
+/// Sources for `dugout secrets import --from`.
+#[derive(clap::ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ImportSource {
+    /// A local file (see --format)
+    File,
+    /// AWS Secrets Manager (needs --secret-id; built with --features aws)
+    AwsSm,
+    /// 1Password, through the `op` CLI (needs --item)
+    Op,
+}
+
+/// Quoting modes for dotenv output (`--quote`).
+#[derive(clap::ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum QuoteArg {
+    /// Quote only values that need it
+    Minimal,
+    /// Quote every value
+    Always,
+    /// Never quote; fail on values that need quotes
+    Never,
+}
+
+/// Commands with a `--json` output described by `dugout schema`.
+#[derive(clap::ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SchemaTarget {
+    /// dugout get --json
+    Get,
+    /// dugout list --json
+    List,
+    /// dugout team list --json
+    TeamList,
+    /// dugout vault list --json
+    VaultList,
+    /// dugout check status --json
+    Status,
+    /// dugout secrets diff --json
+    Diff,
+}
+
+/// Hook trust subcommands.
+#[derive(Subcommand)]
+pub enum HooksCommand {
+    /// Allow the vault's current hook commands to run in this project
+    Allow,
+    /// Stop the vault's hook commands from running in this project
+    Deny,
 }
 
 /// Check/diagnostic subcommands.
 #[derive(Subcommand)]
 pub enum CheckCommand {
     /// Show quick status overview
-    Status,
+    Status {
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Audit the vault file and git history for problems
+    Audit {
+        /// Scan only staged changes and fail on high-severity findings (pre-commit)
+        #[arg(long)]
+        staged: bool,
+    },
 
-    /// Audit git history for leaked secrets
-    Audit,
+    /// Check every recipient can decrypt every secret (header analysis only)
+    Access,
 }
 
 /// Vault management subcommands.
@@ -295,59 +859,414 @@ pub enum VaultCommand {
         #[arg(long)]
         json: bool,
     },
+    /// Copy this vault's recipients and secrets into a new vault
+    Clone {
+        /// Name of the new vault
+        name: String,
+    },
+    /// Delete a vault file and its pending access requests
+    Rm {
+        /// Vault to delete (`default` for .dugout.toml)
+        name: String,
+        /// Skip the confirmation prompt (protected vaults still need --yes)
+        #[arg(short, long)]
+        force: bool,
+    },
+}
+
+/// Parse a recipient expiry: an RFC3339 time, or a `YYYY-MM-DD` date
+/// (expiring at the start of that day, UTC).
+fn parse_expiry(value: &str) -> Result<chrono::DateTime<chrono::Utc>, String> {
+    if let Ok(time) = chrono::DateTime::parse_from_rfc3339(value) {
+        return Ok(time.with_timezone(&chrono::Utc));
+    }
+    chrono::NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .ok()
+        .and_then(|date| date.and_hms_opt(0, 0, 0))
+        .map(|time| time.and_utc())
+        .ok_or_else(|| {
+            format!(
+                "invalid expiry '{}': expected e.g. 2026-12-31 or 2026-12-31T18:00:00Z",
+                value
+            )
+        })
+}
+
+/// Parse a cache TTL: a number with an `ms`, `s`, `m`, or `h` suffix
+/// (bare numbers are seconds).
+fn parse_ttl(value: &str) -> Result<std::time::Duration, String> {
+    let split = value
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(value.len());
+    let (amount, unit) = value.split_at(split);
+    let amount: u64 = amount
+        .parse()
+        .map_err(|_| format!("invalid TTL '{}': expected e.g. 5s or 500ms", value))?;
+    let millis = match unit {
+        "ms" => 1,
+        "" | "s" => 1_000,
+        "m" => 60_000,
+        "h" => 3_600_000,
+        _ => return Err(format!("invalid TTL unit '{}': use ms, s, m, or h", unit)),
+    };
+    if amount == 0 {
+        return Err("TTL must be greater than zero".to_string());
+    }
+    Ok(std::time::Duration::from_millis(
+        amount.saturating_mul(millis),
+    ))
+}
+
+impl Command {
+    /// Whether the command writes to the selected vault.
+    fn is_mutation(&self) -> bool {
+        match self {
+            Command::Add { .. }
+            | Command::Set { .. }
+            | Command::Edit { .. }
+            | Command::Rm { .. }
+            | Command::Rename { .. }
+            | Command::Cp { .. }
+            | Command::Mv { .. }
+            | Command::Alias { .. }
+            | Command::Admit { .. }
+            | Command::Resolve { .. } => true,
+            Command::Sync { dry_run, .. } => !dry_run,
+            Command::Team(action) => {
+                !matches!(action, TeamAction::List { .. } | TeamAction::Export { .. })
+            }
+            Command::Secrets(cmd) => matches!(
+                cmd,
+                SecretsCommand::Import { .. }
+                    | SecretsCommand::Rotate { .. }
+                    | SecretsCommand::FixHash
+                    | SecretsCommand::Dedup { .. }
+                    | SecretsCommand::Diff { apply: true, .. }
+            ),
+            _ => false,
+        }
+    }
 }
 
 /// Execute a command with vault context.
-pub fn execute(command: Command, vault: Option<String>) -> crate::error::Result<()> {
+///
+/// Commands that change a protected vault are confirmed first (see
+/// [`protect::confirm_mutation`]); `yes` skips the prompt.
+pub fn execute(command: Command, vault: Option<String>, yes: bool) -> crate::error::Result<()> {
     use Command::*;
 
+    // Resolve once up front so the guard and the command agree on the vault
+    let vault = if command.is_mutation() {
+        let resolved = resolve::resolve_vault(vault.as_deref())?;
+        protect::confirm_mutation(resolved.as_deref(), yes)?;
+        resolved
+    } else {
+        vault
+    };
+
     match command {
         Setup {
             force,
             name,
             output,
-        } => setup::execute(force, name, output),
-        Whoami => whoami::execute(),
+            passphrase,
+        } => setup::execute(force, name, output, passphrase),
+        Whoami { all } => whoami::execute(all),
         Init {
             name,
             no_banner,
             kms,
-        } => init::execute(name, no_banner, kms, vault),
+            here,
+        } => init::execute(name, no_banner, kms, here, vault),
         Add { key } => add::execute(&key, vault),
-        Set { key, value, force } => secrets::set(&key, &value, force, vault),
-        Get { key } => secrets::get(&key, vault),
+        Set {
+            args,
+            stdin,
+            no_trim,
+            force,
+            desc,
+            #[cfg(feature = "external-fetch")]
+            from_op,
+            #[cfg(feature = "external-fetch")]
+            from_pass,
+        } => {
+            #[cfg(feature = "external-fetch")]
+            if let Some(source) = secrets::fetch::Source::from_flags(from_op, from_pass) {
+                return secrets::set_fetched(&args, &source, force, desc.as_deref(), vault);
+            }
+            match secrets::parse_set_args(args, stdin, no_trim)? {
+                secrets::SetInput::Single(key, value) => {
+                    secrets::set(&key, &value, force, desc.as_deref(), vault)
+                }
+                secrets::SetInput::Pairs(_) if desc.is_some() => Err(crate::error::Error::Other(
+                    "--desc can only be used with a single KEY VALUE".to_string(),
+                )),
+                secrets::SetInput::Pairs(pairs) => secrets::set_many(&pairs, force, vault),
+            }
+        }
+        Get {
+            key,
+            json,
+            mask,
+            cache_ttl,
+            ..
+        } => secrets::get(&key, json, mask, cache_ttl, vault),
+        Edit { key } => secrets::edit(&key, vault),
         Rm { key } => secrets::rm(&key, vault),
-        List { json } => secrets::list(json, vault),
+        Rename { old, new, force } => secrets::rename(&old, &new, force, vault),
+        Cp { src, dst, force } => secrets::copy(&src, &dst, force, vault),
+        Mv { key, to, force } => {
+            resolve::validate_vault_name(&to)?;
+            protect::confirm_mutation(Some(to.as_str()).filter(|to| *to != "default"), yes)?;
+            secrets::move_to(&key, &to, force, vault)
+        }
+        Alias { name, target } => secrets::alias(&name, &target, vault),
+        List {
+            json,
+            sort,
+            long,
+            group,
+        } => secrets::list(json, sort, long, group, vault),
         Knock { name } => knock::execute(name, vault),
         Pending => pending::execute(vault),
-        Admit { name } => admit::execute(&name, vault),
-        Sync { dry_run, force } => sync::execute(dry_run, force, vault),
+        Admit {
+            name,
+            all,
+            strict,
+            verify,
+        } => match name {
+            Some(name) if !all => admit::execute(&name, strict, verify, yes, vault),
+            _ => admit::execute_all(strict, verify, yes, vault),
+        },
+        Sync {
+            dry_run,
+            force,
+            prune_expired,
+        } => sync::execute(dry_run, force, prune_expired, vault),
+        Resolve { ours, theirs } => conflict::execute(ours, theirs, vault),
         Dot => dot::execute(vault),
-        Run { command: cmd } => run::execute(&cmd, vault),
-        Env => shell::execute(vault),
+        Run {
+            only,
+            except,
+            command: cmd,
+        } => run::execute(&cmd, &only, &except, vault),
+        Render {
+            template,
+            output,
+            allow_missing,
+        } => render::execute(&template, output.as_deref(), allow_missing, vault),
+        Env { export, shell } => {
+            if export {
+                shell::export(shell, vault)
+            } else {
+                shell::execute(vault)
+            }
+        }
+        ShellHook { shell } => hook::execute(shell),
         Team(action) => match action {
-            TeamAction::Add { name, key } => team::add(&name, &key, vault),
+            TeamAction::Add {
+                name,
+                key,
+                expires,
+                note,
+            } => team::add(&name, &key.join(" "), expires, note.as_deref(), vault),
             TeamAction::List { json } => team::list(json, vault),
             TeamAction::Rm { name } => team::rm(&name, vault),
+            TeamAction::Export {
+                format,
+                no_comments,
+            } => team::export(format, !no_comments, vault),
+            TeamAction::Import { file, replace, .. } => team::import(&file, replace, vault),
+            TeamAction::Role { name, role } => {
+                use crate::core::domain::Role;
+                let role = match role {
+                    RoleArg::Admin => Role::Admin,
+                    RoleArg::Member => Role::Member,
+                };
+                team::role(&name, role, vault)
+            }
         },
         Secrets(cmd) => match cmd {
             SecretsCommand::Lock => secrets::lock(vault),
-            SecretsCommand::Unlock => secrets::unlock(vault),
-            SecretsCommand::Import { path } => secrets::import(&path, vault),
-            SecretsCommand::Export => secrets::export(vault),
-            SecretsCommand::Diff => secrets::diff(vault),
-            SecretsCommand::Rotate => secrets::rotate(vault),
+            SecretsCommand::Unlock {
+                line_ending,
+                quote,
+                pattern,
+                output,
+                force,
+                backup,
+            } => secrets::unlock(
+                line_ending,
+                quote,
+                pattern.as_deref(),
+                output.as_deref(),
+                force,
+                backup,
+                vault,
+            ),
+            SecretsCommand::Import {
+                path,
+                from,
+                secret_id,
+                item,
+                format,
+                only_prefix,
+                strip,
+                expand,
+                allow_undefined,
+                strict,
+                on_conflict,
+            } => {
+                use crate::core::domain::ImportFormat;
+                use crate::error::Error;
+                if expand && format != ImportFormatArg::Dotenv {
+                    return Err(Error::Other(
+                        "--expand only applies to dotenv files".to_string(),
+                    ));
+                }
+                let opts = crate::core::domain::ImportOptions {
+                    format: match format {
+                        ImportFormatArg::Dotenv => ImportFormat::Dotenv,
+                        ImportFormatArg::AwsSecretsManager => ImportFormat::AwsSecretsManager,
+                        ImportFormatArg::GcpSecretManager => ImportFormat::GcpSecretManager,
+                        ImportFormatArg::EnvVault => ImportFormat::EnvVault,
+                    },
+                    prefix: only_prefix,
+                    strip_prefix: strip,
+                    expand,
+                    allow_undefined,
+                    strict,
+                    on_conflict: match on_conflict {
+                        OnConflictArg::Error => crate::core::domain::OnConflict::Error,
+                        OnConflictArg::Skip => crate::core::domain::OnConflict::Skip,
+                        OnConflictArg::Overwrite => crate::core::domain::OnConflict::Overwrite,
+                    },
+                };
+                let remote = match (from, secret_id, item) {
+                    (ImportSource::File, None, None) => None,
+                    (ImportSource::File, Some(_), _) => {
+                        return Err(Error::Other(
+                            "--secret-id only applies with --from aws-sm".to_string(),
+                        ))
+                    }
+                    (ImportSource::File, None, Some(_)) => {
+                        return Err(Error::Other(
+                            "--item only applies with --from op".to_string(),
+                        ))
+                    }
+                    (ImportSource::AwsSm, Some(id), None) => Some(secrets::Remote::AwsSm(id)),
+                    (ImportSource::AwsSm, ..) => {
+                        return Err(Error::Other(
+                            "--from aws-sm requires --secret-id NAME".to_string(),
+                        ))
+                    }
+                    (ImportSource::Op, None, Some(item)) => {
+                        Some(secrets::Remote::OnePassword(item))
+                    }
+                    (ImportSource::Op, ..) => {
+                        return Err(Error::Other("--from op requires --item ITEM".to_string()))
+                    }
+                };
+                match (remote, path) {
+                    (None, Some(path)) => secrets::import(&path, &opts, vault),
+                    (None, None) => Err(Error::Other(
+                        "a path is required\n  → Pass a file, or use --from aws-sm / --from op"
+                            .to_string(),
+                    )),
+                    (Some(remote), Some(_)) => Err(Error::Other(format!(
+                        "--from {} takes no path",
+                        remote.source()
+                    ))),
+                    (Some(_), None) if opts.format != ImportFormat::Dotenv => Err(Error::Other(
+                        "--format only applies to file imports".to_string(),
+                    )),
+                    (Some(remote), None) => secrets::import_remote(&remote, &opts, vault),
+                }
+            }
+            SecretsCommand::Export {
+                format,
+                quote,
+                pattern,
+                name,
+                namespace,
+            } => {
+                let k8s = match (format, name) {
+                    (ExportFormat::K8s, Some(name)) => Some(secrets::K8sTarget { name, namespace }),
+                    (ExportFormat::K8s, None) => {
+                        return Err(crate::error::Error::Other(
+                            "--format k8s requires --name NAME".to_string(),
+                        ))
+                    }
+                    (_, Some(_)) => {
+                        return Err(crate::error::Error::Other(
+                            "--name only applies with --format k8s".to_string(),
+                        ))
+                    }
+                    (_, None) => None,
+                };
+                secrets::export(format, quote, pattern.as_deref(), k8s.as_ref(), vault)
+            }
+            SecretsCommand::Diff {
+                ignore,
+                exit_code,
+                json,
+                show_values,
+                against,
+                apply,
+                prune,
+            } => secrets::diff(
+                &ignore,
+                exit_code,
+                json,
+                show_values,
+                against.as_deref(),
+                apply.then_some(prune),
+                vault,
+            ),
+            SecretsCommand::Rotate { only } => secrets::rotate(only, vault),
+            SecretsCommand::Verify => secrets::verify(vault),
+            SecretsCommand::FixHash => secrets::fix_hash(vault),
+            SecretsCommand::Dedup { keep } => secrets::dedup(&keep, vault),
+        },
+        Log(cmd) => match cmd {
+            LogCommand::Export {
+                format,
+                since,
+                syslog,
+            } => log::export(format, since.as_deref(), syslog, vault),
+        },
+        Hooks(cmd) => match cmd {
+            HooksCommand::Allow => hooks::allow(vault),
+            HooksCommand::Deny => hooks::deny(vault),
         },
         Check(cmd) => match cmd {
-            CheckCommand::Status => check::status(vault),
-            CheckCommand::Audit => check::audit(),
+            CheckCommand::Status { json } => check::status(json, vault),
+            CheckCommand::Audit { staged } => check::audit(staged, vault),
+            CheckCommand::Access => check::access(vault),
         },
         Vault(cmd) => match cmd {
             VaultCommand::List { json } => vault::list::execute(json),
+            VaultCommand::Clone { name } => {
+                resolve::validate_vault_name(&name)?;
+                protect::confirm_mutation(Some(name.as_str()).filter(|n| *n != "default"), yes)?;
+                vault::clone::execute(&name, vault)
+            }
+            VaultCommand::Rm { name, force } => {
+                // --force only skips the delete prompt, never the protection guard
+                resolve::validate_vault_name(&name)?;
+                protect::confirm_mutation(Some(name.as_str()).filter(|n| *n != "default"), yes)?;
+                vault::rm::execute(&name, force)
+            }
         },
+        Encrypt { recipients, value } => crypt::encrypt(&recipients, value),
+        Decrypt { input } => crypt::decrypt(input),
+        Selftest => selftest::execute(),
+        Schema { command } => schema::execute(command),
         Completions { shell } => completions::execute(shell),
         #[cfg(target_os = "macos")]
         MigrateKeychain { delete, force } => migrate_keychain::execute(delete, force),
+        #[cfg(target_os = "windows")]
+        MigrateCredman { delete, force } => migrate_credman::execute(delete, force),
         #[cfg(target_os = "macos")]
         ResetKeychain {
             account,
@@ -7,14 +7,33 @@
 //! - Cyan: paths, commands, keys
 //! - Bold: emphasis
 //! - Dim: hints
+//!
+//! With `--quiet`, success messages and hints are dropped; errors, warnings
+//! and data still print.
 
 use console::style;
 use std::fmt::Display;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static QUIET: AtomicBool = AtomicBool::new(false);
+
+/// Drop success messages and hints for the rest of the process (`--quiet`).
+pub fn set_quiet(quiet: bool) {
+    QUIET.store(quiet, Ordering::Relaxed);
+}
+
+/// Whether `--quiet` is on.
+pub fn is_quiet() -> bool {
+    QUIET.load(Ordering::Relaxed)
+}
 
 /// Print a success message with checkmark (green).
 ///
 /// Example: `✓ initialized vault`
 pub fn success(msg: &str) {
+    if is_quiet() {
+        return;
+    }
     println!("{} {}", style("✓").green(), msg);
 }
 
@@ -36,9 +55,19 @@ pub fn warn(msg: &str) {
 ///
 /// Example: `  run: dugout init`
 pub fn hint(msg: &str) {
+    if is_quiet() {
+        return;
+    }
     println!("  {}", style(msg).dim());
 }
 
+/// Print a note to stderr (dim), keeping stdout clean for piped output.
+///
+/// Example: `  vault: prod (from branch main)`
+pub fn note(msg: &str) {
+    eprintln!("  {}", style(msg).dim());
+}
+
 /// Print a key-value pair (label: value).
 ///
 /// Example: `vault: .dugout.toml`
@@ -78,6 +107,22 @@ pub fn key(k: &str) -> String {
     style(k).cyan().to_string()
 }
 
+/// Format the time elapsed since a timestamp in short form.
+///
+/// Example: `just now`, `5m ago`, `3h ago`, `12d ago`
+pub fn age(since: chrono::DateTime<chrono::Utc>) -> String {
+    let elapsed = chrono::Utc::now().signed_duration_since(since);
+    if elapsed.num_minutes() < 1 {
+        "just now".to_string()
+    } else if elapsed.num_hours() < 1 {
+        format!("{}m ago", elapsed.num_minutes())
+    } else if elapsed.num_days() < 1 {
+        format!("{}h ago", elapsed.num_hours())
+    } else {
+        format!("{}d ago", elapsed.num_days())
+    }
+}
+
 /// Format a count/number in parentheses.
 pub fn count(n: impl Display) -> String {
     format!("{}", n)
@@ -1,6 +1,7 @@
 //! Pending command - list pending access requests.
 
 use crate::cli::output;
+use crate::core::domain::key_fingerprint;
 use crate::core::vault::Vault;
 use crate::error::Result;
 
@@ -15,13 +16,26 @@ pub fn execute(vault: Option<String>) -> Result<()> {
         return Ok(());
     }
 
-    for (name, pubkey) in requests {
+    for request in requests {
+        let pubkey = request.public_key();
+        let fingerprint = key_fingerprint(pubkey);
         let truncated = if pubkey.len() > 20 {
             format!("{}...", &pubkey[..20])
         } else {
-            pubkey
+            pubkey.to_string()
         };
-        println!("{:<15} {}", name, truncated);
+        let status = if request.is_signed() {
+            "signed"
+        } else {
+            "unsigned"
+        };
+        println!(
+            "{:<15} {:<23} {:<24} {}",
+            request.name(),
+            truncated,
+            fingerprint,
+            status
+        );
     }
 
     Ok(())
@@ -0,0 +1,47 @@
+//! Progress bar for long re-encryptions.
+
+use std::io::{self, IsTerminal};
+
+use indicatif::{ProgressBar, ProgressStyle};
+use tracing::level_filters::LevelFilter;
+
+use crate::cli::output;
+use crate::core::vault::Vault;
+
+/// Re-encryptions of fewer secrets finish too fast for a bar to help.
+const MIN_SECRETS: usize = 50;
+
+/// Draw a progress bar on stderr whenever `vault` re-encrypts its secrets.
+///
+/// Skipped when stderr isn't a terminal, with `--quiet`, or when debug
+/// logging is on (`--verbose` or `DUGOUT_LOG`), since log lines would tear
+/// up the bar.
+pub fn attach(vault: &mut Vault) {
+    if !io::stderr().is_terminal()
+        || output::is_quiet()
+        || LevelFilter::current() >= LevelFilter::DEBUG
+    {
+        return;
+    }
+
+    let mut bar: Option<ProgressBar> = None;
+    vault.set_progress(move |done, total| {
+        if total < MIN_SECRETS {
+            return;
+        }
+        let current = bar.get_or_insert_with(|| {
+            let style = ProgressStyle::with_template("{msg} [{bar:30}] {pos}/{len}")
+                .unwrap_or_else(|_| ProgressStyle::default_bar())
+                .progress_chars("=> ");
+            ProgressBar::new(total as u64)
+                .with_style(style)
+                .with_message("re-encrypting")
+        });
+        current.set_position(done as u64);
+        if done == total {
+            if let Some(finished) = bar.take() {
+                finished.finish_and_clear();
+            }
+        }
+    });
+}
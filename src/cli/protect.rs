@@ -0,0 +1,50 @@
+//! Confirmation guard for changes to protected vaults.
+
+use std::io::{self, IsTerminal};
+
+use dialoguer::Confirm;
+
+use crate::cli::output;
+use crate::core::config::Config;
+use crate::error::{ConfigError, Result};
+
+/// Whether a vault requires confirmation before it is changed.
+///
+/// A vault is protected when its own file sets `confirm_mutations`, or when
+/// its name matches a `protected` pattern in the default `.dugout.toml`.
+/// Unreadable files are treated as unprotected; the command itself reports them.
+pub fn is_protected(vault: Option<&str>) -> bool {
+    let name = vault.unwrap_or("default");
+    let own = Config::load_from(vault).is_ok_and(|cfg| cfg.dugout.confirm_mutations);
+    own || Config::load().is_ok_and(|cfg| cfg.is_protected(name))
+}
+
+/// Ask before changing a protected vault.
+///
+/// Passes straight through for unprotected vaults or when `yes` is set.
+///
+/// # Errors
+///
+/// Returns `ConfigError::Protected` when stdin is not a terminal, or when
+/// the prompt is declined.
+pub fn confirm_mutation(vault: Option<&str>, yes: bool) -> Result<()> {
+    if yes || !is_protected(vault) {
+        return Ok(());
+    }
+
+    let name = vault.unwrap_or("default");
+    if !io::stdin().is_terminal() {
+        return Err(ConfigError::Protected(name.to_string()).into());
+    }
+
+    output::warn(&format!("vault '{}' is protected", name));
+    let confirmed = Confirm::new()
+        .with_prompt(format!("Modify vault '{}'?", name))
+        .default(false)
+        .interact()?;
+    if !confirmed {
+        return Err(ConfigError::Protected(name.to_string()).into());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,92 @@
+//! Render command - fill a template's `{{KEY}}` placeholders from the vault.
+
+use std::path::Path;
+
+use crate::cli::output;
+use crate::core::template;
+use crate::error::{Result, SecretError};
+
+/// Render `template_path`, writing to `to` (mode 600) or stdout.
+///
+/// Only the referenced secrets are decrypted. A placeholder for a key the
+/// vault doesn't have is an error unless `allow_missing`, which leaves it in
+/// the output as written.
+pub fn execute(
+    template_path: &str,
+    to: Option<&Path>,
+    allow_missing: bool,
+    vault: Option<String>,
+) -> Result<()> {
+    let vault_name = crate::cli::resolve::resolve_vault(vault.as_deref())?;
+    let v = crate::core::vault::Vault::open_vault(vault_name.as_deref())?;
+    let source = std::fs::read_to_string(template_path)?;
+
+    let config = v.config();
+    let (known, missing): (Vec<&str>, Vec<&str>) = template::placeholders(&source)
+        .into_iter()
+        .partition(|key| config.secrets.contains_key(*key) || config.aliases.contains_key(*key));
+    if !missing.is_empty() && !allow_missing {
+        return Err(SecretError::NotFound {
+            key: missing.join(", "),
+            suggestion: "\n  → Set them, or pass --allow-missing to leave the placeholders"
+                .to_string(),
+        }
+        .into());
+    }
+
+    let values = v.get_many(&known)?;
+    let rendered = template::render(&source, |key| {
+        values
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, value)| value.as_str())
+    });
+
+    match to {
+        Some(path) => {
+            write_private(path, &rendered)?;
+            output::success(&format!(
+                "rendered {} to {} ({} secrets)",
+                template_path,
+                path.display(),
+                values.len()
+            ));
+        }
+        None => output::raw(&rendered),
+    }
+    // On stderr, so it never ends up in rendered stdout
+    for key in &missing {
+        output::note(&format!(
+            "left {{{{{}}}}} unrendered: not in the vault",
+            key
+        ));
+    }
+    Ok(())
+}
+
+/// Write `contents` readable only by the owner.
+fn write_private(path: &Path, contents: &str) -> Result<()> {
+    #[cfg(unix)]
+    {
+        use std::io::Write;
+        use std::os::unix::fs::{OpenOptionsExt, PermissionsExt};
+
+        let mut file = std::fs::OpenOptions::new()
+            .create(true)
+            .truncate(true)
+            .write(true)
+            .mode(0o600)
+            .open(path)?;
+        file.write_all(contents.as_bytes())?;
+        file.flush()?;
+        // The mode only applies to new files
+        std::fs::set_permissions(path, std::fs::Permissions::from_mode(0o600))?;
+    }
+
+    #[cfg(not(unix))]
+    {
+        std::fs::write(path, contents)?;
+    }
+
+    Ok(())
+}
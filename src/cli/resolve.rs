@@ -1,5 +1,9 @@
 //! Vault resolution helpers for CLI commands.
 
+use std::process::Command;
+
+use crate::cli::output;
+use crate::core::config::Config;
 use crate::core::vault::Vault;
 use crate::error::{ConfigError, Result, ValidationError};
 
@@ -84,10 +88,59 @@ pub fn validate_vault_name_for_init(name: &str) -> Result<()> {
     Ok(())
 }
 
+/// Name of the checked-out git branch, if any.
+///
+/// Returns `None` outside a git repository and on a detached HEAD.
+fn current_branch() -> Option<String> {
+    let output = Command::new("git")
+        .args(["symbolic-ref", "--quiet", "--short", "HEAD"])
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let branch = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    (!branch.is_empty()).then_some(branch)
+}
+
+/// Infer the vault from the current git branch using `[vault_from_branch]`.
+///
+/// Only consulted when the default vault defines a mapping. A branch with
+/// no matching entry selects the default vault; a detached HEAD or missing
+/// repository leaves resolution to the usual rules.
+fn vault_from_branch() -> Result<Option<String>> {
+    // A broken default vault is reported by the command itself
+    let Ok(config) = Config::load() else {
+        return Ok(None);
+    };
+    if config.vault_from_branch.is_empty() {
+        return Ok(None);
+    }
+    let Some(branch) = current_branch() else {
+        return Ok(None);
+    };
+
+    match config.vault_for_branch(&branch) {
+        Some(name) => {
+            validate_vault_name(name)?;
+            output::note(&format!("vault: {} (from branch {})", name, branch));
+            Ok(Some(name.to_string()))
+        }
+        None => {
+            output::note(&format!(
+                "vault: default (no mapping for branch {})",
+                branch
+            ));
+            Ok(Some("default".to_string()))
+        }
+    }
+}
+
 /// Resolve which vault to use based on CLI flag and available vaults.
 ///
 /// Rules:
 /// - If vault is specified, validate and use it
+/// - If `[vault_from_branch]` is configured, use the current branch's vault
 /// - If only one vault exists, use it
 /// - If multiple vaults exist, error with guidance
 pub fn resolve_vault(vault: Option<&str>) -> Result<Option<String>> {
@@ -97,6 +150,10 @@ pub fn resolve_vault(vault: Option<&str>) -> Result<Option<String>> {
         return Ok(Some(v.to_string()));
     }
 
+    if let Some(inferred) = vault_from_branch()? {
+        return Ok(Some(inferred));
+    }
+
     // Check how many vaults exist
     let vault_files = Vault::find_vault_files()?;
 
@@ -131,14 +188,15 @@ pub fn resolve_vault(vault: Option<&str>) -> Result<Option<String>> {
 
 /// Resolve vault for commands that default to .dugout.toml (like `dugout .`).
 ///
-/// Unlike resolve_vault(), this always defaults to None (the default vault)
-/// if no explicit vault is specified, regardless of how many vaults exist.
+/// Unlike resolve_vault(), this defaults to None (the default vault) if no
+/// explicit vault is specified and no branch mapping applies, regardless of
+/// how many vaults exist.
 pub fn resolve_vault_default(vault: Option<&str>) -> Result<Option<String>> {
     if let Some(v) = vault {
         validate_vault_name(v)?;
         return Ok(Some(v.to_string()));
     }
-    Ok(None)
+    vault_from_branch()
 }
 
 #[cfg(test)]
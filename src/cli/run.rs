@@ -1,13 +1,22 @@
 //! Run command - execute a command with secrets injected.
 
 use crate::core::vault::Vault;
-use crate::error::Result;
-use zeroize::Zeroizing;
+use crate::error::{Result, SecretError};
 
 /// Run a command with secrets injected as environment variables.
-pub fn execute(command: &[String], vault: Option<String>) -> Result<()> {
+///
+/// With neither `only` nor `except`, every secret (and alias) is injected.
+/// `only` injects just the named keys; `except` injects all but them.
+pub fn execute(
+    command: &[String],
+    only: &[String],
+    except: &[String],
+    vault: Option<String>,
+) -> Result<()> {
     let vault_name = crate::cli::resolve::resolve_vault(vault.as_deref())?;
-    execute_with_vault(command, vault_name)
+    let v = Vault::open_vault(vault_name.as_deref())?;
+    let exit_code = run_with_secrets(&v, command, only, except)?;
+    std::process::exit(exit_code);
 }
 
 /// Run a command with an already-resolved vault name.
@@ -15,29 +24,63 @@ pub fn execute(command: &[String], vault: Option<String>) -> Result<()> {
 /// This is used by dot command which has its own vault resolution logic.
 pub fn execute_with_vault(command: &[String], vault_name: Option<String>) -> Result<()> {
     let v = Vault::open_vault(vault_name.as_deref())?;
-    let exit_code = run_with_secrets(&v, command)?;
+    let exit_code = run_with_secrets(&v, command, &[], &[])?;
     std::process::exit(exit_code);
 }
 
 /// Run a command with decrypted secrets as environment variables.
-fn run_with_secrets(vault: &Vault, command: &[String]) -> Result<i32> {
+fn run_with_secrets(
+    vault: &Vault,
+    command: &[String],
+    only: &[String],
+    except: &[String],
+) -> Result<i32> {
     if command.is_empty() {
         return Err(crate::error::Error::Other(
             "no command specified".to_string(),
         ));
     }
 
-    let pairs = vault.decrypt_all()?;
+    // Only the selected keys are decrypted
+    let keys = select_keys(vault, only, except)?;
+    let keys: Vec<&str> = keys.iter().map(String::as_str).collect();
+    let pairs = vault.get_many(&keys)?;
 
     let mut cmd = std::process::Command::new(&command[0]);
     cmd.args(&command[1..]);
 
     // Inject secrets as environment variables
-    for (key, value) in pairs {
-        let zeroized_value = Zeroizing::new(value);
-        cmd.env(key, zeroized_value.as_str());
+    for (key, value) in &pairs {
+        cmd.env(key, value.as_str());
     }
 
     let status = cmd.status()?;
     Ok(status.code().unwrap_or(1))
 }
+
+/// Pick the keys to inject, rejecting filter keys that don't exist.
+fn select_keys(vault: &Vault, only: &[String], except: &[String]) -> Result<Vec<String>> {
+    let all: Vec<String> = vault
+        .list()
+        .iter()
+        .map(|s| s.key().to_string())
+        .chain(
+            vault
+                .aliases()
+                .into_iter()
+                .map(|(alias, _)| alias.to_string()),
+        )
+        .collect();
+
+    if let Some(missing) = only.iter().chain(except).find(|key| !all.contains(key)) {
+        return Err(SecretError::not_found_with_suggestions(missing.clone(), &all).into());
+    }
+
+    if !only.is_empty() {
+        return Ok(only.to_vec());
+    }
+    Ok(all
+        .into_iter()
+        .filter(|key| !except.contains(key))
+        .collect())
+}
@@ -0,0 +1,161 @@
+//! Schema command - print the JSON Schema for a command's `--json` output.
+//!
+//! Every `--json` payload carries a top-level `schema_version`. Bump
+//! [`SCHEMA_VERSION`] whenever a field is removed, renamed, or changes type;
+//! adding a field is not a breaking change.
+
+use crate::cli::output;
+use crate::cli::SchemaTarget;
+use crate::error::Result;
+use serde_json::{json, Value};
+
+/// Version of the machine-readable output format.
+pub const SCHEMA_VERSION: u32 = 1;
+
+/// Print the JSON Schema for `target`.
+pub fn execute(target: SchemaTarget) -> Result<()> {
+    output::data(&serde_json::to_string_pretty(&schema(target))?);
+    Ok(())
+}
+
+/// Build the JSON Schema describing `target`'s `--json` output.
+pub fn schema(target: SchemaTarget) -> Value {
+    let (title, properties) = match target {
+        SchemaTarget::Get => (
+            "dugout get --json",
+            json!({
+                "key": { "type": "string" },
+                "value": { "type": "string" },
+            }),
+        ),
+        SchemaTarget::List => (
+            "dugout list --json",
+            json!({
+                "keys": { "type": "array", "items": { "type": "string" } },
+                "groups": {
+                    "type": "object",
+                    "additionalProperties": { "type": "array", "items": { "type": "string" } },
+                },
+                "ungrouped": { "type": "array", "items": { "type": "string" } },
+                "count": { "type": "integer", "minimum": 0 },
+            }),
+        ),
+        SchemaTarget::TeamList => (
+            "dugout team list --json",
+            json!({
+                "members": {
+                    "type": "array",
+                    "items": object(json!({
+                        "name": { "type": "string" },
+                        "public_key": { "type": "string" },
+                        "role": { "type": "string" },
+                        "note": { "type": ["string", "null"] },
+                        "expires": { "type": ["string", "null"] },
+                        "expired": { "type": "boolean" },
+                    })),
+                },
+                "count": { "type": "integer", "minimum": 0 },
+            }),
+        ),
+        SchemaTarget::VaultList => (
+            "dugout vault list --json",
+            json!({
+                "vaults": {
+                    "type": "array",
+                    "items": object(json!({
+                        "name": { "type": "string" },
+                        "path": { "type": "string" },
+                        "secrets": { "type": "integer", "minimum": 0 },
+                        "recipients": { "type": "integer", "minimum": 0 },
+                        "access": { "type": "boolean" },
+                    })),
+                },
+                "count": { "type": "integer", "minimum": 0 },
+            }),
+        ),
+        SchemaTarget::Status => (
+            "dugout check status --json",
+            json!({
+                "vault": { "type": "string" },
+                "cipher": { "type": "string" },
+                "secret_count": { "type": "integer", "minimum": 0 },
+                "recipient_count": { "type": "integer", "minimum": 0 },
+                "needs_sync": { "type": "boolean" },
+                "has_env": { "type": "boolean" },
+            }),
+        ),
+        SchemaTarget::Diff => (
+            "dugout secrets diff --json",
+            json!({
+                "synced": { "type": "boolean" },
+                "entries": {
+                    "type": "array",
+                    // Values are only present with `--show-values`
+                    "items": optional(
+                        object(json!({
+                            "key": { "type": "string" },
+                            "status": { "type": "string" },
+                            "differs": { "type": "boolean" },
+                            "vault_value": { "type": "string" },
+                            "env_value": { "type": "string" },
+                        })),
+                        &["vault_value", "env_value"],
+                    ),
+                },
+                "count": { "type": "integer", "minimum": 0 },
+            }),
+        ),
+    };
+
+    let mut properties = properties;
+    properties["schema_version"] = json!({ "const": SCHEMA_VERSION });
+
+    let mut root = object(properties);
+    if target == SchemaTarget::List {
+        // Only present with `--group`
+        root = optional(root, &["groups", "ungrouped"]);
+    }
+    root["$schema"] = json!("https://json-schema.org/draft/2020-12/schema");
+    root["title"] = json!(title);
+    root
+}
+
+/// A closed object schema where every listed property is required.
+fn object(properties: Value) -> Value {
+    let required: Vec<String> = properties
+        .as_object()
+        .map(|props| props.keys().cloned().collect())
+        .unwrap_or_default();
+    json!({
+        "type": "object",
+        "required": required,
+        "properties": properties,
+        "additionalProperties": false,
+    })
+}
+
+/// Drop `names` from an object schema's required properties.
+fn optional(mut schema: Value, names: &[&str]) -> Value {
+    if let Some(required) = schema["required"].as_array_mut() {
+        required.retain(|name| !names.iter().any(|n| name == n));
+    }
+    schema
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_schema_pins_version() {
+        let schema = schema(SchemaTarget::List);
+        assert_eq!(
+            schema["properties"]["schema_version"]["const"],
+            SCHEMA_VERSION
+        );
+        assert!(schema["required"]
+            .as_array()
+            .unwrap()
+            .contains(&json!("schema_version")));
+    }
+}
@@ -0,0 +1,57 @@
+//! Dedup command - merge secret keys that differ only by case.
+
+use std::io::{self, IsTerminal};
+
+use dialoguer::Select;
+
+use crate::cli::output;
+use crate::core::vault::Vault;
+use crate::error::{Error, Result};
+
+/// Keep one key from each group of case-variant duplicates.
+///
+/// Keys listed in `keep` win without prompting. Otherwise the user picks
+/// interactively; without a terminal, groups whose values are identical
+/// keep the upper-case spelling and groups that differ are an error.
+pub fn execute(keep: &[String], vault: Option<String>) -> Result<()> {
+    let vault_name = crate::cli::resolve::resolve_vault(vault.as_deref())?;
+    let mut v = Vault::open_vault(vault_name.as_deref())?;
+
+    let decisions = v.dedup_case(|group, identical| {
+        if let Some(key) = group.iter().find(|key| keep.contains(key)) {
+            return Ok(key.clone());
+        }
+        let interactive = io::stdin().is_terminal();
+        if identical && !interactive {
+            let upper = group.iter().find(|key| **key == key.to_ascii_uppercase());
+            return Ok(upper.unwrap_or(&group[0]).clone());
+        }
+        if !interactive {
+            return Err(Error::Other(format!(
+                "{} have different values\n  → Pass --keep with the key to keep",
+                group.join(", ")
+            )));
+        }
+        let values = if identical {
+            "same value"
+        } else {
+            "different values"
+        };
+        let choice = Select::new()
+            .with_prompt(format!("{} ({}); keep which?", group.join(", "), values))
+            .items(group)
+            .default(0)
+            .interact()?;
+        Ok(group[choice].clone())
+    })?;
+
+    if decisions.is_empty() {
+        output::success("no case-variant duplicate keys");
+        return Ok(());
+    }
+    for (kept, dropped) in &decisions {
+        output::list_item(&format!("{} (removed {})", kept, dropped.join(", ")));
+    }
+    output::success(&format!("deduplicated {} keys", decisions.len()));
+    Ok(())
+}
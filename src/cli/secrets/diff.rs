@@ -1,44 +1,182 @@
-//! Diff command - show differences between vault and .env.
+//! Diff command - show differences between vault and .env or another vault.
+
+use zeroize::Zeroizing;
 
 use crate::cli::output;
-use crate::error::Result;
+use crate::core::domain::{Diff, DiffEntry};
+use crate::core::vault::Vault;
+use crate::error::{Error, Result};
 
 /// Show diff/status between encrypted vault and local .env.
-pub fn execute(vault: Option<String>) -> Result<()> {
+///
+/// Keys matching any `ignore` pattern are left out of the output and the
+/// drift check. With `exit_code`, drift is reported as an error so hooks fail.
+/// With `json`, entries are printed as JSON; plaintext values are only
+/// included when `show_values` is also set. With `against`, the other vault
+/// takes the place of `.env`. With `apply` set to `Some(prune)`, keys added
+/// or changed in `.env` are written to the vault, and with `prune` keys
+/// missing from `.env` are removed from it.
+pub fn execute(
+    ignore: &[String],
+    exit_code: bool,
+    json: bool,
+    show_values: bool,
+    against: Option<&str>,
+    apply: Option<bool>,
+    vault: Option<String>,
+) -> Result<()> {
     let vault_name = crate::cli::resolve::resolve_vault(vault.as_deref())?;
-    let v = crate::core::vault::Vault::open_vault(vault_name.as_deref())?;
-    let env_path = std::path::Path::new(".env");
+    let mut v = crate::core::vault::Vault::open_vault(vault_name.as_deref())?;
 
-    if !env_path.exists() {
-        output::warn(".env not found");
-        return Ok(());
+    // Labels for keys only on each side, and for both sides together
+    let (diff, [ours, theirs, both]) = match against {
+        Some(other) => (
+            v.diff_vault(other)?,
+            [
+                "this vault only".to_string(),
+                format!("vault '{}' only", other),
+                format!("this vault and vault '{}'", other),
+            ],
+        ),
+        None => {
+            let env_path = std::path::Path::new(".env");
+            if !env_path.exists() {
+                output::warn(".env not found");
+                return Ok(());
+            }
+            (
+                v.diff(env_path)?,
+                ["vault only", "env only", "vault and .env"].map(String::from),
+            )
+        }
+    };
+    let diff = diff.ignore(ignore);
+
+    if json {
+        print_json(&diff, show_values)?;
+    } else {
+        print_text(&diff, &ours, &theirs);
+    }
+
+    if let Some(prune) = apply {
+        return apply_env(&mut v, &diff, prune);
+    }
+
+    if exit_code && !diff.is_synced() {
+        let changed = diff.len() - diff.synced().len();
+        return Err(Error::Other(format!("{} differ ({} keys)", both, changed)));
     }
 
-    let diff = v.diff(env_path)?;
+    Ok(())
+}
+
+/// Write `.env`'s side of `diff` into the vault.
+///
+/// Keys only in `.env` and changed keys are set (overwriting); with `prune`,
+/// keys only in the vault are removed. Aliases follow their target and are
+/// left alone.
+fn apply_env(v: &mut Vault, diff: &Diff, prune: bool) -> Result<()> {
+    let is_alias = |key: &str| v.config().aliases.contains_key(key);
+
+    let pairs: Vec<(String, Zeroizing<String>)> = diff
+        .env_only()
+        .into_iter()
+        .chain(diff.modified())
+        .filter(|entry| !is_alias(entry.key()))
+        .filter_map(|entry| {
+            let value = Zeroizing::new(entry.env_value()?.to_string());
+            Some((entry.key().to_string(), value))
+        })
+        .collect();
+    let removed: Vec<&str> = if prune {
+        diff.vault_only()
+            .into_iter()
+            .map(DiffEntry::key)
+            .filter(|&key| !is_alias(key))
+            .collect()
+    } else {
+        Vec::new()
+    };
+
+    if pairs.is_empty() && removed.is_empty() {
+        output::success("nothing to apply");
+        return Ok(());
+    }
+    if !pairs.is_empty() {
+        v.set_many(&pairs, true)?;
+    }
+    for key in &removed {
+        v.remove(key)?;
+    }
+    output::success(&format!(
+        "applied .env to the vault: {} set, {} removed",
+        pairs.len(),
+        removed.len()
+    ));
+    if !prune && !diff.vault_only().is_empty() {
+        output::hint("keys only in the vault were kept; pass --prune to remove them");
+    }
+    Ok(())
+}
 
+fn print_text(diff: &Diff, vault_only: &str, env_only: &str) {
     // Vault-only entries
-    let vault_only = diff.vault_only();
-    for entry in &vault_only {
-        println!("+ {} (vault only)", entry.key());
+    for entry in diff.vault_only() {
+        println!("+ {} ({})", entry.key(), vault_only);
     }
 
     // Env-only entries
-    let env_only = diff.env_only();
-    for entry in &env_only {
-        println!("- {} (env only)", entry.key());
+    for entry in diff.env_only() {
+        println!("- {} ({})", entry.key(), env_only);
     }
 
     // Modified entries
-    let modified = diff.modified();
-    for entry in &modified {
+    for entry in diff.modified() {
         println!("~ {} (modified)", entry.key());
     }
 
     // Synced entries
-    let synced = diff.synced();
-    for entry in &synced {
+    for entry in diff.synced() {
         println!("✓ {}", entry.key());
     }
+}
+
+fn print_json(diff: &Diff, show_values: bool) -> Result<()> {
+    #[derive(serde::Serialize)]
+    struct Entry<'a> {
+        #[serde(flatten)]
+        entry: &'a DiffEntry,
+        differs: bool,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        vault_value: Option<&'a str>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        env_value: Option<&'a str>,
+    }
+
+    #[derive(serde::Serialize)]
+    struct Output<'a> {
+        schema_version: u32,
+        synced: bool,
+        entries: Vec<Entry<'a>>,
+        count: usize,
+    }
 
+    let entries = diff
+        .entries()
+        .iter()
+        .map(|entry| Entry {
+            entry,
+            differs: !entry.is_synced(),
+            vault_value: entry.vault_value().filter(|_| show_values),
+            env_value: entry.env_value().filter(|_| show_values),
+        })
+        .collect();
+    let out = Zeroizing::new(serde_json::to_string_pretty(&Output {
+        schema_version: crate::cli::schema::SCHEMA_VERSION,
+        synced: diff.is_synced(),
+        entries,
+        count: diff.len(),
+    })?);
+    output::data(&out);
     Ok(())
 }
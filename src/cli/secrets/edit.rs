@@ -0,0 +1,128 @@
+//! Edit command - edit a secret in $EDITOR.
+
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use tracing::debug;
+use zeroize::Zeroizing;
+
+use crate::cli::output;
+use crate::core::vault::Vault;
+use crate::error::{Error, Result};
+
+/// Open a secret in the user's editor and save the result.
+///
+/// The current value (if any) is written to a private temp file, which is
+/// overwritten and removed once the editor exits. Nothing is saved when the
+/// editor fails or the content is unchanged.
+pub fn execute(key: &str, vault: Option<String>) -> Result<()> {
+    let vault_name = crate::cli::resolve::resolve_vault(vault.as_deref())?;
+    let mut v = Vault::open_vault(vault_name.as_deref())?;
+
+    let current = if v.config().secrets.contains_key(key) {
+        v.get(key)?
+    } else {
+        Zeroizing::new(String::new())
+    };
+
+    let file = TempFile::create()?;
+    file.write(current.as_bytes())?;
+
+    let editor = editor();
+    debug!(editor = %editor, "launching editor");
+    let mut parts = editor.split_whitespace();
+    let program = parts.next().unwrap_or(DEFAULT_EDITOR);
+    let status = Command::new(program)
+        .args(parts)
+        .arg(&file.path)
+        .status()
+        .map_err(|e| Error::Other(format!("failed to launch editor '{}': {}", editor, e)))?;
+
+    if !status.success() {
+        return Err(Error::Other(format!(
+            "editor exited with {}, {} not updated",
+            status, key
+        )));
+    }
+
+    let edited = Zeroizing::new(fs::read_to_string(&file.path)?);
+    let edited = edited.trim_end_matches(['\n', '\r']);
+    if edited == current.trim_end_matches(['\n', '\r']) {
+        output::warn(&format!("no changes, {} not updated", key));
+        return Ok(());
+    }
+
+    v.set(key, edited, true)?;
+    output::success(&format!("updated {}", key));
+    Ok(())
+}
+
+#[cfg(windows)]
+const DEFAULT_EDITOR: &str = "notepad";
+#[cfg(not(windows))]
+const DEFAULT_EDITOR: &str = "vi";
+
+/// Resolve the editor command from `$VISUAL`, `$EDITOR`, or the platform default.
+fn editor() -> String {
+    ["VISUAL", "EDITOR"]
+        .iter()
+        .filter_map(|var| std::env::var(var).ok())
+        .find(|value| !value.trim().is_empty())
+        .unwrap_or_else(|| DEFAULT_EDITOR.to_string())
+}
+
+/// Temp file holding a plaintext secret while it is being edited.
+///
+/// Created with owner-only permissions; on drop the contents are overwritten
+/// with zeros before the file is removed.
+struct TempFile {
+    path: PathBuf,
+}
+
+impl TempFile {
+    fn create() -> Result<Self> {
+        let nonce = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_nanos())
+            .unwrap_or_default();
+        let path = std::env::temp_dir().join(format!("dugout-{}-{}", std::process::id(), nonce));
+
+        let mut options = OpenOptions::new();
+        options.write(true).create_new(true);
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::OpenOptionsExt;
+            options.mode(0o600);
+        }
+        options.open(&path)?;
+
+        Ok(Self { path })
+    }
+
+    fn write(&self, data: &[u8]) -> Result<()> {
+        let mut file = OpenOptions::new()
+            .write(true)
+            .truncate(true)
+            .open(&self.path)?;
+        file.write_all(data)?;
+        file.sync_all()?;
+        Ok(())
+    }
+}
+
+impl Drop for TempFile {
+    fn drop(&mut self) {
+        let _ = scrub(&self.path);
+        let _ = fs::remove_file(&self.path);
+    }
+}
+
+/// Overwrite a file's contents with zeros.
+fn scrub(path: &Path) -> std::io::Result<()> {
+    let len = fs::metadata(path)?.len() as usize;
+    let mut file = OpenOptions::new().write(true).open(path)?;
+    file.write_all(&vec![0u8; len])?;
+    file.sync_all()
+}
@@ -1,13 +1,179 @@
-//! Export command - export secrets as .env format to stdout.
+//! Export command - export secrets to stdout.
 
-use crate::cli::output;
-use crate::error::Result;
+use base64::Engine;
+use zeroize::Zeroizing;
+
+use crate::cli::shell::quote_posix;
+use crate::cli::{output, ExportFormat, QuoteArg};
+use crate::core::cipher::env_vault::{self, DotenvKey};
+use crate::core::constants;
+use crate::error::{Error, Result};
+
+/// Name and namespace of the Secret written by `--format k8s`.
+pub struct K8sTarget {
+    pub name: String,
+    pub namespace: String,
+}
+
+/// Export secrets to stdout in the given format.
+///
+/// With a `pattern`, only secrets whose keys match the glob are decrypted.
+/// `env-vault` output is encrypted with the key in `DOTENV_KEY`, or a new
+/// key (printed to stderr) named after the vault's environment. `k8s`
+/// output needs a `target`.
+pub fn execute(
+    format: ExportFormat,
+    quote: QuoteArg,
+    pattern: Option<&str>,
+    target: Option<&K8sTarget>,
+    vault: Option<String>,
+) -> Result<()> {
+    if let Some(target) = target {
+        validate_k8s_name("--name", &target.name)?;
+        validate_k8s_name("--namespace", &target.namespace)?;
+    }
 
-/// Export secrets as .env format to stdout.
-pub fn execute(vault: Option<String>) -> Result<()> {
     let vault_name = crate::cli::resolve::resolve_vault(vault.as_deref())?;
     let v = crate::core::vault::Vault::open_vault(vault_name.as_deref())?;
-    let env = v.export()?;
-    output::raw(&env.to_string());
+    let env = match pattern {
+        Some(pattern) => v.export_filtered(pattern)?,
+        None => v.export()?,
+    };
+    let entries = env.entries();
+
+    let rendered = Zeroizing::new(match format {
+        ExportFormat::Dotenv => env.clone().with_quoting(super::quoting(quote)).render()?,
+        ExportFormat::Json => {
+            let map: serde_json::Map<String, serde_json::Value> = entries
+                .iter()
+                .map(|(k, v)| (k.clone(), serde_json::Value::String(v.clone())))
+                .collect();
+            let mut json = serde_json::to_string_pretty(&map)?;
+            json.push('\n');
+            json
+        }
+        ExportFormat::Yaml => render_yaml(entries)?,
+        ExportFormat::Shell => entries
+            .iter()
+            .map(|(k, v)| format!("export {}={}\n", k, quote_posix(v)))
+            .collect(),
+        ExportFormat::EnvVault => {
+            let key = dotenv_key(vault_name.as_deref())?;
+            let dotenv = Zeroizing::new(env.clone().with_quoting(super::quoting(quote)).render()?);
+            env_vault::seal(&dotenv, &key)?
+        }
+        ExportFormat::Docker => render_docker(entries)?,
+        ExportFormat::K8s => {
+            let target = target
+                .ok_or_else(|| Error::Other("--format k8s requires --name NAME".to_string()))?;
+            render_k8s(target, entries)?
+        }
+    });
+
+    output::raw(&rendered);
     Ok(())
 }
+
+/// Key for `env-vault` output: `DOTENV_KEY` if set, otherwise a new one.
+///
+/// New keys are for the vault's environment (`development` for the default
+/// vault) and are printed to stderr, since they're needed to decrypt.
+fn dotenv_key(vault_name: Option<&str>) -> Result<DotenvKey> {
+    if let Ok(uri) = std::env::var(constants::DOTENV_KEY_ENV) {
+        return DotenvKey::parse(&uri);
+    }
+    let environment = match vault_name {
+        None | Some("default") => "development",
+        Some(name) => name,
+    };
+    let key = DotenvKey::generate(environment);
+    output::note("new key for this file; store it safely, it's needed to decrypt:");
+    output::note(&format!(
+        "{}={}",
+        constants::DOTENV_KEY_ENV,
+        key.to_uri().as_str()
+    ));
+    Ok(key)
+}
+
+/// Render pairs as a flat YAML map.
+///
+/// Values are written as double-quoted scalars; JSON string escaping is a
+/// valid subset of YAML's double-quoted style.
+fn render_yaml(entries: &[(String, String)]) -> Result<String> {
+    let mut out = String::new();
+    for (key, value) in entries {
+        out.push_str(&format!("{}: {}\n", key, serde_json::to_string(value)?));
+    }
+    Ok(out)
+}
+
+/// Render pairs as a Docker `--env-file`.
+///
+/// Docker takes everything after the first `=` literally: quotes are kept
+/// and nothing is interpolated, so values are written as-is. A line break
+/// can't be represented (and a trailing `\r` would be dropped), so values
+/// containing `\n` or `\r` are refused.
+fn render_docker(entries: &[(String, String)]) -> Result<String> {
+    let multiline: Vec<&str> = entries
+        .iter()
+        .filter(|(_, value)| value.contains(['\n', '\r']))
+        .map(|(key, _)| key.as_str())
+        .collect();
+    if !multiline.is_empty() {
+        return Err(Error::Other(format!(
+            "docker env files can't hold line breaks; these values contain one: {}\n  → Use --format json or k8s for values like these",
+            multiline.join(", ")
+        )));
+    }
+    Ok(entries
+        .iter()
+        .map(|(key, value)| format!("{}={}\n", key, value))
+        .collect())
+}
+
+/// Render pairs as a Kubernetes `v1/Secret` manifest.
+///
+/// Values go in `data`, base64-encoded, so any bytes survive; names are
+/// double-quoted like [`render_yaml`] values.
+fn render_k8s(target: &K8sTarget, entries: &[(String, String)]) -> Result<String> {
+    let engine = base64::engine::general_purpose::STANDARD;
+    let mut out = String::from("apiVersion: v1\nkind: Secret\nmetadata:\n");
+    out.push_str(&format!(
+        "  name: {}\n",
+        serde_json::to_string(&target.name)?
+    ));
+    out.push_str(&format!(
+        "  namespace: {}\n",
+        serde_json::to_string(&target.namespace)?
+    ));
+    out.push_str("type: Opaque\n");
+    if entries.is_empty() {
+        out.push_str("data: {}\n");
+        return Ok(out);
+    }
+    out.push_str("data:\n");
+    for (key, value) in entries {
+        out.push_str(&format!("  {}: {}\n", key, engine.encode(value.as_bytes())));
+    }
+    Ok(out)
+}
+
+/// Check a Kubernetes object name (an RFC 1123 DNS subdomain).
+fn validate_k8s_name(flag: &str, name: &str) -> Result<()> {
+    let valid = !name.is_empty()
+        && name.len() <= 253
+        && name
+            .chars()
+            .all(|ch| ch.is_ascii_lowercase() || ch.is_ascii_digit() || ch == '-' || ch == '.')
+        && name.starts_with(|ch: char| ch.is_ascii_alphanumeric())
+        && name.ends_with(|ch: char| ch.is_ascii_alphanumeric());
+    if valid {
+        Ok(())
+    } else {
+        Err(Error::Other(format!(
+            "invalid {} '{}': use lowercase letters, digits, '-' and '.', starting and ending with a letter or digit",
+            flag, name
+        )))
+    }
+}
@@ -0,0 +1,98 @@
+//! Fetch secret values from a password manager CLI.
+//!
+//! Used by `dugout set KEY --from-op REF` and `--from-pass PATH`, so the
+//! value goes straight from the password manager into the vault without
+//! passing through shell history or process arguments.
+
+use std::process::{Command, Stdio};
+
+use tracing::debug;
+use zeroize::Zeroizing;
+
+use crate::error::{Error, Result};
+
+/// Where to fetch a value from.
+pub enum Source {
+    /// 1Password secret reference, read with `op read`
+    OnePassword(String),
+    /// Entry in the Unix password store, read with `pass show`
+    Pass(String),
+}
+
+impl Source {
+    /// Source selected by the `--from-op` / `--from-pass` flags, if any.
+    pub fn from_flags(op: Option<String>, pass: Option<String>) -> Option<Self> {
+        op.map(Self::OnePassword).or(pass.map(Self::Pass))
+    }
+
+    fn program(&self) -> &'static str {
+        match self {
+            Self::OnePassword(_) => "op",
+            Self::Pass(_) => "pass",
+        }
+    }
+
+    fn install_hint(&self) -> &'static str {
+        match self {
+            Self::OnePassword(_) => {
+                "Install the 1Password CLI: https://developer.1password.com/docs/cli"
+            }
+            Self::Pass(_) => "Install pass: https://www.passwordstore.org",
+        }
+    }
+}
+
+/// Run the password manager and return the value it prints.
+///
+/// `op read` prints the field as-is; `pass show` prints the password on the
+/// first line followed by optional metadata, so only the first line is kept.
+/// Trailing newlines are stripped in both cases.
+///
+/// # Errors
+///
+/// Returns an error if the tool isn't on `PATH`, exits unsuccessfully (its
+/// stderr is included), or prints nothing.
+pub fn fetch(source: &Source) -> Result<Zeroizing<String>> {
+    let program = source.program();
+    let path = which::which(program).map_err(|_| {
+        Error::Other(format!(
+            "{} not found on PATH\n  → {}",
+            program,
+            source.install_hint()
+        ))
+    })?;
+
+    let mut cmd = Command::new(&path);
+    match source {
+        Source::OnePassword(reference) => cmd.args(["read", "--no-newline", reference]),
+        Source::Pass(entry) => cmd.args(["show", entry]),
+    };
+    debug!(program = %program, "fetching secret value");
+
+    let output = cmd
+        .stdin(Stdio::inherit())
+        .output()
+        .map_err(|e| Error::Other(format!("failed to run {}: {}", program, e)))?;
+    let stdout = Zeroizing::new(output.stdout);
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(Error::Other(format!(
+            "{} exited with {}: {}",
+            program,
+            output.status,
+            stderr.trim()
+        )));
+    }
+
+    let text = std::str::from_utf8(&stdout)
+        .map_err(|_| Error::Other(format!("{} printed a value that isn't UTF-8", program)))?;
+    let value = match source {
+        Source::OnePassword(_) => text.trim_end_matches(['\n', '\r']),
+        Source::Pass(_) => text.lines().next().unwrap_or_default(),
+    };
+    if value.is_empty() {
+        return Err(Error::Other(format!("{} returned an empty value", program)));
+    }
+    Ok(Zeroizing::new(value.to_string()))
+}
@@ -0,0 +1,19 @@
+//! Fix-hash command - repair the stored recipients hash.
+
+use crate::cli::output;
+use crate::core::vault::Vault;
+use crate::error::Result;
+
+/// Recompute `recipients_hash` from the current recipients, leaving secrets as-is.
+pub fn execute(vault: Option<String>) -> Result<()> {
+    let vault_name = crate::cli::resolve::resolve_vault(vault.as_deref())?;
+    let mut v = Vault::open_vault(vault_name.as_deref())?;
+
+    if v.fix_recipients_hash()? {
+        output::success("recipients hash rewritten");
+        output::hint("if teammates still can't decrypt, run: dugout sync --force");
+    } else {
+        output::success("recipients hash already up to date");
+    }
+    Ok(())
+}
@@ -1,17 +1,118 @@
-//! Import command - import secrets from a .env file.
+//! Import command - import secrets from a .env file or a remote store.
 
 use crate::cli::output;
-use crate::error::Result;
+use crate::core::domain::{ImportOptions, ImportResult, OnConflict};
+use crate::core::import::{aws_sm, onepassword};
+use crate::error::{Result, SecretError};
+use std::path::Path;
 
-/// Import secrets from a .env file.
-pub fn execute(path: &str, vault: Option<String>) -> Result<()> {
+/// `path` that reads from stdin.
+const STDIN: &str = "-";
+
+/// Remote store to import from instead of a file.
+pub enum Remote {
+    /// AWS Secrets Manager secret name or ARN
+    AwsSm(String),
+    /// 1Password item name or ID
+    OnePassword(String),
+}
+
+impl Remote {
+    /// The `--from` value that selects this source.
+    pub fn source(&self) -> &'static str {
+        match self {
+            Self::AwsSm(_) => "aws-sm",
+            Self::OnePassword(_) => "op",
+        }
+    }
+
+    fn name(&self) -> &str {
+        match self {
+            Self::AwsSm(name) | Self::OnePassword(name) => name,
+        }
+    }
+
+    fn fetch(&self) -> Result<Vec<(String, String)>> {
+        match self {
+            Self::AwsSm(secret_id) => aws_sm::fetch(secret_id),
+            Self::OnePassword(item) => onepassword::fetch(item),
+        }
+    }
+}
+
+/// Import secrets from a .env file, or from stdin when `path` is `-`.
+pub fn execute(path: &str, opts: &ImportOptions, vault: Option<String>) -> Result<()> {
     let vault_name = crate::cli::resolve::resolve_vault(vault.as_deref())?;
     let mut v = crate::core::vault::Vault::open_vault(vault_name.as_deref())?;
-    let imported = v.import(path)?;
+    if path == STDIN {
+        let stdin = std::io::stdin();
+        let result = v.import_from_reader(stdin.lock(), Path::new(STDIN), opts)?;
+        report(&result, "stdin");
+    } else {
+        report(&v.import_with(path, opts)?, path);
+    }
+    Ok(())
+}
+
+/// Import the key/value pairs fetched from a remote store.
+///
+/// Pairs are filtered and renamed by `opts` like a file import, and keys the
+/// vault already has are handled by `opts.on_conflict`.
+pub fn execute_remote(remote: &Remote, opts: &ImportOptions, vault: Option<String>) -> Result<()> {
+    let vault_name = crate::cli::resolve::resolve_vault(vault.as_deref())?;
+    let mut v = crate::core::vault::Vault::open_vault(vault_name.as_deref())?;
+
+    let pairs: Vec<(String, String)> = remote
+        .fetch()?
+        .into_iter()
+        .filter_map(|(key, value)| Some((opts.map_key(&key)?.to_string(), value)))
+        // Aliases follow their target
+        .filter(|(key, _)| !v.config().aliases.contains_key(key))
+        .collect();
+
+    let (existing, fresh): (Vec<_>, Vec<_>) = pairs
+        .into_iter()
+        .partition(|(key, _)| v.config().secrets.contains_key(key));
+    let keys = |pairs: &[(String, String)]| -> Vec<String> {
+        pairs.iter().map(|(key, _)| key.clone()).collect()
+    };
+    let mut result = ImportResult {
+        created: keys(&fresh),
+        ..Default::default()
+    };
+    let pairs = match opts.on_conflict {
+        OnConflict::Error if !existing.is_empty() => {
+            return Err(SecretError::ImportConflict(keys(&existing).join(", ")).into());
+        }
+        OnConflict::Skip => {
+            result.skipped = keys(&existing);
+            fresh
+        }
+        _ => {
+            result.overwritten = keys(&existing);
+            existing.into_iter().chain(fresh).collect()
+        }
+    };
+
+    v.set_many(&pairs, true)?;
+    report(&result, remote.name());
+    Ok(())
+}
+
+/// Print what an import wrote and what it left alone.
+fn report(result: &ImportResult, source: &str) {
     output::success(&format!(
         "imported {} secrets from {}",
-        imported.len(),
-        path
+        result.written(),
+        source
     ));
-    Ok(())
+    if !result.overwritten.is_empty() {
+        output::note(&format!("overwritten: {}", result.overwritten.join(", ")));
+    }
+    if !result.skipped.is_empty() {
+        output::note(&format!(
+            "skipped, already in the vault: {}",
+            result.skipped.join(", ")
+        ));
+    }
 }
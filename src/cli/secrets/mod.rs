@@ -1,41 +1,271 @@
 //! Secret management commands.
 
+mod dedup;
 mod diff;
+mod edit;
 mod export;
+#[cfg(feature = "external-fetch")]
+pub mod fetch;
+mod fix_hash;
 mod import;
 mod lock;
 mod rotate;
 mod unlock;
+mod verify;
+
+use std::collections::BTreeMap;
+use std::io::{self, Read};
 
 use tracing::debug;
+use zeroize::Zeroizing;
 
 use crate::cli::output;
+use crate::cli::{ListSort, QuoteArg};
+use crate::core::domain::{Quoting, SetOutcome};
 use crate::core::vault::Vault;
-use crate::error::Result;
+use crate::error::{Error, Result};
 
 // Re-export subcommand functions
+pub use dedup::execute as dedup;
 pub use diff::execute as diff;
-pub use export::execute as export;
+pub use edit::execute as edit;
+pub use export::{execute as export, K8sTarget};
+pub use fix_hash::execute as fix_hash;
 pub use import::execute as import;
+pub use import::{execute_remote as import_remote, Remote};
 pub use lock::execute as lock;
 pub use rotate::execute as rotate;
 pub use unlock::execute as unlock;
+pub use verify::execute as verify;
+
+/// Parsed arguments of `dugout set`.
+pub enum SetInput {
+    /// `KEY VALUE`, `KEY -`, or `KEY --stdin`
+    Single(String, Zeroizing<String>),
+    /// One or more `KEY=VALUE` pairs
+    Pairs(Vec<(String, String)>),
+}
+
+/// Parse the positional arguments of `dugout set`.
+///
+/// If the first argument contains `=`, every argument is a `KEY=VALUE` pair
+/// (keys can't contain `=`). Otherwise the arguments are `KEY [VALUE]`, where
+/// a value of `-` or the `--stdin` flag reads stdin until EOF, keeping the
+/// secret out of shell history and process arguments. Trailing newlines are
+/// stripped from stdin unless `no_trim` is set.
+pub fn parse_set_args(args: Vec<String>, stdin: bool, no_trim: bool) -> Result<SetInput> {
+    if args.first().is_some_and(|arg| arg.contains('=')) {
+        if stdin {
+            return Err(Error::Other(
+                "--stdin can't be combined with KEY=VALUE pairs".to_string(),
+            ));
+        }
+        let pairs = args
+            .into_iter()
+            .map(|arg| match arg.split_once('=') {
+                Some((key, value)) => {
+                    reject_value_as_key(key)?;
+                    Ok((key.to_string(), value.to_string()))
+                }
+                None => {
+                    reject_value_as_key(&arg)?;
+                    Err(Error::Other(format!("expected KEY=VALUE, got '{}'", arg)))
+                }
+            })
+            .collect::<Result<Vec<_>>>()?;
+        return Ok(SetInput::Pairs(pairs));
+    }
+
+    let mut args = args.into_iter();
+    let key = args.next().unwrap_or_default();
+    reject_value_as_key(&key)?;
+    let value = args.next();
+    if args.next().is_some() {
+        return Err(Error::Other(
+            "too many arguments: pass KEY VALUE or KEY=VALUE pairs".to_string(),
+        ));
+    }
+    if stdin && value.is_some() {
+        return Err(Error::Other(
+            "pass either VALUE or --stdin, not both".to_string(),
+        ));
+    }
+
+    let from_stdin = stdin || value.as_deref() == Some("-");
+    if !from_stdin {
+        let value = value
+            .map(Zeroizing::new)
+            .ok_or_else(|| Error::Other("missing value: pass VALUE, - or --stdin".to_string()))?;
+        return Ok(SetInput::Single(key, value));
+    }
+
+    let mut input = Zeroizing::new(String::new());
+    io::stdin().read_to_string(&mut input)?;
+    if no_trim {
+        return Ok(SetInput::Single(key, input));
+    }
+    let value = Zeroizing::new(input.trim_end_matches(['\n', '\r']).to_string());
+    Ok(SetInput::Single(key, value))
+}
+
+/// Catch `dugout set VALUE KEY` slips before they become a confusing key
+/// validation error.
+///
+/// The argument is never echoed back: if it looks like a value, it is
+/// probably the secret.
+fn reject_value_as_key(key: &str) -> Result<()> {
+    let reason = if key.contains("://") {
+        "contains '://'"
+    } else if key.chars().any(char::is_whitespace) {
+        "contains whitespace"
+    } else {
+        return Ok(());
+    };
+    Err(Error::Other(format!(
+        "the key looks like a value ({})\n  → Did you mean to swap them? dugout set KEY VALUE",
+        reason
+    )))
+}
+
+/// Whether a value has the shape of a key name, e.g. `DATABASE_URL`.
+fn looks_like_key(value: &str) -> bool {
+    value.contains('_')
+        && value.starts_with(|ch: char| ch.is_ascii_uppercase())
+        && value
+            .chars()
+            .all(|ch| ch.is_ascii_uppercase() || ch.is_ascii_digit() || ch == '_')
+}
+
+/// Shortest value whose ends are shown by [`mask_value`].
+const MASK_MIN_LEN: usize = 6;
+
+/// Replace all but the first and last 2 characters with `*`.
+///
+/// Values shorter than 6 characters are masked entirely.
+fn mask_value(value: &str) -> Zeroizing<String> {
+    let len = value.chars().count();
+    let masked = value
+        .chars()
+        .enumerate()
+        .map(|(i, ch)| {
+            if len >= MASK_MIN_LEN && (i < 2 || i >= len - 2) {
+                ch
+            } else {
+                '*'
+            }
+        })
+        .collect();
+    Zeroizing::new(masked)
+}
+
+/// Map a `--quote` argument to the dotenv quoting mode.
+fn quoting(arg: QuoteArg) -> Quoting {
+    match arg {
+        QuoteArg::Minimal => Quoting::Minimal,
+        QuoteArg::Always => Quoting::Always,
+        QuoteArg::Never => Quoting::Never,
+    }
+}
 
 /// Set a secret value.
-pub fn set(key: &str, value: &str, force: bool, vault: Option<String>) -> Result<()> {
+pub fn set(
+    key: &str,
+    value: &str,
+    force: bool,
+    desc: Option<&str>,
+    vault: Option<String>,
+) -> Result<()> {
     let vault_name = crate::cli::resolve::resolve_vault(vault.as_deref())?;
     debug!("Setting secret: {} (force: {})", key, force);
     let mut v = Vault::open_vault(vault_name.as_deref())?;
-    v.set(key, value, force)?;
-    output::success(&format!("set {}", key));
+    let outcome = v.set(key, value, force)?;
+    if let Some(desc) = desc {
+        v.set_description(key, desc)?;
+    }
+    match outcome {
+        SetOutcome::Unchanged => output::success(&format!("{} unchanged", key)),
+        SetOutcome::Created | SetOutcome::Updated => output::success(&format!("set {}", key)),
+    }
+    if looks_like_key(value) {
+        output::warn("the value looks like a key name");
+        output::hint("if the arguments were swapped: dugout set KEY VALUE");
+    }
+    Ok(())
+}
+
+/// Set a secret to a value fetched from a password manager.
+///
+/// `args` must be just the key; the value comes from `source`.
+#[cfg(feature = "external-fetch")]
+pub fn set_fetched(
+    args: &[String],
+    source: &fetch::Source,
+    force: bool,
+    desc: Option<&str>,
+    vault: Option<String>,
+) -> Result<()> {
+    let [key] = args else {
+        return Err(Error::Other(
+            "--from-op and --from-pass take only KEY".to_string(),
+        ));
+    };
+    reject_value_as_key(key)?;
+    let value = fetch::fetch(source)?;
+    set(key, &value, force, desc, vault)
+}
+
+/// Set several secrets at once, saving the vault a single time.
+pub fn set_many(pairs: &[(String, String)], force: bool, vault: Option<String>) -> Result<()> {
+    let vault_name = crate::cli::resolve::resolve_vault(vault.as_deref())?;
+    debug!("Setting {} secrets (force: {})", pairs.len(), force);
+    let mut v = Vault::open_vault(vault_name.as_deref())?;
+    let secrets = v.set_many(pairs, force)?;
+    for secret in &secrets {
+        output::success(&format!("set {}", secret.key()));
+    }
     Ok(())
 }
 
 /// Get a secret value.
-pub fn get(key: &str, vault: Option<String>) -> Result<()> {
+///
+/// With `mask`, only a partial preview is printed (see [`mask_value`]).
+/// With `cache_ttl`, a value read within that window is reused instead of
+/// decrypting again (see [`Vault::get_cached`]).
+pub fn get(
+    key: &str,
+    json: bool,
+    mask: bool,
+    cache_ttl: Option<std::time::Duration>,
+    vault: Option<String>,
+) -> Result<()> {
     let vault_name = crate::cli::resolve::resolve_vault(vault.as_deref())?;
     let v = Vault::open_vault(vault_name.as_deref())?;
-    let value = v.get(key)?;
+    let value = match cache_ttl {
+        Some(ttl) => v.get_cached(key, ttl)?,
+        None => v.get(key)?,
+    };
+
+    if mask {
+        output::data(mask_value(&value).as_str());
+        return Ok(());
+    }
+
+    if json {
+        #[derive(serde::Serialize)]
+        struct Output<'a> {
+            schema_version: u32,
+            key: &'a str,
+            value: &'a str,
+        }
+        let out = Zeroizing::new(serde_json::to_string(&Output {
+            schema_version: crate::cli::schema::SCHEMA_VERSION,
+            key,
+            value: value.as_str(),
+        })?);
+        output::data(&out);
+        return Ok(());
+    }
+
     // Plain output for scripting - no decoration
     output::data(value.as_str());
     Ok(())
@@ -51,26 +281,144 @@ pub fn rm(key: &str, vault: Option<String>) -> Result<()> {
     Ok(())
 }
 
+/// Rename a secret.
+pub fn rename(old: &str, new: &str, force: bool, vault: Option<String>) -> Result<()> {
+    let vault_name = crate::cli::resolve::resolve_vault(vault.as_deref())?;
+    debug!("Renaming secret: {} -> {} (force: {})", old, new, force);
+    let mut v = Vault::open_vault(vault_name.as_deref())?;
+    v.rename(old, new, force)?;
+    output::success(&format!("renamed {} to {}", old, new));
+    Ok(())
+}
+
+/// Copy a secret to a new key.
+pub fn copy(src: &str, dst: &str, force: bool, vault: Option<String>) -> Result<()> {
+    let vault_name = crate::cli::resolve::resolve_vault(vault.as_deref())?;
+    debug!("Copying secret: {} -> {} (force: {})", src, dst, force);
+    let mut v = Vault::open_vault(vault_name.as_deref())?;
+    v.copy(src, dst, force)?;
+    output::success(&format!("copied {} to {}", src, dst));
+    Ok(())
+}
+
+/// Move a secret into another vault.
+pub fn move_to(key: &str, to: &str, force: bool, vault: Option<String>) -> Result<()> {
+    let vault_name = crate::cli::resolve::resolve_vault(vault.as_deref())?;
+    debug!("Moving secret {} to vault {} (force: {})", key, to, force);
+    let mut v = Vault::open_vault(vault_name.as_deref())?;
+    v.move_to(key, Some(to), force)?;
+    output::success(&format!("moved {} to vault '{}'", key, to));
+    Ok(())
+}
+
+/// Create an alias for another secret.
+pub fn alias(name: &str, target: &str, vault: Option<String>) -> Result<()> {
+    let vault_name = crate::cli::resolve::resolve_vault(vault.as_deref())?;
+    debug!("Aliasing secret: {} -> {}", name, target);
+    let mut v = Vault::open_vault(vault_name.as_deref())?;
+    v.alias(name, target)?;
+    output::success(&format!("{} now points to {}", name, target));
+    Ok(())
+}
+
 /// List all secret keys.
-pub fn list(json: bool, vault: Option<String>) -> Result<()> {
+///
+/// Encrypted descriptions are only decrypted with `long`; otherwise they are
+/// shown as `(encrypted)`. With `group`, keys are printed under headers named
+/// after their first underscore-delimited segment (`DB_HOST` under `DB`).
+pub fn list(
+    json: bool,
+    sort: ListSort,
+    long: bool,
+    group: bool,
+    vault: Option<String>,
+) -> Result<()> {
     let vault_name = crate::cli::resolve::resolve_vault(vault.as_deref())?;
     let v = Vault::open_vault(vault_name.as_deref())?;
-    let secrets = v.list();
+    let mut secrets = v.list();
 
-    if json {
+    if sort == ListSort::Modified {
+        // Newest first; secrets without a timestamp sink to the bottom
+        secrets.sort_by_key(|s| std::cmp::Reverse(v.updated_at(s.key())));
+    }
+
+    if json && group {
+        let keys: Vec<&str> = secrets.iter().map(|s| s.key()).collect();
+        let (groups, ungrouped) = group_keys(keys.iter().copied());
+        let result = serde_json::json!({
+            "schema_version": crate::cli::schema::SCHEMA_VERSION,
+            "keys": keys,
+            "groups": groups,
+            "ungrouped": ungrouped,
+            "count": secrets.len()
+        });
+        output::data(&serde_json::to_string_pretty(&result)?);
+    } else if json {
         let keys: Vec<String> = secrets.iter().map(|s| s.key().to_string()).collect();
         let result = serde_json::json!({
+            "schema_version": crate::cli::schema::SCHEMA_VERSION,
             "keys": keys,
             "count": secrets.len()
         });
         output::data(&serde_json::to_string_pretty(&result)?);
-    } else if secrets.is_empty() {
+    } else if secrets.is_empty() && v.aliases().is_empty() {
         output::data("no secrets");
     } else {
-        for secret in secrets {
-            output::list_item(secret.key());
+        let width = secrets.iter().map(|s| s.key().len()).max().unwrap_or(0);
+        let line = |key: &str| -> Result<String> {
+            let desc = match v.description(key) {
+                Some(desc) => Some(Zeroizing::new(desc.to_string())),
+                None if long => v.decrypt_description(key)?,
+                None if v.has_encrypted_description(key) => {
+                    Some(Zeroizing::new("(encrypted)".to_string()))
+                }
+                None => None,
+            };
+            Ok(match desc {
+                Some(desc) => format!("{:<width$}  {}", key, desc.as_str(), width = width),
+                None => key.to_string(),
+            })
+        };
+
+        if group {
+            let (groups, ungrouped) = group_keys(secrets.iter().map(|s| s.key()));
+            for (name, keys) in &groups {
+                output::data(&format!("{}:", name));
+                for key in keys {
+                    output::list_item(&format!("  {}", line(key)?));
+                }
+            }
+            if !ungrouped.is_empty() {
+                output::data("(ungrouped):");
+                for key in &ungrouped {
+                    output::list_item(&format!("  {}", line(key)?));
+                }
+            }
+        } else {
+            for secret in &secrets {
+                output::list_item(&line(secret.key())?);
+            }
+        }
+        for (alias, target) in v.aliases() {
+            output::list_item(&format!("{} -> {}", alias, target));
         }
     }
 
     Ok(())
 }
+
+/// Split keys by their first underscore-delimited segment, keeping order
+/// within each group. Keys without an underscore are returned separately.
+fn group_keys<'a>(
+    keys: impl Iterator<Item = &'a str>,
+) -> (BTreeMap<&'a str, Vec<&'a str>>, Vec<&'a str>) {
+    let mut groups: BTreeMap<&str, Vec<&str>> = BTreeMap::new();
+    let mut ungrouped = Vec::new();
+    for key in keys {
+        match key.split_once('_') {
+            Some((prefix, _)) if !prefix.is_empty() => groups.entry(prefix).or_default().push(key),
+            _ => ungrouped.push(key),
+        }
+    }
+    (groups, ungrouped)
+}
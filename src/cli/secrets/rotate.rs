@@ -1,117 +1,76 @@
 //! Rotate command - rotate keypair and re-encrypt secrets.
 
+use std::io::{self, IsTerminal};
+
+use dialoguer::Password;
 use tracing::info;
 use zeroize::Zeroizing;
 
-use crate::cli::output;
+use crate::cli::{output, progress};
 use crate::core::vault::Vault;
-use crate::core::{cipher, config, store};
-use crate::error::Result;
-use std::fs;
-use std::path::PathBuf;
-
-/// Get the key archive directory.
-fn archive_dir(project_id: &str) -> Result<PathBuf> {
-    use crate::core::domain::Identity;
-    let key_dir = Identity::project_dir(project_id)?;
-    Ok(key_dir.join("archive"))
-}
+use crate::error::{Result, SecretError, ValidationError};
 
-/// Archive the old identity key with timestamp.
-///
-/// Uses atomic rename and handles missing files gracefully to avoid TOCTOU races.
-fn archive_old_key(project_id: &str) -> Result<()> {
-    use crate::core::domain::Identity;
-    let old_key_path = Identity::project_dir(project_id)?.join("identity.key");
-
-    let archive_path = archive_dir(project_id)?;
-    fs::create_dir_all(&archive_path)?;
-
-    let timestamp = chrono::Utc::now().format("%Y%m%d_%H%M%S");
-    let archive_file = archive_path.join(format!("identity.key.{}", timestamp));
-
-    // Try to rename directly - handles TOCTOU by checking error instead of exists()
-    match fs::rename(&old_key_path, &archive_file) {
-        Ok(()) => Ok(()),
-        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()), // No key to archive
-        Err(e) => Err(e.into()),
+/// Execute key rotation, or replace the values of `only` when given.
+pub fn execute(only: Option<Vec<String>>, vault: Option<String>) -> Result<()> {
+    let vault_name = crate::cli::resolve::resolve_vault(vault.as_deref())?;
+
+    if let Some(keys) = only {
+        return rotate_secrets(&keys, vault_name.as_deref());
     }
-}
 
-/// Execute key rotation.
-pub fn execute(vault: Option<String>) -> Result<()> {
-    let vault_name = crate::cli::resolve::resolve_vault(vault.as_deref())?;
     info!("Starting key rotation");
 
-    // Verify access and pick the effective identity for decryption.
-    let v = Vault::open_vault(vault_name.as_deref())?;
-    let old_public_key = v.identity().public_key();
+    let mut v = Vault::open_vault(vault_name.as_deref())?;
 
-    // Load config
-    let mut cfg = config::Config::load_from(vault_name.as_deref())?;
-    let project_id = cfg.project_id();
-    let backend = cipher::CipherBackend::from_config(&cfg)?;
+    progress::attach(&mut v);
+    let secret_count = v.config().secrets.len();
+    v.rotate_keypair()?;
 
-    // Check that we have a key to rotate
-    if !store::has_key(&project_id) {
-        return Err(crate::error::StoreError::NoPrivateKey(project_id.clone()).into());
-    }
+    output::success(&format!("rotated ({} secrets re-encrypted)", secret_count));
 
-    // Step 1: Decrypt all secrets (wrapped in Zeroizing for secure memory cleanup)
-    let mut decrypted_secrets: Vec<(String, Zeroizing<String>)> = Vec::new();
-    for (key, ciphertext) in &cfg.secrets {
-        let plaintext = backend.decrypt(ciphertext, v.identity().as_age())?;
-        decrypted_secrets.push((key.clone(), Zeroizing::new(plaintext)));
-    }
+    Ok(())
+}
 
-    // Step 2: Archive old key
-    archive_old_key(&project_id)?;
-
-    // Step 3: Generate new keypair
-    let new_public_key = store::generate_keypair(&project_id)?;
-
-    // Step 4: Build recipient set with rotated owner key.
-    let mut recipients: Vec<String> = cfg
-        .recipients
-        .values()
-        .map(|key| {
-            if key == &old_public_key {
-                new_public_key.clone()
-            } else {
-                key.clone()
-            }
-        })
-        .collect();
-    if !recipients.iter().any(|key| key == &new_public_key) {
-        recipients.push(new_public_key.clone());
-    }
-    recipients.sort();
-    recipients.dedup();
-
-    // Step 5: Re-encrypt all secrets
-    let secret_count = decrypted_secrets.len();
-    cfg.secrets.clear();
-    for (key, plaintext) in &decrypted_secrets {
-        let ciphertext = backend.encrypt(plaintext.as_str(), &recipients)?;
-        cfg.secrets.insert(key.clone(), ciphertext);
+/// Prompt for fresh values of `keys` and store them in place of the old ones.
+///
+/// Every key is checked before anything is prompted for, and nothing is
+/// written until all new values have been read.
+fn rotate_secrets(keys: &[String], vault_name: Option<&str>) -> Result<()> {
+    let mut vault = Vault::open_vault(vault_name)?;
+
+    let existing: Vec<String> = vault.config().secrets.keys().cloned().collect();
+    for key in keys {
+        if !existing.contains(key) {
+            return Err(SecretError::not_found_with_suggestions(key.clone(), &existing).into());
+        }
     }
-    // decrypted_secrets dropped here, Zeroizing will securely clear memory
-
-    // Step 6: Update config with new public key
-    let owner_name = cfg
-        .recipients
-        .iter()
-        .find(|(_, key)| *key == &old_public_key)
-        .map(|(name, _)| name.clone())
-        .or_else(|| cfg.recipients.keys().next().cloned());
-
-    if let Some(name) = owner_name {
-        cfg.recipients.insert(name, new_public_key.clone());
+
+    let interactive = io::stdin().is_terminal();
+    let mut values: Vec<(String, Zeroizing<String>)> = Vec::with_capacity(keys.len());
+    for key in keys {
+        let value = if interactive {
+            // Interactive prompt with hidden input
+            Zeroizing::new(
+                Password::new()
+                    .with_prompt(format!("New value for {}", key))
+                    .interact()?,
+            )
+        } else {
+            // One value per line from piped input; only the line ending is dropped
+            let mut input = Zeroizing::new(String::new());
+            io::stdin().read_line(&mut input)?;
+            Zeroizing::new(input.trim_end_matches(['\n', '\r']).to_string())
+        };
+
+        if value.is_empty() {
+            return Err(ValidationError::EmptyValue(key.clone()).into());
+        }
+        values.push((key.clone(), value));
     }
 
-    cfg.save_to(vault_name.as_deref())?;
+    vault.set_many(&values, true)?;
 
-    output::success(&format!("rotated ({} secrets re-encrypted)", secret_count));
+    output::success(&format!("rotated {} secrets", values.len()));
 
     Ok(())
 }
@@ -1,13 +1,59 @@
 //! Unlock command - decrypt secrets to .env file.
 
+use std::path::Path;
+
 use crate::cli::output;
+use crate::cli::{LineEndingArg, QuoteArg};
+use crate::core::constants;
+use crate::core::domain::LineEnding;
 use crate::error::Result;
 
-/// Unlock secrets to .env file.
-pub fn execute(vault: Option<String>) -> Result<()> {
+/// Unlock secrets to .env file, or to `to` when given.
+///
+/// An existing `to` file is only replaced with `force`. With `backup`, the
+/// file being replaced is first moved to `<name>.bak`.
+pub fn execute(
+    line_ending: Option<LineEndingArg>,
+    quote: QuoteArg,
+    pattern: Option<&str>,
+    to: Option<&Path>,
+    force: bool,
+    backup: bool,
+    vault: Option<String>,
+) -> Result<()> {
     let vault_name = crate::cli::resolve::resolve_vault(vault.as_deref())?;
     let v = crate::core::vault::Vault::open_vault(vault_name.as_deref())?;
-    let env = v.unlock()?;
-    output::success(&format!("unlocked to .env ({} secrets)", env.len()));
+    let line_ending = match line_ending {
+        Some(LineEndingArg::Lf) => LineEnding::Lf,
+        Some(LineEndingArg::Crlf) => LineEnding::Crlf,
+        Some(LineEndingArg::Native) => LineEnding::Native,
+        None => v.config().dugout.env_line_ending.unwrap_or_default(),
+    };
+    let quoting = super::quoting(quote);
+    let target = to.unwrap_or(Path::new(constants::ENV_FILE));
+    let replacing = backup && target.exists();
+    let env = match to {
+        Some(path) => v.unlock_to(path, line_ending, quoting, pattern, force, backup)?,
+        None if backup => v.unlock_to(
+            Path::new(constants::ENV_FILE),
+            line_ending,
+            quoting,
+            pattern,
+            true,
+            true,
+        )?,
+        None => v.unlock_with(line_ending, quoting, pattern)?,
+    };
+    if replacing {
+        output::note(&format!(
+            "previous file saved to {}",
+            env.backup_path().display()
+        ));
+    }
+    output::success(&format!(
+        "unlocked to {} ({} secrets)",
+        env.path().display(),
+        env.len()
+    ));
     Ok(())
 }
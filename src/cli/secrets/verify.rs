@@ -0,0 +1,31 @@
+//! Verify command - confirm every secret decrypts with the current identity.
+
+use crate::cli::output;
+use crate::core::vault::Vault;
+use crate::error::{Error, Result};
+
+/// Try to decrypt each secret and report the ones that fail.
+pub fn execute(vault: Option<String>) -> Result<()> {
+    let vault_name = crate::cli::resolve::resolve_vault(vault.as_deref())?;
+    let v = Vault::open_vault(vault_name.as_deref())?;
+    let results = v.verify_all()?;
+
+    let failed: Vec<_> = results
+        .iter()
+        .filter_map(|(key, err)| err.as_ref().map(|err| (key, err)))
+        .collect();
+
+    if failed.is_empty() {
+        output::success(&format!("verified {} secrets", results.len()));
+        return Ok(());
+    }
+
+    for (key, err) in &failed {
+        output::list_item(&format!("{}: {}", key, err));
+    }
+    Err(Error::Other(format!(
+        "{} of {} secrets failed to decrypt",
+        failed.len(),
+        results.len()
+    )))
+}
@@ -0,0 +1,30 @@
+//! Selftest command - verify the compiled-in cipher backends work.
+
+use crate::cli::output;
+use crate::core::cipher::selftest;
+use crate::error::{Error, Result};
+
+/// Run the cipher self-test and report each check.
+pub fn execute() -> Result<()> {
+    let checks = selftest::run();
+
+    for check in &checks {
+        let line = format!("{}: {}", check.name, check.detail);
+        if check.skipped {
+            output::warn(&format!("{} (skipped)", line));
+        } else if check.passed {
+            output::success(&line);
+        } else {
+            output::error(&line);
+        }
+    }
+
+    let failed = checks.iter().filter(|c| !c.passed).count();
+    if failed > 0 {
+        return Err(Error::Other(format!(
+            "self-test failed ({} checks)",
+            failed
+        )));
+    }
+    Ok(())
+}
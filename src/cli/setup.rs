@@ -1,12 +1,45 @@
 //! Setup command - generate global identity.
 
+use std::io::IsTerminal;
+
+use age::secrecy::SecretString;
+use dialoguer::Password;
+
 use crate::cli::output;
+use crate::core::constants;
 use crate::core::domain::Identity;
 use crate::core::store;
-use crate::error::Result;
+use crate::error::{Error, Result, StoreError};
+
+/// Read a new passphrase from `DUGOUT_PASSPHRASE`, or prompt twice on a terminal.
+fn new_passphrase() -> Result<SecretString> {
+    let passphrase = match std::env::var(constants::PASSPHRASE_ENV) {
+        Ok(value) => value,
+        Err(_) if std::io::stdin().is_terminal() => Password::new()
+            .with_prompt("Passphrase")
+            .with_confirmation("Confirm passphrase", "passphrases don't match")
+            .interact()?,
+        Err(_) => {
+            return Err(Error::Other(format!(
+                "no terminal to prompt for a passphrase\n  → Set {} instead",
+                constants::PASSPHRASE_ENV
+            )))
+        }
+    };
+
+    if passphrase.is_empty() {
+        return Err(Error::Other("passphrase cannot be empty".to_string()));
+    }
+    Ok(SecretString::from(passphrase))
+}
 
 /// Generate global identity.
-pub fn execute(force: bool, _name: Option<String>, output_path: Option<String>) -> Result<()> {
+pub fn execute(
+    force: bool,
+    _name: Option<String>,
+    output_path: Option<String>,
+    passphrase: bool,
+) -> Result<()> {
     // Check if identity already exists
     if store::has_global()? && !force {
         let pubkey = Identity::load_global_pubkey()?;
@@ -16,13 +49,20 @@ pub fn execute(force: bool, _name: Option<String>, output_path: Option<String>)
         return Ok(());
     }
 
-    let identity = Identity::generate_global()?;
+    let identity = if passphrase {
+        Identity::generate_global_protected(new_passphrase()?)?
+    } else {
+        Identity::generate_global()?
+    };
     let pubkey = identity.public_key();
 
     // Output private key if requested
     if let Some(ref path) = output_path {
         use age::secrecy::ExposeSecret;
-        let secret = identity.as_age().to_string();
+        let secret = identity
+            .as_x25519()
+            .ok_or_else(|| StoreError::InvalidFormat("not an age identity".to_string()))?
+            .to_string();
         let key_str = secret.expose_secret();
 
         if path == "-" {
@@ -44,7 +84,11 @@ pub fn execute(force: bool, _name: Option<String>, output_path: Option<String>)
         output::success(&format!("private key written to {}", path));
     }
 
-    output::success("generated identity");
+    if passphrase {
+        output::success("generated passphrase-protected identity");
+    } else {
+        output::success("generated identity");
+    }
     output::hint(&format!("public key: {}", pubkey));
 
     Ok(())
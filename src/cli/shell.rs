@@ -1,5 +1,6 @@
 //! Interactive shell command - spawn shell with secrets loaded.
 
+use crate::cli::{output, HookShell};
 use crate::core::vault::Vault;
 use crate::error::Result;
 use zeroize::Zeroizing;
@@ -24,3 +25,48 @@ pub fn execute(vault: Option<String>) -> Result<()> {
     let status = cmd.status()?;
     std::process::exit(status.code().unwrap_or(0));
 }
+
+/// Print secrets as shell export statements for `eval`.
+///
+/// Also records the exported key names in `_DUGOUT_KEYS` so the shell
+/// hook can unset them again when leaving the project.
+pub fn export(shell: HookShell, vault: Option<String>) -> Result<()> {
+    let vault_name = crate::cli::resolve::resolve_vault(vault.as_deref())?;
+    let v = Vault::open_vault(vault_name.as_deref())?;
+    let pairs = v.decrypt_all()?;
+
+    let mut script = Zeroizing::new(String::new());
+    let mut keys = Vec::with_capacity(pairs.len());
+    for (key, value) in pairs {
+        let line = match shell {
+            HookShell::Bash | HookShell::Zsh => {
+                format!("export {}={}\n", key, quote_posix(&value))
+            }
+            HookShell::Fish => format!("set -gx {} {}\n", key, quote_fish(&value)),
+        };
+        script.push_str(&Zeroizing::new(line));
+        keys.push(key);
+    }
+
+    match shell {
+        HookShell::Bash | HookShell::Zsh => {
+            script.push_str(&format!("_DUGOUT_KEYS={}\n", quote_posix(&keys.join(" "))));
+        }
+        HookShell::Fish => {
+            script.push_str(&format!("set -g _DUGOUT_KEYS {}\n", keys.join(" ")));
+        }
+    }
+
+    output::raw(&script);
+    Ok(())
+}
+
+/// Single-quote a value for POSIX shells.
+pub(crate) fn quote_posix(value: &str) -> String {
+    format!("'{}'", value.replace('\'', r"'\''"))
+}
+
+/// Single-quote a value for fish.
+fn quote_fish(value: &str) -> String {
+    format!("'{}'", value.replace('\\', r"\\").replace('\'', r"\'"))
+}
@@ -2,25 +2,72 @@
 
 use tracing::info;
 
-use crate::cli::output;
+use crate::cli::{output, progress};
 use crate::core::vault::Vault;
 use crate::error::Result;
 
 /// Sync secrets for the current recipient set.
-pub fn execute(dry_run: bool, force: bool, vault: Option<String>) -> Result<()> {
+///
+/// With `prune_expired`, team members whose expiry has passed are removed
+/// from the vault first.
+pub fn execute(
+    dry_run: bool,
+    force: bool,
+    prune_expired: bool,
+    vault: Option<String>,
+) -> Result<()> {
     let vault_name = crate::cli::resolve::resolve_vault(vault.as_deref())?;
-    info!(dry_run, force, "running sync");
+    info!(dry_run, force, prune_expired, "running sync");
 
     let mut v = Vault::open_vault(vault_name.as_deref())?;
 
+    progress::attach(&mut v);
+
+    let expired = v.expired_recipients();
+    if !expired.is_empty() {
+        if dry_run && prune_expired {
+            output::warn(&format!("would remove expired: {}", expired.join(", ")));
+        } else if prune_expired {
+            v.prune_expired()?;
+            output::success(&format!("removed expired: {}", expired.join(", ")));
+        } else {
+            output::warn(&format!(
+                "expired: {} (left out of encryption)",
+                expired.join(", ")
+            ));
+            output::hint("run: dugout sync --prune-expired to remove them");
+        }
+    }
+
     if dry_run {
-        if v.needs_sync() || force {
-            let secrets = v.config().secrets.len();
+        let plan = v.sync_plan(force);
+        if plan.status.needs_sync() || force {
             let recipients = v.config().recipients.len();
             output::warn(&format!(
                 "would sync ({} secrets, {} recipients)",
-                secrets, recipients
+                plan.secrets.len(),
+                recipients
             ));
+            if plan.status.needs_sync() {
+                output::kv("reason", plan.status);
+            } else {
+                output::kv("reason", "--force");
+            }
+            for name in &plan.added {
+                output::data(&format!("+ {}", name));
+            }
+            for name in &plan.removed {
+                output::data(&format!("- {}", name));
+            }
+            for name in &plan.rekeyed {
+                output::data(&format!("~ {} (new key)", name));
+            }
+            if !plan.snapshot && plan.status.needs_sync() {
+                output::hint("recipient changes unknown: the last sync stored no snapshot");
+            }
+            if !plan.secrets.is_empty() {
+                output::kv("re-encrypt", plan.secrets.join(", "));
+            }
         } else {
             output::success("already in sync");
         }
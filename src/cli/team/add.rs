@@ -1,17 +1,39 @@
 //! Team add command - add a team member.
 
+use chrono::{DateTime, Utc};
 use tracing::info;
 
-use crate::cli::output;
+use crate::cli::{output, progress};
 use crate::core::vault::Vault;
 use crate::error::Result;
 
-/// Add a team member.
-pub fn execute(name: &str, key: &str, vault: Option<String>) -> Result<()> {
+/// Add a team member, optionally with an expiry and a note.
+pub fn execute(
+    name: &str,
+    key: &str,
+    expires: Option<DateTime<Utc>>,
+    note: Option<&str>,
+    vault: Option<String>,
+) -> Result<()> {
     let vault_name = crate::cli::resolve::resolve_vault(vault.as_deref())?;
     info!("Adding team member: {}", name);
     let mut v = Vault::open_vault(vault_name.as_deref())?;
-    v.add_recipient(name, key)?;
-    output::success(&format!("added {}", name));
+    progress::attach(&mut v);
+    match expires {
+        Some(expires) => v.add_recipient_with_expiry(name, key, expires)?,
+        None => v.add_recipient(name, key)?,
+    }
+    if let Some(note) = note {
+        v.set_recipient_note(name, note)?;
+    }
+
+    match expires {
+        Some(expires) => output::success(&format!(
+            "added {} (expires {})",
+            name,
+            expires.format("%Y-%m-%d %H:%M UTC")
+        )),
+        None => output::success(&format!("added {}", name)),
+    }
     Ok(())
 }
@@ -0,0 +1,57 @@
+//! Team export command - write recipient public keys for other tools.
+
+use super::import::Roster;
+use crate::cli::{output, TeamExportFormat};
+use crate::core::cipher;
+use crate::core::vault::Vault;
+use crate::error::{ConfigError, Result};
+
+/// Print the team's public keys in the given format.
+///
+/// `age-recipients` is one key per line, as read by `age -R` and sops, with
+/// a `# name` comment above each unless `comments` is false. Keys that
+/// aren't age or SSH recipients are skipped with a note on stderr. `json`
+/// and `toml` write the `{name: key}` roster read by `team import`.
+pub fn execute(format: TeamExportFormat, comments: bool, vault: Option<String>) -> Result<()> {
+    let vault_name = crate::cli::resolve::resolve_vault(vault.as_deref())?;
+    let v = Vault::open_vault(vault_name.as_deref())?;
+
+    let out = match format {
+        TeamExportFormat::AgeRecipients => {
+            let mut out = String::new();
+            for (name, key) in &v.config().recipients {
+                if cipher::parse_recipient(key).is_err() {
+                    output::note(&format!("skipping {}: not an age or SSH recipient", name));
+                    continue;
+                }
+                if comments {
+                    out.push_str(&format!("# {}\n", name));
+                }
+                out.push_str(key.trim());
+                out.push('\n');
+            }
+            out
+        }
+        TeamExportFormat::Json => {
+            let roster = Roster {
+                recipients: v.config().recipients.clone(),
+            };
+            format!("{}\n", serde_json::to_string_pretty(&roster)?)
+        }
+        TeamExportFormat::Toml => {
+            let roster = Roster {
+                recipients: v.config().recipients.clone(),
+            };
+            toml::to_string(&roster).map_err(ConfigError::Serialize)?
+        }
+    };
+
+    if let Some(key) = v.config().kms_key() {
+        output::note(&format!(
+            "KMS key {} is not an age recipient and is not included",
+            key
+        ));
+    }
+    output::raw(&out);
+    Ok(())
+}
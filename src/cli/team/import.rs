@@ -0,0 +1,53 @@
+//! Team import command - add recipients from an exported roster.
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+use crate::cli::{output, progress};
+use crate::core::types::{MemberName, PublicKey};
+use crate::core::vault::Vault;
+use crate::error::{ConfigError, Result};
+
+/// A team roster as written by `team export --format json|toml`.
+#[derive(Serialize, Deserialize)]
+pub(crate) struct Roster {
+    pub(crate) recipients: BTreeMap<MemberName, PublicKey>,
+}
+
+/// Add the recipients listed in `file`, or make them the whole team with `replace`.
+pub fn execute(file: &str, replace: bool, vault: Option<String>) -> Result<()> {
+    let vault_name = crate::cli::resolve::resolve_vault(vault.as_deref())?;
+    info!("Importing team roster from {}", file);
+
+    let contents = std::fs::read_to_string(file)?;
+    let roster = parse(&contents)?;
+
+    let mut v = Vault::open_vault(vault_name.as_deref())?;
+
+    progress::attach(&mut v);
+    let (added, removed) = v.import_recipients(&roster.recipients, replace)?;
+
+    if added.is_empty() && removed.is_empty() {
+        output::success("team already matches");
+        return Ok(());
+    }
+    if !added.is_empty() {
+        output::success(&format!("added {}", added.join(", ")));
+    }
+    if !removed.is_empty() {
+        output::success(&format!("removed {}", removed.join(", ")));
+    }
+    Ok(())
+}
+
+/// Parse a JSON or TOML roster.
+fn parse(contents: &str) -> Result<Roster> {
+    if contents.trim_start().starts_with('{') {
+        serde_json::from_str(contents)
+            .map_err(|e| ConfigError::Other(format!("invalid roster: {}", e)).into())
+    } else {
+        toml::from_str(contents).map_err(|e| ConfigError::Parse(e).into())
+    }
+}
@@ -16,12 +16,17 @@ pub fn execute(json: bool, vault: Option<String>) -> Result<()> {
             .map(|r| {
                 serde_json::json!({
                     "name": r.name(),
-                    "public_key": r.public_key()
+                    "public_key": r.public_key(),
+                    "role": v.role_of(r.name()).as_str(),
+                    "note": v.recipient_note(r.name()),
+                    "expires": v.recipient_expiry(r.name()).map(|t| t.to_rfc3339()),
+                    "expired": v.config().is_expired(r.name())
                 })
             })
             .collect();
 
         let result = serde_json::json!({
+            "schema_version": crate::cli::schema::SCHEMA_VERSION,
             "members": members_json,
             "count": members.len()
         });
@@ -35,7 +40,34 @@ pub fn execute(json: bool, vault: Option<String>) -> Result<()> {
             } else {
                 recipient.public_key().to_string()
             };
-            println!("{:<15} {}", recipient.name(), truncated);
+            let expiry = match v.recipient_expiry(recipient.name()) {
+                Some(t) if v.config().is_expired(recipient.name()) => {
+                    format!("  expired {}", t.format("%Y-%m-%d"))
+                }
+                Some(t) => format!("  expires {}", t.format("%Y-%m-%d")),
+                None => String::new(),
+            };
+            let note = v
+                .recipient_note(recipient.name())
+                .map(|note| format!("  # {}", note))
+                .unwrap_or_default();
+            println!(
+                "{:<15} {:<7} {}{}{}",
+                recipient.name(),
+                v.role_of(recipient.name()),
+                truncated,
+                expiry,
+                note
+            );
+        }
+
+        let expired = v.expired_recipients();
+        if !expired.is_empty() {
+            output::warn(&format!(
+                "expired: {} (no longer encrypted for)",
+                expired.join(", ")
+            ));
+            output::hint("run: dugout sync --prune-expired");
         }
     }
 
@@ -1,12 +1,19 @@
 //! Team management commands.
 //!
-//! Add, list, and remove team members (recipients).
+//! Add, list, export, import, and remove team members (recipients), and set
+//! their roles.
 
 mod add;
+mod export;
+mod import;
 mod list;
 mod rm;
+mod role;
 
 // Re-export command functions
 pub use add::execute as add;
+pub use export::execute as export;
+pub use import::execute as import;
 pub use list::execute as list;
 pub use rm::execute as rm;
+pub use role::execute as role;
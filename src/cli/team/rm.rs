@@ -1,6 +1,6 @@
 //! Team remove command - remove a team member.
 
-use crate::cli::output;
+use crate::cli::{output, progress};
 use crate::core::vault::Vault;
 use crate::error::Result;
 
@@ -8,6 +8,7 @@ use crate::error::Result;
 pub fn execute(name: &str, vault: Option<String>) -> Result<()> {
     let vault_name = crate::cli::resolve::resolve_vault(vault.as_deref())?;
     let mut v = Vault::open_vault(vault_name.as_deref())?;
+    progress::attach(&mut v);
     v.remove_recipient(name)?;
     output::success(&format!("removed {}", name));
     Ok(())
@@ -0,0 +1,18 @@
+//! Team role command - set a member's role.
+
+use tracing::info;
+
+use crate::cli::output;
+use crate::core::domain::Role;
+use crate::core::vault::Vault;
+use crate::error::Result;
+
+/// Set a team member's role.
+pub fn execute(name: &str, role: Role, vault: Option<String>) -> Result<()> {
+    let vault_name = crate::cli::resolve::resolve_vault(vault.as_deref())?;
+    info!("Setting role of {} to {}", name, role);
+    let mut v = Vault::open_vault(vault_name.as_deref())?;
+    v.set_role(name, role)?;
+    output::success(&format!("{} is now {}", name, role));
+    Ok(())
+}
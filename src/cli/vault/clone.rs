@@ -0,0 +1,27 @@
+//! Vault clone command - fork a vault under a new name.
+
+use tracing::debug;
+
+use crate::cli::output;
+use crate::cli::resolve::validate_vault_name_for_init;
+use crate::core::vault::Vault;
+use crate::error::Result;
+
+/// Copy the selected vault's recipients and secrets into a new vault.
+pub fn execute(new_name: &str, vault: Option<String>) -> Result<()> {
+    validate_vault_name_for_init(new_name)?;
+    let vault_name = crate::cli::resolve::resolve_vault(vault.as_deref())?;
+    debug!("Cloning vault {:?} to {}", vault_name, new_name);
+
+    let v = Vault::open_vault(vault_name.as_deref())?;
+    v.clone_to(new_name)?;
+
+    output::success(&format!(
+        "cloned vault '{}' to '{}' ({} secrets, {} recipients)",
+        vault_name.as_deref().unwrap_or("default"),
+        new_name,
+        v.config().secrets.len(),
+        v.config().recipients.len()
+    ));
+    Ok(())
+}
@@ -8,14 +8,8 @@ use crate::error::Result;
 pub fn execute(json: bool) -> Result<()> {
     let vaults = Vault::list_vaults()?;
 
-    if vaults.is_empty() {
-        output::data("no vaults found");
-        output::hint("run: dugout init");
-        return Ok(());
-    }
-
     if json {
-        let json_output: Vec<_> = vaults
+        let vaults_json: Vec<_> = vaults
             .iter()
             .map(|v| {
                 serde_json::json!({
@@ -27,7 +21,15 @@ pub fn execute(json: bool) -> Result<()> {
                 })
             })
             .collect();
-        println!("{}", serde_json::to_string_pretty(&json_output)?);
+        let result = serde_json::json!({
+            "schema_version": crate::cli::schema::SCHEMA_VERSION,
+            "vaults": vaults_json,
+            "count": vaults.len()
+        });
+        println!("{}", serde_json::to_string_pretty(&result)?);
+    } else if vaults.is_empty() {
+        output::data("no vaults found");
+        output::hint("run: dugout init");
     } else {
         // Calculate column width based on longest vault name (min 7 for "default")
         let name_width = vaults
@@ -1,3 +1,5 @@
 //! Vault management commands.
 
+pub mod clone;
 pub mod list;
+pub mod rm;
@@ -0,0 +1,66 @@
+//! Vault rm command - delete a vault file.
+
+use std::io::{self, IsTerminal};
+
+use dialoguer::Confirm;
+use tracing::debug;
+
+use crate::cli::output;
+use crate::cli::resolve::validate_vault_name;
+use crate::core::config::Config;
+use crate::core::constants;
+use crate::error::{ConfigError, Result};
+
+/// Delete a vault's file, its secrets sidecar, and its pending requests.
+///
+/// The default vault is only removed when named as `default`. Files that
+/// don't parse as a dugout config are left alone.
+pub fn execute(name: &str, force: bool) -> Result<()> {
+    validate_vault_name(name)?;
+    let vault = (name != "default").then_some(name);
+    let path = Config::config_path_for(vault);
+
+    if !path.exists() {
+        return Err(ConfigError::Other(format!("no vault named '{}'", name)).into());
+    }
+    if Config::load_from(vault).is_err() {
+        return Err(ConfigError::Other(format!(
+            "{} doesn't parse as a dugout vault; refusing to delete it",
+            path.display()
+        ))
+        .into());
+    }
+
+    if !force {
+        if !io::stdin().is_terminal() {
+            return Err(ConfigError::Other(format!(
+                "refusing to delete vault '{}' without confirmation\n  → Pass --force to delete it non-interactively",
+                name
+            ))
+            .into());
+        }
+        let confirmed = Confirm::new()
+            .with_prompt(format!("Delete vault '{}' ({})?", name, path.display()))
+            .default(false)
+            .interact()?;
+        if !confirmed {
+            output::hint("Cancelled");
+            return Ok(());
+        }
+    }
+
+    debug!(path = %path.display(), "removing vault");
+    std::fs::remove_file(&path)?;
+
+    let sidecar = constants::sidecar_path(vault);
+    if sidecar.exists() {
+        std::fs::remove_file(&sidecar)?;
+    }
+    let requests = constants::request_dir(vault);
+    if requests.exists() {
+        std::fs::remove_dir_all(&requests)?;
+    }
+
+    output::success(&format!("removed vault '{}'", name));
+    Ok(())
+}
@@ -1,12 +1,16 @@
-//! Whoami command - print your public key.
+//! Whoami command - print your public key and vault access.
 
 use crate::cli::output;
 use crate::core::domain::Identity;
 use crate::core::store;
+use crate::core::vault::Vault;
 use crate::error::Result;
 
 /// Print your public key.
-pub fn execute() -> Result<()> {
+///
+/// With `all`, also list each vault in this repository and whether your
+/// key is one of its recipients.
+pub fn execute(all: bool) -> Result<()> {
     if !store::has_global()? {
         output::error("no identity found");
         output::hint("run: dugout setup");
@@ -18,5 +22,12 @@ pub fn execute() -> Result<()> {
     let pubkey = Identity::load_global_pubkey()?;
     output::data(&pubkey);
 
+    if all {
+        for v in Vault::list_vaults()? {
+            let mark = if v.has_access { "✓" } else { "✗" };
+            output::data(&format!("{} {}", mark, v.name));
+        }
+    }
+
     Ok(())
 }
@@ -0,0 +1,95 @@
+//! Short-lived read cache for `dugout get --cache-ttl`.
+//!
+//! Entries live under `~/.dugout/cache/<project>/`, one file per secret.
+//! Each value is re-encrypted with age to the reader's own key, so a hit
+//! costs one local age decryption and never reaches KMS. Entries are named
+//! by a hash of the recipients fingerprint, the secret key, and its stored
+//! ciphertext, so any change to the vault is a miss rather than a stale read.
+
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use sha2::{Digest, Sha256};
+use tracing::debug;
+use zeroize::Zeroizing;
+
+use crate::core::cipher::{parse_recipient, Age, Cipher};
+use crate::core::constants;
+use crate::core::domain::Identity;
+use crate::error::{Error, Result};
+
+/// Name of the cache entry for `key` stored as `ciphertext`.
+pub fn entry_name(fingerprint: &str, key: &str, ciphertext: &str) -> String {
+    let mut hasher = Sha256::new();
+    for part in [fingerprint, key, ciphertext] {
+        hasher.update(part.as_bytes());
+        hasher.update([0]);
+    }
+    format!("{:x}", hasher.finalize())
+}
+
+/// Path of a cache entry.
+fn path(project_id: &str, entry: &str) -> Result<PathBuf> {
+    Ok(Identity::resolve_home()?
+        .join(constants::CACHE_DIR)
+        .join(project_id)
+        .join(entry))
+}
+
+/// Read a cached value, if present and not expired.
+///
+/// Expired entries are removed. Unreadable entries are treated as misses.
+pub fn load(project_id: &str, entry: &str, identity: &Identity) -> Option<Zeroizing<String>> {
+    let path = path(project_id, entry).ok()?;
+    let contents = fs::read_to_string(&path).ok()?;
+    let (expires, ciphertext) = contents.split_once('\n')?;
+    let expires = DateTime::parse_from_rfc3339(expires).ok()?;
+
+    if expires <= Utc::now() {
+        debug!(entry = %entry, "cache entry expired");
+        let _ = fs::remove_file(&path);
+        return None;
+    }
+    match Age.decrypt(ciphertext, identity.as_age()) {
+        Ok(value) => Some(Zeroizing::new(value)),
+        Err(e) => {
+            debug!(error = %e, "unreadable cache entry");
+            None
+        }
+    }
+}
+
+/// Cache `value` for `ttl`, encrypted to `identity`'s own key.
+pub fn store(
+    project_id: &str,
+    entry: &str,
+    value: &str,
+    identity: &Identity,
+    ttl: Duration,
+) -> Result<()> {
+    let recipient = parse_recipient(&identity.public_key())?;
+    let ciphertext = Age.encrypt(value, &[recipient])?;
+    let ttl = chrono::Duration::from_std(ttl)
+        .map_err(|e| Error::Other(format!("invalid cache TTL: {}", e)))?;
+    let expires = Utc::now()
+        .checked_add_signed(ttl)
+        .ok_or_else(|| Error::Other("cache TTL is too large".to_string()))?;
+
+    let path = path(project_id, entry)?;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let mut options = OpenOptions::new();
+    options.create(true).write(true).truncate(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    let mut file = options.open(&path)?;
+    write!(file, "{}\n{}", expires.to_rfc3339(), ciphertext)?;
+    Ok(())
+}
@@ -19,8 +19,12 @@
 //! The AWS KMS backend uses AWS credentials from the environment (AWS_ACCESS_KEY_ID, etc.)
 //! or from the default credential provider chain.
 
+#[cfg(feature = "aws")]
+use std::sync::OnceLock;
 use tracing::trace;
 
+#[cfg(feature = "aws")]
+use crate::core::cipher::envelope::{KmsBackend, KmsProvider};
 use crate::core::cipher::Cipher;
 use crate::error::{CipherError, Result};
 
@@ -31,8 +35,11 @@ use crate::error::{CipherError, Result};
 /// doesn't require specifying the key ID.
 #[cfg(feature = "aws")]
 #[allow(dead_code)]
+#[derive(Debug)]
 pub struct AwsKms {
     key_id: String,
+    /// Runtime and SDK client, built on first use and reused after
+    sdk: OnceLock<(tokio::runtime::Runtime, aws_sdk_kms::Client)>,
 }
 
 #[cfg(feature = "aws")]
@@ -40,7 +47,34 @@ impl AwsKms {
     /// Create a new AWS KMS cipher with the specified key ID or ARN
     #[allow(dead_code)]
     pub fn new(key_id: String) -> Self {
-        Self { key_id }
+        Self {
+            key_id,
+            sdk: OnceLock::new(),
+        }
+    }
+
+    /// Runtime and KMS client, loading the AWS config on first call.
+    fn sdk(
+        &self,
+        failed: fn(String) -> CipherError,
+    ) -> Result<&(tokio::runtime::Runtime, aws_sdk_kms::Client)> {
+        if let Some(sdk) = self.sdk.get() {
+            return Ok(sdk);
+        }
+
+        // Create a tokio runtime for the async AWS SDK
+        let rt = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .map_err(|e| failed(format!("failed to create runtime: {}", e)))?;
+
+        // Load AWS config from environment
+        let config = rt.block_on(aws_config::load_defaults(
+            aws_config::BehaviorVersion::latest(),
+        ));
+        let client = aws_sdk_kms::Client::new(&config);
+        trace!("created AWS KMS client");
+        Ok(self.sdk.get_or_init(|| (rt, client)))
     }
 }
 
@@ -64,19 +98,8 @@ impl Cipher for AwsKms {
             "encrypting with AWS KMS"
         );
 
-        // Create a tokio runtime for the async AWS SDK
-        let rt = tokio::runtime::Builder::new_current_thread()
-            .enable_all()
-            .build()
-            .map_err(|e| {
-                CipherError::EncryptionFailed(format!("failed to create runtime: {}", e))
-            })?;
-
+        let (rt, client) = self.sdk(CipherError::EncryptionFailed)?;
         rt.block_on(async {
-            // Load AWS config from environment
-            let config = aws_config::load_defaults(aws_config::BehaviorVersion::latest()).await;
-            let client = aws_sdk_kms::Client::new(&config);
-
             // Encrypt the plaintext
             let result = client
                 .encrypt()
@@ -108,19 +131,8 @@ impl Cipher for AwsKms {
             .decode(ciphertext)
             .map_err(|e| CipherError::DecryptionFailed(format!("invalid base64: {}", e)))?;
 
-        // Create a tokio runtime for the async AWS SDK
-        let rt = tokio::runtime::Builder::new_current_thread()
-            .enable_all()
-            .build()
-            .map_err(|e| {
-                CipherError::DecryptionFailed(format!("failed to create runtime: {}", e))
-            })?;
-
+        let (rt, client) = self.sdk(CipherError::DecryptionFailed)?;
         rt.block_on(async {
-            // Load AWS config from environment
-            let config = aws_config::load_defaults(aws_config::BehaviorVersion::latest()).await;
-            let client = aws_sdk_kms::Client::new(&config);
-
             // Decrypt the ciphertext
             // KMS stores the key ID in the ciphertext blob, so we don't need to specify it
             let result = client
@@ -144,3 +156,18 @@ impl Cipher for AwsKms {
         })
     }
 }
+
+#[cfg(feature = "aws")]
+impl KmsBackend for AwsKms {
+    fn encrypt(&self, plaintext: &str) -> Result<String> {
+        Cipher::encrypt(self, plaintext, &[])
+    }
+
+    fn decrypt(&self, ciphertext: &str) -> Result<String> {
+        Cipher::decrypt(self, ciphertext, &())
+    }
+
+    fn provider(&self) -> &KmsProvider {
+        &KmsProvider::Aws
+    }
+}
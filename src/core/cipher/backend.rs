@@ -2,18 +2,32 @@
 //!
 //! Two backends:
 //! - **Age** (default): secrets encrypted with age only
-//! - **Hybrid**: secrets encrypted with age + cloud KMS
+//! - **Hybrid**: secrets encrypted with age + cloud KMS or Vault transit
 
-use crate::core::config::Config;
-use crate::error::{CipherError, Result};
+use crate::core::config::{Config, TransitConfig};
+use crate::error::{CipherError, ConfigError, Result};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, OnceLock};
 use tracing::debug;
 
-use super::envelope::{Envelope, KmsProvider};
+use super::envelope::{Envelope, KmsBackend, KmsProvider};
+
+/// Hybrid backends built so far in this process, keyed by KMS target.
+static SHARED: OnceLock<Mutex<HashMap<String, Arc<CipherBackend>>>> = OnceLock::new();
+
+#[cfg(test)]
+thread_local! {
+    static CLIENT_INITS: std::cell::Cell<usize> = const { std::cell::Cell::new(0) };
+}
+
+/// KMS client of a hybrid backend, built on first use and reused after.
+type KmsClient = OnceLock<Box<dyn KmsBackend + Send + Sync>>;
 
 /// Cipher backend for vault operations.
 ///
 /// - `Age`: raw age ciphertext (default)
 /// - `Hybrid`: v2 envelope with age + cloud KMS
+/// - `VaultTransit`: v2 envelope with age + HashiCorp Vault transit
 #[derive(Debug)]
 pub enum CipherBackend {
     /// Age encryption (default)
@@ -21,12 +35,35 @@ pub enum CipherBackend {
 
     /// Hybrid: age + cloud KMS
     #[allow(dead_code)]
-    Hybrid { provider: KmsProvider, key: String },
+    Hybrid {
+        provider: KmsProvider,
+        key: String,
+        client: KmsClient,
+    },
+
+    /// Hybrid: age + HashiCorp Vault transit
+    #[allow(dead_code)]
+    VaultTransit {
+        transit: TransitConfig,
+        client: KmsClient,
+    },
 }
 
 impl CipherBackend {
     /// Create a cipher backend from configuration.
     pub fn from_config(config: &Config) -> Result<Self> {
+        if config.dugout.cipher.as_deref() == Some("vault-transit") {
+            let transit = config
+                .vault
+                .clone()
+                .ok_or(ConfigError::MissingField { field: "vault" })?;
+            debug!(address = %transit.address, key = %transit.key, "creating vault transit cipher backend");
+            return Ok(Self::VaultTransit {
+                transit,
+                client: KmsClient::new(),
+            });
+        }
+
         if let Some(kms_key) = config.kms_key() {
             debug!(kms_key = %kms_key, "creating hybrid cipher backend");
             let provider = KmsProvider::detect(kms_key).ok_or_else(|| {
@@ -38,6 +75,7 @@ impl CipherBackend {
             Ok(Self::Hybrid {
                 provider,
                 key: kms_key.to_string(),
+                client: KmsClient::new(),
             })
         } else {
             debug!("creating age cipher backend");
@@ -45,6 +83,104 @@ impl CipherBackend {
         }
     }
 
+    /// Check that the KMS half of this backend is compiled into the binary.
+    ///
+    /// Lets `init` refuse a KMS key it could never encrypt with, instead of
+    /// writing a vault whose first `set` fails.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ConfigError::BackendNotCompiled` naming the cargo feature to
+    /// rebuild with.
+    pub fn ensure_available(&self) -> Result<()> {
+        let (name, compiled) = match self {
+            Self::Age => return Ok(()),
+            Self::Hybrid { provider, .. } => (
+                provider.name(),
+                match provider {
+                    KmsProvider::Aws => cfg!(feature = "aws"),
+                    KmsProvider::Gcp => cfg!(feature = "gcp"),
+                    KmsProvider::Vault => cfg!(feature = "vault"),
+                },
+            ),
+            Self::VaultTransit { .. } => ("vault", cfg!(feature = "vault")),
+        };
+        // Test builds route every provider through the stub KMS
+        if compiled || cfg!(any(test, feature = "test-kms")) {
+            return Ok(());
+        }
+        Err(ConfigError::BackendNotCompiled(name).into())
+    }
+
+    /// Backend for `config`, shared across every vault opened in this process.
+    ///
+    /// Hybrid backends are cached by KMS key (or Vault transit target), so
+    /// opening many vaults with the same settings reuses one backend; different
+    /// settings get their own. Age backends carry no state and are never cached.
+    pub fn shared(config: &Config) -> Result<Arc<Self>> {
+        let Some(cache_key) = Self::cache_key(config) else {
+            return Self::from_config(config).map(Arc::new);
+        };
+
+        let mut cache = SHARED
+            .get_or_init(Default::default)
+            .lock()
+            .unwrap_or_else(|e| e.into_inner());
+        if let Some(backend) = cache.get(&cache_key) {
+            debug!(target = %cache_key, "reusing cached cipher backend");
+            return Ok(Arc::clone(backend));
+        }
+
+        let backend = Arc::new(Self::from_config(config)?);
+        cache.insert(cache_key, Arc::clone(&backend));
+        Ok(backend)
+    }
+
+    /// Drop the backend [`CipherBackend::shared`] cached for `config`.
+    ///
+    /// Cached backends keep their KMS client, and with it the credentials (or
+    /// Vault token) read when the client was built. Embedders that rotate
+    /// those credentials mid-process call this so the next open reconnects.
+    #[allow(dead_code)]
+    pub fn evict_shared(config: &Config) {
+        let (Some(cache), Some(cache_key)) = (SHARED.get(), Self::cache_key(config)) else {
+            return;
+        };
+        cache
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .remove(&cache_key);
+    }
+
+    /// Key identifying the hybrid settings of `config`, if any.
+    fn cache_key(config: &Config) -> Option<String> {
+        match config.transit() {
+            Some(transit) => Some(format!(
+                "vault-transit:{}|{}",
+                transit_target(transit),
+                transit.token_env
+            )),
+            None => config.kms_key().map(str::to_string),
+        }
+    }
+
+    /// KMS provider and key the hybrid half of an envelope is sealed with.
+    fn kms_target(&self) -> Option<(&KmsProvider, String)> {
+        match self {
+            Self::Age => None,
+            Self::Hybrid { provider, key, .. } => Some((provider, key.clone())),
+            Self::VaultTransit { transit, .. } => {
+                Some((&KmsProvider::Vault, transit_target(transit)))
+            }
+        }
+    }
+
+    /// Number of KMS clients hybrid backends have built on the current thread.
+    #[cfg(test)]
+    pub fn client_inits() -> usize {
+        CLIENT_INITS.with(|inits| inits.get())
+    }
+
     /// Encrypt plaintext with age for all recipients.
     fn encrypt_age(plaintext: &str, recipients: &[String]) -> Result<String> {
         use super::Cipher;
@@ -55,106 +191,112 @@ impl CipherBackend {
         super::Age.encrypt(plaintext, &age_recipients?)
     }
 
-    /// Encrypt plaintext using the configured KMS backend.
-    #[allow(unused_variables)]
-    fn encrypt_kms(&self, plaintext: &str) -> Result<String> {
-        match self {
-            Self::Age => unreachable!("encrypt_kms called on Age backend"),
-
-            #[cfg(any(test, feature = "test-kms"))]
-            Self::Hybrid { .. } => {
-                use super::envelope::{KmsBackend, StubKms};
-                StubKms.encrypt(plaintext)
-            }
-
-            #[cfg(all(not(test), not(feature = "test-kms"), feature = "aws"))]
-            Self::Hybrid {
-                provider: KmsProvider::Aws,
-                key,
-            } => {
-                use super::Cipher;
-                super::aws::AwsKms::new(key.clone()).encrypt(plaintext, &[])
-            }
-
-            #[cfg(all(not(test), not(feature = "test-kms"), feature = "gcp"))]
-            Self::Hybrid {
-                provider: KmsProvider::Gcp,
-                key,
-            } => {
-                use super::Cipher;
-                super::gcp::GcpKms::new(key.clone()).encrypt(plaintext, &[])
-            }
-
-            #[cfg(all(not(test), not(feature = "test-kms")))]
-            Self::Hybrid { provider, .. } => Err(CipherError::EncryptionFailed(format!(
-                "{} KMS not compiled. Rebuild with: cargo install dugout --features {}",
-                provider.name(),
-                provider.name()
-            ))
-            .into()),
+    /// KMS client for this backend, connecting on first use.
+    fn kms_client(&self) -> Result<&dyn KmsBackend> {
+        let client = match self {
+            Self::Age => unreachable!("kms_client called on Age backend"),
+            Self::Hybrid { client, .. } | Self::VaultTransit { client, .. } => client,
+        };
+        if let Some(client) = client.get() {
+            return Ok(client.as_ref());
         }
+        let connected = self.connect()?;
+        #[cfg(test)]
+        CLIENT_INITS.with(|inits| inits.set(inits.get() + 1));
+        Ok(client.get_or_init(|| connected).as_ref())
     }
 
-    /// Decrypt ciphertext using the configured KMS backend.
+    /// Build the KMS client for this backend.
     #[allow(unused_variables)]
-    fn decrypt_kms(&self, ciphertext: &str) -> Result<String> {
+    fn connect(&self) -> Result<Box<dyn KmsBackend + Send + Sync>> {
         match self {
-            Self::Age => unreachable!("decrypt_kms called on Age backend"),
+            Self::Age => unreachable!("connect called on Age backend"),
 
             #[cfg(any(test, feature = "test-kms"))]
-            Self::Hybrid { .. } => {
-                use super::envelope::{KmsBackend, StubKms};
-                StubKms.decrypt(ciphertext)
+            Self::Hybrid { .. } | Self::VaultTransit { .. } => {
+                Ok(Box::new(super::envelope::StubKms))
             }
 
             #[cfg(all(not(test), not(feature = "test-kms"), feature = "aws"))]
             Self::Hybrid {
                 provider: KmsProvider::Aws,
+                key,
                 ..
-            } => {
-                use super::Cipher;
-                super::aws::AwsKms::new(String::new()).decrypt(ciphertext, &())
-            }
+            } => Ok(Box::new(super::aws::AwsKms::new(key.clone()))),
 
             #[cfg(all(not(test), not(feature = "test-kms"), feature = "gcp"))]
             Self::Hybrid {
                 provider: KmsProvider::Gcp,
                 key,
-            } => {
-                use super::Cipher;
-                super::gcp::GcpKms::new(key.clone()).decrypt(ciphertext, &())
+                ..
+            } => Ok(Box::new(super::gcp::GcpKms::new(key.clone()))),
+
+            #[cfg(all(not(test), not(feature = "test-kms"), feature = "vault"))]
+            Self::VaultTransit { transit, .. } => {
+                Ok(Box::new(super::vault::VaultTransit::new(transit)?))
+            }
+
+            #[cfg(all(not(test), not(feature = "test-kms"), not(feature = "vault")))]
+            Self::VaultTransit { .. } => {
+                Err(ConfigError::BackendNotCompiled(KmsProvider::Vault.name()).into())
             }
 
             #[cfg(all(not(test), not(feature = "test-kms")))]
-            Self::Hybrid { provider, .. } => Err(CipherError::DecryptionFailed(format!(
-                "{} KMS not compiled. Rebuild with: cargo install dugout --features {}",
-                provider.name(),
-                provider.name()
-            ))
-            .into()),
+            Self::Hybrid { provider, .. } => {
+                Err(ConfigError::BackendNotCompiled(provider.name()).into())
+            }
         }
     }
 
     /// Encrypt plaintext for the given recipients.
     ///
     /// - Age: raw age ciphertext
-    /// - Hybrid: v2 envelope with age + KMS ciphertext
+    /// - Hybrid, VaultTransit: v2 envelope with age + KMS ciphertext
     pub fn encrypt(&self, plaintext: &str, recipients: &[String]) -> Result<String> {
-        match self {
-            Self::Age => Self::encrypt_age(plaintext, recipients),
-            Self::Hybrid { provider, .. } => {
-                let age_ct = Self::encrypt_age(plaintext, recipients)?;
-                let kms_ct = self.encrypt_kms(plaintext)?;
-                Envelope::new(age_ct, Some(kms_ct), Some(provider)).seal()
+        let Some((provider, key)) = self.kms_target() else {
+            return Self::encrypt_age(plaintext, recipients);
+        };
+        let age_ct = Self::encrypt_age(plaintext, recipients)?;
+        let kms_ct = self.kms_client()?.encrypt(plaintext)?;
+        Envelope::new(age_ct, Some(kms_ct), Some(provider))
+            .with_kms_key(&key)
+            .seal()
+    }
+
+    /// Re-encrypt an existing secret for a new recipient set.
+    ///
+    /// The KMS half of an envelope doesn't depend on the age recipients, so
+    /// when `ciphertext` was sealed with the current KMS key only the age half
+    /// is re-wrapped and the KMS ciphertext is reused. Any other input
+    /// (raw age, legacy envelope, different KMS key) is encrypted from scratch.
+    pub fn reencrypt(
+        &self,
+        ciphertext: &str,
+        plaintext: &str,
+        recipients: &[String],
+    ) -> Result<String> {
+        if let Some((provider, key)) = self.kms_target() {
+            if let Some(envelope) = Envelope::parse(ciphertext) {
+                if let (Some(kms_ct), Some(kms_key)) = (envelope.kms, envelope.kms_key) {
+                    if kms_key == key {
+                        debug!("reusing KMS ciphertext, re-wrapping age only");
+                        let age_ct = Self::encrypt_age(plaintext, recipients)?;
+                        return Envelope::new(age_ct, Some(kms_ct), Some(provider))
+                            .with_kms_key(&key)
+                            .seal();
+                    }
+                }
             }
         }
+
+        self.encrypt(plaintext, recipients)
     }
 
     /// Decrypt ciphertext using the provided identity.
     ///
     /// - Envelope: tries age first (fast, local), then KMS fallback
     /// - Raw age ciphertext: decrypts directly
-    pub fn decrypt(&self, ciphertext: &str, identity: &age::x25519::Identity) -> Result<String> {
+    pub fn decrypt(&self, ciphertext: &str, identity: &super::AgeIdentity) -> Result<String> {
         use super::Cipher;
 
         if let Some(env) = Envelope::parse(ciphertext) {
@@ -162,7 +304,7 @@ impl CipherBackend {
                 return Ok(result);
             }
             if let Some(kms_ct) = &env.kms {
-                return self.decrypt_kms(kms_ct);
+                return self.kms_client()?.decrypt(kms_ct);
             }
             return Err(CipherError::DecryptionFailed(
                 "envelope decryption failed: no valid path".to_string(),
@@ -174,18 +316,29 @@ impl CipherBackend {
     }
 
     /// Backend name for display.
-    #[allow(dead_code)]
     pub fn name(&self) -> &'static str {
         match self {
             Self::Age => "age",
             Self::Hybrid { provider, .. } => match provider {
                 KmsProvider::Aws => "hybrid+aws",
                 KmsProvider::Gcp => "hybrid+gcp",
+                KmsProvider::Vault => "hybrid+vault",
             },
+            Self::VaultTransit { .. } => "hybrid+vault",
         }
     }
 }
 
+/// Identifier of a transit key recorded in envelopes (`<address>/v1/<mount>/keys/<key>`).
+fn transit_target(transit: &TransitConfig) -> String {
+    format!(
+        "{}/v1/{}/keys/{}",
+        transit.address.trim_end_matches('/'),
+        transit.mount.trim_matches('/'),
+        transit.key
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -318,4 +471,173 @@ mod tests {
         let decrypted = age_backend.decrypt(&encrypted, &identity).unwrap();
         assert_eq!(decrypted, "cross-compat");
     }
+
+    fn hybrid_backend(key: &str) -> CipherBackend {
+        let mut config = Config::new();
+        config.kms = Some(KmsConfig {
+            key: key.to_string(),
+        });
+        CipherBackend::from_config(&config).unwrap()
+    }
+
+    #[test]
+    fn test_hybrid_reencrypt_reuses_kms_for_new_recipients() {
+        use super::super::envelope::StubKms;
+
+        let backend = hybrid_backend("arn:aws:kms:us-east-1:123:key/abc");
+        let alice = age::x25519::Identity::generate();
+        let bob = age::x25519::Identity::generate();
+
+        let encrypted = backend
+            .encrypt("shared", &[alice.to_public().to_string()])
+            .unwrap();
+        let before = Envelope::parse(&encrypted).unwrap();
+
+        let calls = StubKms::encrypt_calls();
+        let recipients = [alice.to_public().to_string(), bob.to_public().to_string()];
+        let rewrapped = backend
+            .reencrypt(&encrypted, "shared", &recipients)
+            .unwrap();
+        assert_eq!(StubKms::encrypt_calls(), calls);
+
+        let after = Envelope::parse(&rewrapped).unwrap();
+        assert_eq!(after.kms, before.kms);
+        assert_ne!(after.age, before.age);
+        assert_eq!(
+            CipherBackend::Age.decrypt(&rewrapped, &bob).unwrap(),
+            "shared"
+        );
+    }
+
+    #[test]
+    fn test_hybrid_reencrypt_calls_kms_when_key_changes() {
+        use super::super::envelope::StubKms;
+
+        let old = hybrid_backend("arn:aws:kms:us-east-1:123:key/old");
+        let new = hybrid_backend("arn:aws:kms:us-east-1:123:key/new");
+        let identity = age::x25519::Identity::generate();
+        let recipients = [identity.to_public().to_string()];
+
+        let encrypted = old.encrypt("value", &recipients).unwrap();
+
+        let calls = StubKms::encrypt_calls();
+        let rewrapped = new.reencrypt(&encrypted, "value", &recipients).unwrap();
+        assert_eq!(StubKms::encrypt_calls(), calls + 1);
+
+        let envelope = Envelope::parse(&rewrapped).unwrap();
+        assert_eq!(
+            envelope.kms_key.as_deref(),
+            Some("arn:aws:kms:us-east-1:123:key/new")
+        );
+    }
+
+    #[test]
+    fn test_hybrid_reencrypt_calls_kms_for_legacy_envelope() {
+        use super::super::envelope::StubKms;
+
+        let backend = hybrid_backend("arn:aws:kms:us-east-1:123:key/abc");
+        let identity = age::x25519::Identity::generate();
+        let recipients = [identity.to_public().to_string()];
+
+        // Envelopes sealed before the KMS key was recorded
+        let age_ct = CipherBackend::Age.encrypt("value", &recipients).unwrap();
+        let legacy = Envelope::new(age_ct, Some("stub-kms:76616c7565".to_string()), None)
+            .seal()
+            .unwrap();
+
+        let calls = StubKms::encrypt_calls();
+        backend.reencrypt(&legacy, "value", &recipients).unwrap();
+        assert_eq!(StubKms::encrypt_calls(), calls + 1);
+    }
+
+    #[test]
+    fn test_shared_reuses_backend_for_same_kms_key() {
+        let mut config = Config::new();
+        config.kms = Some(KmsConfig {
+            key: "arn:aws:kms:us-east-1:123:key/shared-test".to_string(),
+        });
+
+        let first = CipherBackend::shared(&config).unwrap();
+        let second = CipherBackend::shared(&config).unwrap();
+        assert!(Arc::ptr_eq(&first, &second));
+
+        config.kms = Some(KmsConfig {
+            key: "arn:aws:kms:us-east-1:123:key/shared-test-other".to_string(),
+        });
+        let other = CipherBackend::shared(&config).unwrap();
+        assert!(!Arc::ptr_eq(&first, &other));
+        assert_eq!(other.name(), "hybrid+aws");
+    }
+
+    #[test]
+    fn test_shared_backend_connects_once() {
+        let mut config = Config::new();
+        config.kms = Some(KmsConfig {
+            key: "arn:aws:kms:us-east-1:123:key/connect-once".to_string(),
+        });
+        let identity = age::x25519::Identity::generate();
+        let recipients = [identity.to_public().to_string()];
+
+        let inits = CipherBackend::client_inits();
+        let first = CipherBackend::shared(&config).unwrap();
+        first.encrypt("one", &recipients).unwrap();
+        let second = CipherBackend::shared(&config).unwrap();
+        second.encrypt("two", &recipients).unwrap();
+        assert_eq!(CipherBackend::client_inits(), inits + 1);
+
+        // Evicting drops the client along with the backend
+        CipherBackend::evict_shared(&config);
+        let third = CipherBackend::shared(&config).unwrap();
+        assert!(!Arc::ptr_eq(&first, &third));
+        third.encrypt("three", &recipients).unwrap();
+        assert_eq!(CipherBackend::client_inits(), inits + 2);
+    }
+
+    #[test]
+    fn test_shared_age_is_not_cached() {
+        let config = Config::new();
+        let backend = CipherBackend::shared(&config).unwrap();
+        assert_eq!(backend.name(), "age");
+    }
+
+    fn transit_config() -> Config {
+        let mut config = Config::new();
+        config.dugout.cipher = Some("vault-transit".to_string());
+        config.vault = Some(TransitConfig {
+            address: "https://vault.example.com:8200".to_string(),
+            key: "dugout".to_string(),
+            mount: "transit".to_string(),
+            token_env: "VAULT_TOKEN".to_string(),
+        });
+        config
+    }
+
+    #[test]
+    fn test_vault_transit_from_config() {
+        let backend = CipherBackend::from_config(&transit_config()).unwrap();
+        assert_eq!(backend.name(), "hybrid+vault");
+
+        let mut missing = transit_config();
+        missing.vault = None;
+        assert!(CipherBackend::from_config(&missing).is_err());
+    }
+
+    #[test]
+    fn test_vault_transit_envelope_decrypts_via_age() {
+        let backend = CipherBackend::from_config(&transit_config()).unwrap();
+        let identity = age::x25519::Identity::generate();
+        let recipient = identity.to_public().to_string();
+
+        let encrypted = backend.encrypt("transit-secret", &[recipient]).unwrap();
+        let envelope = Envelope::parse(&encrypted).expect("should be envelope");
+        assert_eq!(envelope.provider.as_deref(), Some("vault"));
+        assert_eq!(
+            envelope.kms_key.as_deref(),
+            Some("https://vault.example.com:8200/v1/transit/keys/dugout")
+        );
+
+        // Developers decrypt locally with age, without calling Vault
+        let decrypted = backend.decrypt(&encrypted, &identity).unwrap();
+        assert_eq!(decrypted, "transit-secret");
+    }
 }
@@ -0,0 +1,232 @@
+//! `.env.vault` files, as read by dotenv-vault and `dotenv` 16.1+.
+//!
+//! dugout writes the dotenv-vault v1 layout:
+//!
+//! - a dotenv file with one `DOTENV_VAULT_<ENVIRONMENT>="<base64>"` entry
+//! - the payload is a 12-byte random nonce, followed by the AES-256-GCM
+//!   ciphertext of a dotenv file and its 16-byte tag
+//! - the key travels separately in `DOTENV_KEY`, as
+//!   `dotenv://:key_<64 hex chars>@dotenv.org/vault/.env.vault?environment=<environment>`
+
+use aes_gcm::aead::{Aead, AeadCore, KeyInit, OsRng};
+use aes_gcm::{Aes256Gcm, Key, Nonce};
+use base64::Engine;
+use zeroize::Zeroizing;
+
+use crate::error::{CipherError, ConfigError, Result};
+
+/// Length of the AES-GCM nonce prefixed to each payload.
+const NONCE_LEN: usize = 12;
+
+/// First line of every file dugout writes.
+const HEADER: &str =
+    "#/ .env.vault (dotenv-vault v1), written by dugout; decrypt with DOTENV_KEY /";
+
+/// A `DOTENV_KEY`: an AES-256 key and the environment it unlocks.
+pub struct DotenvKey {
+    key: Zeroizing<[u8; 32]>,
+    environment: String,
+}
+
+impl DotenvKey {
+    /// Generate a random key for `environment`.
+    ///
+    /// The environment is normalized to `[a-z0-9_]` so that dotenv, which
+    /// upper-cases it, looks up the same `DOTENV_VAULT_<ENVIRONMENT>` entry.
+    pub fn generate(environment: &str) -> Self {
+        let generated = Aes256Gcm::generate_key(OsRng);
+        let mut key = Zeroizing::new([0u8; 32]);
+        key.copy_from_slice(&generated);
+        Self {
+            key,
+            environment: normalize(environment).to_ascii_lowercase(),
+        }
+    }
+
+    /// Parse a `dotenv://:key_<hex>@dotenv.org/vault/.env.vault?environment=<env>` URI.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ConfigError::InvalidValue` if the URI isn't in that form.
+    pub fn parse(uri: &str) -> Result<Self> {
+        let invalid = |reason: &str| ConfigError::InvalidValue {
+            field: "DOTENV_KEY",
+            reason: reason.to_string(),
+        };
+        let rest = uri
+            .trim()
+            .strip_prefix("dotenv://:key_")
+            .ok_or_else(|| invalid("expected dotenv://:key_<hex>@..."))?;
+        let (hex, rest) = rest
+            .split_once('@')
+            .ok_or_else(|| invalid("missing @dotenv.org/vault/.env.vault"))?;
+        let environment = rest
+            .split_once('?')
+            .and_then(|(_, query)| {
+                query
+                    .split('&')
+                    .find_map(|param| param.strip_prefix("environment="))
+            })
+            .filter(|env| !env.is_empty())
+            .ok_or_else(|| invalid("missing ?environment="))?;
+
+        if hex.len() != 64 || !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return Err(invalid("key must be 64 hex characters").into());
+        }
+        let mut key = Zeroizing::new([0u8; 32]);
+        for (i, byte) in key.iter_mut().enumerate() {
+            *byte = u8::from_str_radix(&hex[i * 2..i * 2 + 2], 16)
+                .map_err(|_| invalid("key must be 64 hex characters"))?;
+        }
+        Ok(Self {
+            key,
+            environment: environment.to_string(),
+        })
+    }
+
+    /// The key as a `DOTENV_KEY` URI.
+    pub fn to_uri(&self) -> Zeroizing<String> {
+        let hex: String = self.key.iter().map(|b| format!("{:02x}", b)).collect();
+        Zeroizing::new(format!(
+            "dotenv://:key_{}@dotenv.org/vault/.env.vault?environment={}",
+            hex, self.environment
+        ))
+    }
+
+    /// Name of the `.env.vault` entry for this key's environment.
+    fn variable(&self) -> String {
+        format!("DOTENV_VAULT_{}", normalize(&self.environment))
+    }
+
+    fn cipher(&self) -> Aes256Gcm {
+        Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(self.key.as_slice()))
+    }
+}
+
+/// Upper-case `environment` and replace anything outside `[A-Z0-9_]` with `_`.
+fn normalize(environment: &str) -> String {
+    environment
+        .chars()
+        .map(|c| match c.to_ascii_uppercase() {
+            c @ ('A'..='Z' | '0'..='9') => c,
+            _ => '_',
+        })
+        .collect()
+}
+
+/// Encrypt dotenv content into a complete `.env.vault` file.
+///
+/// # Errors
+///
+/// Returns `CipherError::EncryptionFailed` if encryption fails.
+pub fn seal(dotenv: &str, key: &DotenvKey) -> Result<String> {
+    let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+    let ciphertext = key
+        .cipher()
+        .encrypt(&nonce, dotenv.as_bytes())
+        .map_err(|e| CipherError::EncryptionFailed(e.to_string()))?;
+
+    let mut payload = nonce.to_vec();
+    payload.extend_from_slice(&ciphertext);
+    Ok(format!(
+        "{}\n\n# {}\n{}=\"{}\"\n",
+        HEADER,
+        key.environment,
+        key.variable(),
+        base64::engine::general_purpose::STANDARD.encode(payload)
+    ))
+}
+
+/// Decrypt the entry for `key`'s environment from parsed `.env.vault` entries.
+///
+/// # Errors
+///
+/// Returns `CipherError::DecryptionFailed` if the environment's entry is
+/// missing, malformed, or doesn't decrypt with `key`.
+pub fn open(entries: &[(String, String)], key: &DotenvKey) -> Result<Zeroizing<String>> {
+    let variable = key.variable();
+    let failed = |reason: String| CipherError::DecryptionFailed(reason);
+
+    let encoded = entries
+        .iter()
+        .find(|(name, _)| *name == variable)
+        .map(|(_, value)| value)
+        .ok_or_else(|| failed(format!("no {} entry in .env.vault", variable)))?;
+    let payload = base64::engine::general_purpose::STANDARD
+        .decode(encoded.trim())
+        .map_err(|e| failed(format!("{} is not valid base64: {}", variable, e)))?;
+    if payload.len() <= NONCE_LEN {
+        return Err(failed(format!("{} is too short", variable)).into());
+    }
+
+    let (nonce, ciphertext) = payload.split_at(NONCE_LEN);
+    let plaintext = key
+        .cipher()
+        .decrypt(Nonce::from_slice(nonce), ciphertext)
+        .map(Zeroizing::new)
+        .map_err(|_| failed(format!("{} doesn't decrypt with this DOTENV_KEY", variable)))?;
+    let text =
+        std::str::from_utf8(&plaintext).map_err(|_| failed(format!("{} isn't UTF-8", variable)))?;
+    Ok(Zeroizing::new(text.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_key_uri_round_trip() {
+        let key = DotenvKey::generate("production");
+        let parsed = DotenvKey::parse(&key.to_uri()).unwrap();
+        assert_eq!(parsed.variable(), "DOTENV_VAULT_PRODUCTION");
+        assert_eq!(*parsed.key, *key.key);
+        assert!(key.to_uri().ends_with("?environment=production"));
+    }
+
+    #[test]
+    fn test_parse_rejects_malformed_keys() {
+        for uri in [
+            "not-a-key",
+            "dotenv://:key_abc@dotenv.org/vault/.env.vault?environment=development",
+            "dotenv://:key_0000000000000000000000000000000000000000000000000000000000000000@dotenv.org/vault/.env.vault",
+            // 64 bytes, but not 64 hex digits
+            "dotenv://:key_+f00000000000000000000000000000000000000000000000000000000000000@dotenv.org/vault/.env.vault?environment=development",
+            "dotenv://:key_é00000000000000000000000000000000000000000000000000000000000000@dotenv.org/vault/.env.vault?environment=development",
+        ] {
+            assert!(DotenvKey::parse(uri).is_err(), "{}", uri);
+        }
+    }
+
+    #[test]
+    fn test_environment_is_a_valid_variable_name() {
+        let key = DotenvKey::generate("ci-staging.eu");
+        assert_eq!(key.variable(), "DOTENV_VAULT_CI_STAGING_EU");
+        assert!(key.to_uri().ends_with("?environment=ci_staging_eu"));
+
+        let hex = "0".repeat(64);
+        let uri = format!(
+            "dotenv://:key_{}@dotenv.org/vault/.env.vault?environment=pre-prod",
+            hex
+        );
+        let parsed = DotenvKey::parse(&uri).unwrap();
+        assert_eq!(parsed.variable(), "DOTENV_VAULT_PRE_PROD");
+    }
+
+    #[test]
+    fn test_seal_and_open() {
+        let key = DotenvKey::generate("development");
+        let file = seal("API_KEY=secret\n", &key).unwrap();
+
+        assert!(file.starts_with("#/ .env.vault"));
+        assert!(file.contains("\nDOTENV_VAULT_DEVELOPMENT=\""));
+        assert!(!file.contains("secret"));
+
+        let (name, value) = file.lines().find_map(|line| line.split_once('=')).unwrap();
+        let entries = vec![(name.to_string(), value.trim_matches('"').to_string())];
+        assert_eq!(open(&entries, &key).unwrap().as_str(), "API_KEY=secret\n");
+
+        let other = DotenvKey::generate("development");
+        assert!(open(&entries, &other).is_err());
+        assert!(open(&entries, &DotenvKey::generate("production")).is_err());
+    }
+}
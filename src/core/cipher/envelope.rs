@@ -12,6 +12,8 @@ const ENVELOPE_V2: &str = "dugout-envelope-v2";
 pub enum KmsProvider {
     Aws,
     Gcp,
+    /// HashiCorp Vault transit engine (configured by `[vault]`, never detected)
+    Vault,
 }
 
 #[allow(dead_code)]
@@ -35,6 +37,7 @@ impl KmsProvider {
         match self {
             Self::Aws => "aws",
             Self::Gcp => "gcp",
+            Self::Vault => "vault",
         }
     }
 }
@@ -53,9 +56,12 @@ pub struct Envelope {
     /// KMS-encrypted ciphertext (present when KMS configured)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub kms: Option<String>,
-    /// KMS provider name ("aws" | "gcp")
+    /// KMS provider name ("aws" | "gcp" | "vault")
     #[serde(skip_serializing_if = "Option::is_none")]
     pub provider: Option<String>,
+    /// KMS key that produced `kms` (absent in envelopes written before it was recorded)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kms_key: Option<String>,
 }
 
 #[allow(dead_code)]
@@ -71,9 +77,16 @@ impl Envelope {
             age: age_ciphertext,
             kms: kms_ciphertext,
             provider: provider.map(|p| p.name().to_string()),
+            kms_key: None,
         }
     }
 
+    /// Record which KMS key produced the KMS ciphertext.
+    pub fn with_kms_key(mut self, key: &str) -> Self {
+        self.kms_key = Some(key.to_string());
+        self
+    }
+
     /// Serialize the envelope to a JSON string.
     pub fn seal(&self) -> Result<String> {
         serde_json::to_string(self).map_err(|e| {
@@ -117,14 +130,37 @@ pub trait KmsBackend: std::fmt::Debug {
 #[derive(Debug)]
 pub struct StubKms;
 
+#[cfg(test)]
+thread_local! {
+    static STUB_ENCRYPT_CALLS: std::cell::Cell<usize> = const { std::cell::Cell::new(0) };
+    static STUB_DECRYPT_CALLS: std::cell::Cell<usize> = const { std::cell::Cell::new(0) };
+}
+
+#[cfg(test)]
+impl StubKms {
+    /// Number of `encrypt` calls made on the current thread.
+    pub fn encrypt_calls() -> usize {
+        STUB_ENCRYPT_CALLS.with(|calls| calls.get())
+    }
+
+    /// Number of `decrypt` calls made on the current thread.
+    pub fn decrypt_calls() -> usize {
+        STUB_DECRYPT_CALLS.with(|calls| calls.get())
+    }
+}
+
 #[cfg(any(test, feature = "test-kms"))]
 impl KmsBackend for StubKms {
     fn encrypt(&self, plaintext: &str) -> Result<String> {
+        #[cfg(test)]
+        STUB_ENCRYPT_CALLS.with(|calls| calls.set(calls.get() + 1));
         let hex: String = plaintext.bytes().map(|b| format!("{:02x}", b)).collect();
         Ok(format!("stub-kms:{}", hex))
     }
 
     fn decrypt(&self, ciphertext: &str) -> Result<String> {
+        #[cfg(test)]
+        STUB_DECRYPT_CALLS.with(|calls| calls.set(calls.get() + 1));
         let hex = ciphertext.strip_prefix("stub-kms:").ok_or_else(|| {
             CipherError::DecryptionFailed("not a stub-kms ciphertext".to_string())
         })?;
@@ -25,12 +25,15 @@ use std::io::Write;
 use std::process::{Command, Stdio};
 use tracing::trace;
 
+#[cfg(feature = "gcp")]
+use crate::core::cipher::envelope::{KmsBackend, KmsProvider};
 use crate::core::cipher::Cipher;
 use crate::error::{CipherError, Result};
 
 /// Google Cloud KMS cipher backend using gcloud CLI
 #[cfg(feature = "gcp")]
 #[allow(dead_code)]
+#[derive(Debug)]
 pub struct GcpKms {
     /// Full resource name: projects/*/locations/*/keyRings/*/cryptoKeys/*
     resource_name: String,
@@ -234,3 +237,18 @@ impl Cipher for GcpKms {
         Ok(plaintext)
     }
 }
+
+#[cfg(feature = "gcp")]
+impl KmsBackend for GcpKms {
+    fn encrypt(&self, plaintext: &str) -> Result<String> {
+        Cipher::encrypt(self, plaintext, &[])
+    }
+
+    fn decrypt(&self, ciphertext: &str) -> Result<String> {
+        Cipher::decrypt(self, ciphertext, &())
+    }
+
+    fn provider(&self) -> &KmsProvider {
+        &KmsProvider::Gcp
+    }
+}
@@ -1,16 +1,19 @@
 //! Cryptographic operations.
 //!
 //! Two backends:
-//! - **age** (default): x25519 public-key encryption
-//! - **hybrid**: age + cloud KMS (AWS or GCP)
+//! - **age** (default): x25519 (or `ssh-ed25519`) public-key encryption
+//! - **hybrid**: age + cloud KMS (AWS or GCP) or HashiCorp Vault transit
 //!
-//! Cloud KMS implementations live in `envelope.rs`, `aws.rs`, `gcp.rs`.
+//! Cloud KMS implementations live in `envelope.rs`, `aws.rs`, `gcp.rs`, `vault.rs`.
 
 use crate::error::Result;
 
 mod age;
 mod backend;
+pub mod env_vault;
 pub mod envelope;
+pub mod selftest;
+pub mod sign;
 
 #[cfg(feature = "aws")]
 pub mod aws;
@@ -18,7 +21,12 @@ pub mod aws;
 #[cfg(feature = "gcp")]
 pub mod gcp;
 
-pub use age::{parse_recipient, Age};
+#[cfg(feature = "vault")]
+pub mod vault;
+
+#[cfg(test)]
+pub use age::AgeRecipient;
+pub use age::{header_stanzas, is_recipient, parse_recipient, Age, AgeIdentity};
 pub use backend::CipherBackend;
 #[allow(unused_imports)]
 pub use envelope::{Envelope, KmsProvider};
@@ -26,7 +34,7 @@ pub use envelope::{Envelope, KmsProvider};
 /// Cryptographic backend trait.
 pub trait Cipher {
     type Recipient;
-    type Identity;
+    type Identity: ?Sized;
 
     fn encrypt(&self, plaintext: &str, recipients: &[Self::Recipient]) -> Result<String>;
     fn decrypt(&self, encrypted: &str, identity: &Self::Identity) -> Result<String>;
@@ -0,0 +1,130 @@
+//! Cipher self-test.
+//!
+//! Round-trips a random value through age in memory and checks that external
+//! tools backends shell out to are installed. KMS and transit backends need a
+//! real key to round-trip, so they are reported as skipped.
+
+use ::age::secrecy::ExposeSecret;
+use ::age::x25519;
+
+use super::{Age, Cipher};
+
+/// Outcome of one self-test check.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Check {
+    /// What was checked, e.g. `age` or `gcp (gcloud)`
+    pub name: String,
+    /// Whether the check passed
+    pub passed: bool,
+    /// Whether the check couldn't run here (it then counts as passed)
+    pub skipped: bool,
+    /// Short human-readable detail
+    pub detail: String,
+}
+
+/// Run all checks for the compiled-in backends.
+pub fn run() -> Vec<Check> {
+    #[allow(unused_mut)]
+    let mut checks = vec![age_roundtrip()];
+
+    // A KMS round-trip needs a real key, which the self-test doesn't have
+    #[cfg(feature = "aws")]
+    checks.push(skipped(
+        "aws",
+        "compiled in; round-trip needs a KMS key (uses the AWS SDK, no external tool)",
+    ));
+
+    #[cfg(feature = "gcp")]
+    checks.push(tool("gcp", "gcloud"));
+
+    #[cfg(feature = "vault")]
+    checks.push(skipped(
+        "vault",
+        "compiled in; round-trip needs a transit key (uses the Vault HTTP API)",
+    ));
+
+    checks
+}
+
+/// Encrypt and decrypt a random value with a throwaway x25519 identity.
+fn age_roundtrip() -> Check {
+    let identity = x25519::Identity::generate();
+    // A second identity's secret key doubles as random plaintext
+    let value = x25519::Identity::generate().to_string();
+    let value = value.expose_secret();
+
+    let result = Age
+        .encrypt(value, &[identity.to_public().into()])
+        .and_then(|ciphertext| Age.decrypt(&ciphertext, &identity));
+
+    match result {
+        Ok(decrypted) if decrypted == *value => Check {
+            name: "age".to_string(),
+            passed: true,
+            skipped: false,
+            detail: "encrypt/decrypt round-trip ok".to_string(),
+        },
+        Ok(_) => Check {
+            name: "age".to_string(),
+            passed: false,
+            skipped: false,
+            detail: "decrypted value does not match".to_string(),
+        },
+        Err(e) => Check {
+            name: "age".to_string(),
+            passed: false,
+            skipped: false,
+            detail: e.to_string(),
+        },
+    }
+}
+
+/// A check that can't run without external setup.
+#[cfg(any(feature = "aws", feature = "vault"))]
+fn skipped(name: &str, detail: &str) -> Check {
+    Check {
+        name: name.to_string(),
+        passed: true,
+        skipped: true,
+        detail: detail.to_string(),
+    }
+}
+
+/// Check that an external tool a backend depends on is on `PATH`.
+#[cfg(any(feature = "gcp", test))]
+pub(crate) fn tool(backend: &str, program: &str) -> Check {
+    let name = format!("{} ({})", backend, program);
+    match which::which(program) {
+        Ok(path) => Check {
+            name,
+            passed: true,
+            skipped: false,
+            detail: format!("found at {}", path.display()),
+        },
+        Err(_) => Check {
+            name,
+            passed: false,
+            skipped: false,
+            detail: format!("{} not found on PATH", program),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_age_roundtrip_passes() {
+        let check = age_roundtrip();
+        assert!(check.passed, "{}", check.detail);
+    }
+
+    #[test]
+    fn test_missing_tool_reported() {
+        let check = tool("gpg", "dugout-test-no-such-tool");
+        assert!(!check.passed);
+        assert_eq!(check.name, "gpg (dugout-test-no-such-tool)");
+        assert!(check.detail.contains("not found"));
+    }
+}
@@ -0,0 +1,147 @@
+//! Signatures made with age x25519 keys.
+//!
+//! age keys are Diffie-Hellman keys and can't sign directly, so this uses
+//! XEdDSA (the scheme Signal uses for the same problem): the x25519 secret
+//! is used as an Ed25519 scalar, and a signature verifies against the
+//! `age1...` public key alone. Nonces are derived from the key and message,
+//! as in Ed25519, so no randomness is needed.
+//!
+//! Spec: <https://signal.org/docs/specifications/xeddsa/>
+
+use age::secrecy::ExposeSecret;
+use age::x25519;
+use bech32::FromBase32;
+use curve25519_dalek::edwards::{CompressedEdwardsY, EdwardsPoint};
+use curve25519_dalek::montgomery::MontgomeryPoint;
+use curve25519_dalek::scalar::Scalar;
+use sha2::{Digest, Sha512};
+use zeroize::Zeroizing;
+
+use crate::error::{CipherError, Result};
+
+/// Length of a signature in bytes (`R || s`).
+pub const SIGNATURE_LEN: usize = 64;
+
+/// Sign `message` with an age x25519 identity.
+///
+/// # Errors
+///
+/// Returns `CipherError::InvalidSecretKey` if the identity can't be decoded.
+pub fn sign(identity: &x25519::Identity, message: &[u8]) -> Result<[u8; SIGNATURE_LEN]> {
+    let mut clamped = Zeroizing::new(
+        decode_bech32(identity.to_string().expose_secret(), "age-secret-key-").ok_or_else(
+            || CipherError::InvalidSecretKey("not a bech32 age secret key".to_string()),
+        )?,
+    );
+    clamped[0] &= 248;
+    clamped[31] &= 127;
+    clamped[31] |= 64;
+
+    // Pick the scalar whose Edwards public key has a zero sign bit, so the
+    // verifier can recover it from the Montgomery u-coordinate alone
+    let k = Scalar::from_bytes_mod_order(*clamped);
+    let public = EdwardsPoint::mul_base(&k).compress();
+    let a = if public.as_bytes()[31] & 0x80 != 0 {
+        -k
+    } else {
+        k
+    };
+    let public = EdwardsPoint::mul_base(&a).compress();
+
+    // r = hash1(a || M); hash1 prefixes 0xFE followed by 31 0xFF bytes
+    let mut prefix = [0xFF_u8; 32];
+    prefix[0] = 0xFE;
+    let r = wide_scalar(
+        Sha512::new()
+            .chain_update(prefix)
+            .chain_update(a.as_bytes())
+            .chain_update(message),
+    );
+    let big_r = EdwardsPoint::mul_base(&r).compress();
+    let h = challenge(&big_r, &public, message);
+    let s = r + h * a;
+
+    let mut signature = [0u8; SIGNATURE_LEN];
+    signature[..32].copy_from_slice(big_r.as_bytes());
+    signature[32..].copy_from_slice(s.as_bytes());
+    Ok(signature)
+}
+
+/// Check a signature from [`sign`] against an `age1...` public key.
+///
+/// Returns `false` for malformed keys or signatures as well as bad ones.
+pub fn verify(public_key: &str, message: &[u8], signature: &[u8]) -> bool {
+    let Some(u) = decode_bech32(public_key, "age") else {
+        return false;
+    };
+    let Some(public) = MontgomeryPoint(u).to_edwards(0) else {
+        return false;
+    };
+    let Ok(signature) = <[u8; SIGNATURE_LEN]>::try_from(signature) else {
+        return false;
+    };
+
+    let mut r_bytes = [0u8; 32];
+    r_bytes.copy_from_slice(&signature[..32]);
+    let mut s_bytes = [0u8; 32];
+    s_bytes.copy_from_slice(&signature[32..]);
+    let Some(s) = Option::<Scalar>::from(Scalar::from_canonical_bytes(s_bytes)) else {
+        return false;
+    };
+
+    let big_r = CompressedEdwardsY(r_bytes);
+    let h = challenge(&big_r, &public.compress(), message);
+    // R == sB - hA
+    let check = EdwardsPoint::vartime_double_scalar_mul_basepoint(&-h, &public, &s);
+    check.compress() == big_r
+}
+
+/// h = SHA-512(R || A || M) mod l
+fn challenge(big_r: &CompressedEdwardsY, public: &CompressedEdwardsY, message: &[u8]) -> Scalar {
+    wide_scalar(
+        Sha512::new()
+            .chain_update(big_r.as_bytes())
+            .chain_update(public.as_bytes())
+            .chain_update(message),
+    )
+}
+
+/// Reduce a SHA-512 digest to a scalar.
+fn wide_scalar(hasher: Sha512) -> Scalar {
+    let mut wide = Zeroizing::new([0u8; 64]);
+    wide.copy_from_slice(&hasher.finalize());
+    Scalar::from_bytes_mod_order_wide(&wide)
+}
+
+/// Decode a 32-byte bech32 key with the given human-readable part.
+fn decode_bech32(encoded: &str, hrp: &str) -> Option<[u8; 32]> {
+    let (found, data, _) = bech32::decode(&encoded.trim().to_lowercase()).ok()?;
+    if found != hrp {
+        return None;
+    }
+    let bytes = Zeroizing::new(Vec::<u8>::from_base32(&data).ok()?);
+    <[u8; 32]>::try_from(bytes.as_slice()).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sign_verifies_against_public_key() {
+        let identity = x25519::Identity::generate();
+        let public = identity.to_public().to_string();
+
+        let signature = sign(&identity, b"dugout").unwrap();
+        assert!(verify(&public, b"dugout", &signature));
+        assert!(!verify(&public, b"dugout!", &signature));
+
+        let other = x25519::Identity::generate().to_public().to_string();
+        assert!(!verify(&other, b"dugout", &signature));
+
+        let mut tampered = signature;
+        tampered[40] ^= 1;
+        assert!(!verify(&public, b"dugout", &tampered));
+        assert!(!verify(&public, b"dugout", &signature[..63]));
+    }
+}
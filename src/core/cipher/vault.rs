@@ -0,0 +1,217 @@
+//! HashiCorp Vault transit cipher backend.
+//!
+//! Encrypts secrets with a Vault transit key over the HTTP API in hybrid mode.
+//! Enable with `--features vault`.
+//!
+//! ## Requirements
+//!
+//! - A transit secrets engine with a key the token can `encrypt` and `decrypt` with
+//! - A Vault token in the environment (`VAULT_TOKEN` by default)
+//!
+//! ## Usage
+//!
+//! Configure the vault file with:
+//! ```toml
+//! [dugout]
+//! cipher = "vault-transit"
+//!
+//! [vault]
+//! address = "https://vault.example.com:8200"
+//! key = "dugout"
+//! # mount = "transit"
+//! # token_env = "VAULT_TOKEN"
+//! ```
+
+use serde::Deserialize;
+use tracing::trace;
+
+#[cfg(feature = "vault")]
+use crate::core::cipher::envelope::{KmsBackend, KmsProvider};
+use crate::core::cipher::Cipher;
+use crate::core::config::TransitConfig;
+use crate::error::{CipherError, Result};
+
+/// Vault transit cipher backend
+///
+/// Recipients are transit key names; the configured key is used when none
+/// is given. Transit ciphertext (`vault:v1:...`) names its key version, so
+/// decryption only needs the key name.
+#[cfg(feature = "vault")]
+#[allow(dead_code)]
+pub struct VaultTransit {
+    address: String,
+    mount: String,
+    key: String,
+    token: String,
+}
+
+/// Response body of the transit `encrypt` and `decrypt` endpoints.
+#[cfg(feature = "vault")]
+#[derive(Deserialize)]
+struct TransitResponse {
+    data: TransitData,
+}
+
+#[cfg(feature = "vault")]
+#[derive(Deserialize)]
+struct TransitData {
+    #[serde(default)]
+    ciphertext: Option<String>,
+    #[serde(default)]
+    plaintext: Option<String>,
+}
+
+// Hand-written so the token never reaches debug logs
+#[cfg(feature = "vault")]
+impl std::fmt::Debug for VaultTransit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("VaultTransit")
+            .field("address", &self.address)
+            .field("mount", &self.mount)
+            .field("key", &self.key)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(feature = "vault")]
+impl VaultTransit {
+    /// Create a transit cipher from config, reading the token from the environment.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CipherError::EncryptionFailed` if the token variable is unset.
+    #[allow(dead_code)]
+    pub fn new(config: &TransitConfig) -> Result<Self> {
+        let token = std::env::var(&config.token_env).map_err(|_| {
+            CipherError::EncryptionFailed(format!(
+                "Vault token not found. Set {} to a token for {}",
+                config.token_env, config.address
+            ))
+        })?;
+        Ok(Self {
+            address: config.address.trim_end_matches('/').to_string(),
+            mount: config.mount.trim_matches('/').to_string(),
+            key: config.key.clone(),
+            token,
+        })
+    }
+
+    /// URL of a transit operation (`encrypt` or `decrypt`) for a key.
+    fn endpoint(&self, operation: &str, key: &str) -> String {
+        format!("{}/v1/{}/{}/{}", self.address, self.mount, operation, key)
+    }
+
+    /// POST a JSON body to a transit endpoint and return its `data`.
+    fn call(&self, operation: &str, key: &str, body: serde_json::Value) -> Result<TransitData> {
+        let url = self.endpoint(operation, key);
+        let fail = |msg: String| -> crate::error::Error {
+            if operation == "encrypt" {
+                CipherError::EncryptionFailed(msg).into()
+            } else {
+                CipherError::DecryptionFailed(msg).into()
+            }
+        };
+
+        let response = reqwest::blocking::Client::new()
+            .post(&url)
+            .header("X-Vault-Token", &self.token)
+            .json(&body)
+            .send()
+            .map_err(|e| fail(format!("Vault transit {} failed: {}", operation, e)))?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let detail = response.text().unwrap_or_default();
+            return Err(fail(format!(
+                "Vault transit {} failed ({}): {}",
+                operation,
+                status,
+                detail.trim()
+            )));
+        }
+
+        response
+            .json::<TransitResponse>()
+            .map(|r| r.data)
+            .map_err(|e| fail(format!("unexpected Vault response: {}", e)))
+    }
+}
+
+#[cfg(feature = "vault")]
+impl Cipher for VaultTransit {
+    // Transit keys are addressed by name; there is no local identity
+    type Recipient = String;
+    type Identity = ();
+
+    fn name(&self) -> &'static str {
+        "vault-transit"
+    }
+
+    fn encrypt(&self, plaintext: &str, recipients: &[String]) -> Result<String> {
+        use ::base64::Engine;
+
+        let key = recipients.first().unwrap_or(&self.key);
+        trace!(
+            key = %key,
+            plaintext_len = plaintext.len(),
+            "encrypting with Vault transit"
+        );
+
+        let encoded = ::base64::engine::general_purpose::STANDARD.encode(plaintext.as_bytes());
+        let data = self.call("encrypt", key, serde_json::json!({ "plaintext": encoded }))?;
+
+        let ciphertext = data
+            .ciphertext
+            .ok_or_else(|| CipherError::EncryptionFailed("no ciphertext returned".into()))?;
+        trace!(
+            ciphertext_len = ciphertext.len(),
+            "encrypted with Vault transit"
+        );
+        Ok(ciphertext)
+    }
+
+    fn decrypt(&self, ciphertext: &str, _identity: &()) -> Result<String> {
+        use ::base64::Engine;
+
+        trace!(
+            ciphertext_len = ciphertext.len(),
+            "decrypting with Vault transit"
+        );
+
+        let data = self.call(
+            "decrypt",
+            &self.key,
+            serde_json::json!({ "ciphertext": ciphertext }),
+        )?;
+        let encoded = data
+            .plaintext
+            .ok_or_else(|| CipherError::DecryptionFailed("no plaintext returned".into()))?;
+
+        let bytes = ::base64::engine::general_purpose::STANDARD
+            .decode(encoded)
+            .map_err(|e| CipherError::DecryptionFailed(format!("invalid base64: {}", e)))?;
+        let plaintext = String::from_utf8(bytes)
+            .map_err(|e| CipherError::DecryptionFailed(format!("UTF-8 error: {}", e)))?;
+
+        trace!(
+            plaintext_len = plaintext.len(),
+            "decrypted with Vault transit"
+        );
+        Ok(plaintext)
+    }
+}
+
+#[cfg(feature = "vault")]
+impl KmsBackend for VaultTransit {
+    fn encrypt(&self, plaintext: &str) -> Result<String> {
+        Cipher::encrypt(self, plaintext, &[])
+    }
+
+    fn decrypt(&self, ciphertext: &str) -> Result<String> {
+        Cipher::decrypt(self, ciphertext, &())
+    }
+
+    fn provider(&self) -> &KmsProvider {
+        &KmsProvider::Vault
+    }
+}
@@ -2,37 +2,116 @@
 //!
 //! Handles reading, writing, and validating `.dugout.toml` configuration files.
 
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
 use std::path::PathBuf;
 use tracing::debug;
 
 use crate::core::constants;
+use crate::core::domain::{LineEnding, Role};
+use crate::core::glob;
 use crate::core::types::{EncryptedValue, MemberName, PublicKey, SecretKey};
 use crate::core::vault;
 use crate::error::{ConfigError, Result};
 
 /// Project configuration stored in `.dugout.toml`
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Config {
     /// Metadata about the vault configuration
     pub dugout: Meta,
     /// Optional KMS configuration for hybrid encryption
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub kms: Option<KmsConfig>,
+    /// Optional HashiCorp Vault transit settings for `cipher = "vault-transit"`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vault: Option<TransitConfig>,
     /// Map of recipient names to age public keys.
     #[serde(default)]
     pub recipients: BTreeMap<MemberName, PublicKey>,
+    /// RFC3339 time after which a recipient is no longer encrypted for, keyed by name
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub recipient_expiry: BTreeMap<MemberName, String>,
+    /// Free-form notes about recipients (e.g. "CI deploy key"), keyed by name
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub recipient_notes: BTreeMap<MemberName, String>,
+    /// Team roles keyed by member name; members not listed are `member`
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub roles: BTreeMap<MemberName, Role>,
     /// Map of secret keys to their encrypted values
     #[serde(default)]
     pub secrets: BTreeMap<SecretKey, EncryptedValue>,
+    /// Optional human-readable notes, keyed by secret name (stored in plaintext)
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub descriptions: BTreeMap<SecretKey, String>,
+    /// Descriptions encrypted for the recipients, used when `encrypt_descriptions` is set
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub encrypted_descriptions: BTreeMap<SecretKey, EncryptedValue>,
+    /// RFC3339 timestamp of the last plaintext change, keyed by secret name
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub updated: BTreeMap<SecretKey, String>,
+    /// Optional regex patterns that values must match, keyed by secret name
+    #[serde(
+        default,
+        rename = "validate",
+        skip_serializing_if = "BTreeMap::is_empty"
+    )]
+    pub validations: BTreeMap<SecretKey, String>,
+    /// Alias names pointing at another secret (or alias), keyed by alias name
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub aliases: BTreeMap<SecretKey, SecretKey>,
+    /// Defaults for new vaults, keyed by vault name or glob (`prod`, `prod-*`)
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub profiles: BTreeMap<String, Profile>,
+    /// Vault to use when none is given, keyed by git branch name or glob
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub vault_from_branch: BTreeMap<String, String>,
+    /// Extra `check audit` settings
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub audit: Option<AuditConfig>,
+    /// Commands run after secrets change
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hooks: Option<HooksConfig>,
+}
+
+/// `[audit]` settings for `dugout check audit`.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct AuditConfig {
+    /// Extra leak rules, keyed by rule name, as regular expressions
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub rules: BTreeMap<String, String>,
+}
+
+/// `[hooks]` commands run after a successful write.
+///
+/// Each command is run through the shell with `DUGOUT_EVENT`, `DUGOUT_KEYS`
+/// and `DUGOUT_VAULT` set, once the user has allowed it with
+/// `dugout hooks allow`. Values are never passed to hooks.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct HooksConfig {
+    /// Run after `set` or `import` writes secrets
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub post_set: Option<String>,
+    /// Run after `sync` re-encrypts the vault
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub post_sync: Option<String>,
+}
+
+impl HooksConfig {
+    /// Configured commands, with the event each runs on.
+    pub fn commands(&self) -> Vec<(&'static str, &str)> {
+        [("post_set", &self.post_set), ("post_sync", &self.post_sync)]
+            .into_iter()
+            .filter_map(|(event, command)| command.as_deref().map(|c| (event, c)))
+            .collect()
+    }
 }
 
 /// KMS configuration for hybrid encryption.
 ///
 /// When present, secrets are encrypted for both age recipients (developers)
 /// and a cloud KMS key (production). Provider is auto-detected from key format.
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct KmsConfig {
     /// KMS key identifier.
     ///
@@ -41,14 +120,112 @@ pub struct KmsConfig {
     pub key: String,
 }
 
+/// HashiCorp Vault transit configuration for hybrid encryption.
+///
+/// Used when `[dugout] cipher = "vault-transit"`. Secrets are encrypted for
+/// the age recipients and for a transit key, so developers still decrypt
+/// locally while production decrypts through Vault.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct TransitConfig {
+    /// Vault server address, e.g. `https://vault.example.com:8200`
+    pub address: String,
+    /// Transit key name
+    pub key: String,
+    /// Mount path of the transit secrets engine
+    #[serde(default = "default_transit_mount")]
+    pub mount: String,
+    /// Environment variable holding the Vault token
+    #[serde(default = "default_token_env")]
+    pub token_env: String,
+}
+
+fn default_transit_mount() -> String {
+    "transit".to_string()
+}
+
+fn default_token_env() -> String {
+    "VAULT_TOKEN".to_string()
+}
+
+/// Defaults applied when a vault whose name matches the profile is initialized.
+///
+/// Profiles are read from the default `.dugout.toml`; the chosen settings are
+/// copied into the new vault file, so later opens don't consult them again.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct Profile {
+    /// `"age"` or `"hybrid"`; implied by `kms` when omitted
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cipher: Option<String>,
+    /// KMS key for hybrid vaults
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kms: Option<KmsConfig>,
+}
+
+impl Profile {
+    /// KMS key a vault created from this profile should use, if any.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ConfigError::InvalidValue` if `cipher` is unknown or
+    /// contradicts `kms`.
+    pub fn kms_key(&self, name: &str) -> Result<Option<&str>> {
+        let reason = match (self.cipher.as_deref(), &self.kms) {
+            (None | Some("hybrid"), Some(kms)) => return Ok(Some(kms.key.as_str())),
+            (None | Some("age"), None) => return Ok(None),
+            (Some("hybrid"), None) => "cipher = \"hybrid\" requires kms.key".to_string(),
+            (Some("age"), Some(_)) => "cipher = \"age\" can't have a kms.key".to_string(),
+            (Some(other), _) => format!(
+                "unknown cipher '{}' (expected \"age\" or \"hybrid\")",
+                other
+            ),
+        };
+        Err(ConfigError::InvalidValue {
+            field: "profiles",
+            reason: format!("profile '{}': {}", name, reason),
+        }
+        .into())
+    }
+}
+
 /// Metadata section of the configuration
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Meta {
     /// Configuration version
     pub version: String,
     /// SHA-256 hash of sorted recipient public keys (for sync detection)
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub recipients_hash: Option<String>,
+    /// Cipher backend (`age`, `hybrid+aws`, ...) secrets were last re-encrypted with
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_synced_backend: Option<String>,
+    /// Line ending for `.env` files written by `unlock` (LF when omitted)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub env_line_ending: Option<LineEnding>,
+    /// Cipher backend: `"age"`, `"hybrid"`, or `"vault-transit"`; inferred when omitted
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cipher: Option<String>,
+    /// Require confirmation (or `--yes`) before changing this vault
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub confirm_mutations: bool,
+    /// Encrypt new descriptions for the recipients instead of storing plaintext
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub encrypt_descriptions: bool,
+    /// Vault names or globs treated as if they set `confirm_mutations`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub protected: Vec<String>,
+    /// Keep `[secrets]` in a sidecar file (`.dugout-secrets.toml`)
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub split_secrets: bool,
+    /// Key fingerprint of each recipient at the last sync (for `sync --dry-run`)
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub synced_recipients: BTreeMap<MemberName, String>,
+}
+
+/// Contents of the secrets sidecar file.
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct Sidecar {
+    #[serde(default)]
+    secrets: BTreeMap<SecretKey, EncryptedValue>,
 }
 
 impl Config {
@@ -58,10 +235,31 @@ impl Config {
             dugout: Meta {
                 version: env!("CARGO_PKG_VERSION").to_string(),
                 recipients_hash: None,
+                last_synced_backend: None,
+                env_line_ending: None,
+                cipher: None,
+                confirm_mutations: false,
+                encrypt_descriptions: false,
+                protected: Vec::new(),
+                split_secrets: false,
+                synced_recipients: BTreeMap::new(),
             },
             kms: None,
+            vault: None,
             recipients: BTreeMap::new(),
+            recipient_expiry: BTreeMap::new(),
+            recipient_notes: BTreeMap::new(),
+            roles: BTreeMap::new(),
             secrets: BTreeMap::new(),
+            descriptions: BTreeMap::new(),
+            encrypted_descriptions: BTreeMap::new(),
+            updated: BTreeMap::new(),
+            validations: BTreeMap::new(),
+            aliases: BTreeMap::new(),
+            profiles: BTreeMap::new(),
+            vault_from_branch: BTreeMap::new(),
+            audit: None,
+            hooks: None,
         }
     }
 
@@ -75,7 +273,22 @@ impl Config {
         Self::config_path_for(vault).exists()
     }
 
+    /// Find a default vault file in a parent of the current directory.
+    ///
+    /// Returns the nearest one; the current directory itself is not checked.
+    pub fn find_in_ancestors() -> Option<PathBuf> {
+        let cwd = std::env::current_dir().ok()?;
+        cwd.ancestors()
+            .skip(1)
+            .map(|dir| dir.join(constants::CONFIG_FILE))
+            .find(|path| path.is_file())
+    }
+
     /// Load configuration from vault file.
+    ///
+    /// With `split_secrets`, secrets are read from the sidecar file. Entries
+    /// still in the main file (e.g. just after turning the split on) are
+    /// merged in and take precedence; the next save moves them.
     pub fn load_from(vault: Option<&str>) -> Result<Self> {
         let path = Self::config_path_for(vault);
         debug!(path = %path.display(), "loading config");
@@ -83,8 +296,14 @@ impl Config {
         if !path.exists() {
             return Err(ConfigError::NotInitialized.into());
         }
-        let contents = std::fs::read_to_string(&path).map_err(ConfigError::ReadFile)?;
-        let config: Self = toml::from_str(&contents).map_err(ConfigError::Parse)?;
+        let contents = read_checked(&path)?;
+        let sidecar = constants::sidecar_path(vault);
+        let sidecar = if sidecar.exists() {
+            Some(read_checked(&sidecar)?)
+        } else {
+            None
+        };
+        let config = Self::parse_split(&contents, sidecar.as_deref())?;
 
         debug!(
             secrets = config.secrets.len(),
@@ -96,18 +315,60 @@ impl Config {
         Ok(config)
     }
 
+    /// Parse a vault file together with its secrets sidecar, if any.
+    ///
+    /// The sidecar is only read when the vault sets `split_secrets`.
+    /// Does not validate the result.
+    pub(crate) fn parse_split(main: &str, sidecar: Option<&str>) -> Result<Self> {
+        let mut config: Self = toml::from_str(main).map_err(ConfigError::Parse)?;
+        if let (true, Some(sidecar)) = (config.dugout.split_secrets, sidecar) {
+            let sidecar: Sidecar = toml::from_str(sidecar).map_err(ConfigError::Parse)?;
+            let legacy = std::mem::replace(&mut config.secrets, sidecar.secrets);
+            config.secrets.extend(legacy);
+        }
+        Ok(config)
+    }
+
     /// Save configuration to vault file.
     ///
     /// Uses atomic write (temp file + rename) to prevent corruption on crash.
+    /// With `split_secrets`, secrets go to the sidecar file: both files are
+    /// written to temp files before either is renamed into place. Without
+    /// it, a leftover sidecar is removed once the main file holds the secrets.
     pub fn save_to(&self, vault: Option<&str>) -> Result<()> {
         debug!("saving config");
-        let contents = toml::to_string_pretty(self).map_err(ConfigError::Serialize)?;
         let target_path = Self::config_path_for(vault);
+        let sidecar_path = constants::sidecar_path(vault);
+
+        if !self.dugout.split_secrets {
+            let contents = toml::to_string_pretty(self).map_err(ConfigError::Serialize)?;
+            write_atomic(&target_path, &contents)?;
+            if sidecar_path.exists() {
+                std::fs::remove_file(&sidecar_path)?;
+            }
+            return Ok(());
+        }
 
-        // Write to temp file in same directory, then rename for atomicity
-        let temp_path = target_path.with_extension("toml.tmp");
-        std::fs::write(&temp_path, &contents)?;
-        std::fs::rename(&temp_path, &target_path)?;
+        let main = Self {
+            secrets: BTreeMap::new(),
+            ..self.clone()
+        };
+        let sidecar = Sidecar {
+            secrets: self.secrets.clone(),
+        };
+        let main = toml::to_string_pretty(&main).map_err(ConfigError::Serialize)?;
+        let sidecar = toml::to_string_pretty(&sidecar).map_err(ConfigError::Serialize)?;
+
+        // Stage both files before replacing either
+        let main_temp = target_path.with_extension("toml.tmp");
+        let sidecar_temp = sidecar_path.with_extension("toml.tmp");
+        std::fs::write(&sidecar_temp, &sidecar)?;
+        if let Err(e) = std::fs::write(&main_temp, &main) {
+            let _ = std::fs::remove_file(&sidecar_temp);
+            return Err(e.into());
+        }
+        std::fs::rename(&sidecar_temp, &sidecar_path)?;
+        std::fs::rename(&main_temp, &target_path)?;
 
         Ok(())
     }
@@ -145,18 +406,79 @@ impl Config {
         self.kms.is_some()
     }
 
+    /// Get the Vault transit settings if `cipher = "vault-transit"`.
+    pub fn transit(&self) -> Option<&TransitConfig> {
+        match self.dugout.cipher.as_deref() {
+            Some("vault-transit") => self.vault.as_ref(),
+            _ => None,
+        }
+    }
+
     /// Get the KMS key if configured.
     pub fn kms_key(&self) -> Option<&str> {
         self.kms.as_ref().map(|k| k.key.as_str())
     }
 
+    /// Find the profile for a vault name.
+    ///
+    /// An exact name wins over glob patterns; among patterns, the first in
+    /// sorted order matches.
+    pub fn profile_for(&self, vault: &str) -> Option<(&str, &Profile)> {
+        if let Some((name, profile)) = self.profiles.get_key_value(vault) {
+            return Some((name.as_str(), profile));
+        }
+        self.profiles
+            .iter()
+            .find(|(pattern, _)| glob::matches(pattern, vault))
+            .map(|(pattern, profile)| (pattern.as_str(), profile))
+    }
+
+    /// Find the vault mapped to a git branch.
+    ///
+    /// Matches the same way as `profile_for`: an exact branch name wins over
+    /// glob patterns (`release/*`).
+    pub fn vault_for_branch(&self, branch: &str) -> Option<&str> {
+        if let Some(vault) = self.vault_from_branch.get(branch) {
+            return Some(vault.as_str());
+        }
+        self.vault_from_branch
+            .iter()
+            .find(|(pattern, _)| glob::matches(pattern, branch))
+            .map(|(_, vault)| vault.as_str())
+    }
+
+    /// When a recipient's access expires, if it has an expiry.
+    ///
+    /// Malformed timestamps are rejected by `validate`, so they read as `None` here.
+    pub fn recipient_expiry(&self, name: &str) -> Option<DateTime<Utc>> {
+        self.recipient_expiry
+            .get(name)
+            .and_then(|ts| DateTime::parse_from_rfc3339(ts).ok())
+            .map(|ts| ts.with_timezone(&Utc))
+    }
+
+    /// Whether a recipient's expiry has passed.
+    pub fn is_expired(&self, name: &str) -> bool {
+        self.recipient_expiry(name)
+            .is_some_and(|expires| expires <= Utc::now())
+    }
+
+    /// Check whether a vault name matches one of the `protected` patterns.
+    pub fn is_protected(&self, vault: &str) -> bool {
+        glob::matches_any(&self.dugout.protected, vault)
+    }
+
     /// Validate the configuration structure and contents.
     ///
     /// Checks:
     /// - Version field is valid semver
     /// - At least one recipient exists
     /// - Recipients are valid age public keys
+    /// - Recipient expiry times are RFC3339 timestamps
     /// - All secret keys are valid environment variable names
+    /// - Validation patterns and audit rules are valid regular expressions
+    /// - The cipher is known and `vault-transit` has a `[vault]` section
+    /// - Profiles name a known cipher consistent with their KMS key
     ///
     /// # Errors
     ///
@@ -197,11 +519,65 @@ impl Config {
             }
         }
 
+        // Validate expiry timestamps parse
+        for (name, ts) in &self.recipient_expiry {
+            if DateTime::parse_from_rfc3339(ts).is_err() {
+                return Err(ConfigError::InvalidValue {
+                    field: "recipient_expiry",
+                    reason: format!("invalid RFC3339 timestamp for '{}': {}", name, ts),
+                }
+                .into());
+            }
+        }
+
         // Validate secret keys are valid env var names
         for key in self.secrets.keys() {
             vault::validate_key(key)?;
         }
 
+        // Validate alias names are valid env var names
+        for key in self.aliases.keys() {
+            vault::validate_key(key)?;
+        }
+
+        // Validate the cipher names a known backend with its settings present
+        match self.dugout.cipher.as_deref() {
+            None | Some("age") | Some("hybrid") => {}
+            Some("vault-transit") if self.vault.is_none() => {
+                return Err(ConfigError::MissingField { field: "vault" }.into());
+            }
+            Some("vault-transit") => {}
+            Some(other) => {
+                return Err(ConfigError::InvalidValue {
+                    field: "cipher",
+                    reason: format!(
+                        "unknown cipher '{}' (expected \"age\", \"hybrid\" or \"vault-transit\")",
+                        other
+                    ),
+                }
+                .into());
+            }
+        }
+
+        // Validate profiles are self-consistent
+        for (name, profile) in &self.profiles {
+            profile.kms_key(name)?;
+        }
+
+        // Validate patterns compile
+        for (key, pattern) in &self.validations {
+            if let Err(e) = regex::Regex::new(pattern) {
+                return Err(ConfigError::InvalidValue {
+                    field: "validate",
+                    reason: format!("invalid pattern for '{}': {}", key, e),
+                }
+                .into());
+            }
+        }
+        if let Some(audit) = &self.audit {
+            crate::core::domain::audit::Rule::load(&audit.rules)?;
+        }
+
         Ok(())
     }
 }
@@ -246,6 +622,134 @@ pub fn ensure_gitignore() -> Result<()> {
     Ok(())
 }
 
+/// Read a vault file, refusing one with unresolved merge conflicts.
+fn read_checked(path: &std::path::Path) -> Result<String> {
+    let contents = std::fs::read_to_string(path).map_err(ConfigError::ReadFile)?;
+    if let Some(line) = find_conflict_marker(&contents) {
+        return Err(ConfigError::MergeConflict {
+            path: path.display().to_string(),
+            line,
+        }
+        .into());
+    }
+    Ok(contents)
+}
+
+/// Write a file via a temp file in the same directory and a rename.
+fn write_atomic(path: &std::path::Path, contents: &str) -> Result<()> {
+    let temp_path = path.with_extension("toml.tmp");
+    std::fs::write(&temp_path, contents)?;
+    std::fs::rename(&temp_path, path)?;
+    Ok(())
+}
+
+/// Find the first git merge conflict marker, returning its 1-based line number.
+pub(crate) fn find_conflict_marker(contents: &str) -> Option<usize> {
+    contents
+        .lines()
+        .position(|line| {
+            line.starts_with("<<<<<<<")
+                || line.starts_with(">>>>>>>")
+                || line.starts_with("|||||||")
+                || line == "======="
+        })
+        .map(|i| i + 1)
+}
+
+/// The versions of a conflicted file, split out of its conflict hunks.
+pub(crate) struct Conflict {
+    pub ours: String,
+    pub theirs: String,
+    /// The merge base, when every hunk carries a diff3 base section
+    pub base: Option<String>,
+}
+
+impl Conflict {
+    /// The versions of a file without conflicts: all the same.
+    pub(crate) fn unchanged(contents: &str) -> Self {
+        Self {
+            ours: contents.to_string(),
+            theirs: contents.to_string(),
+            base: Some(contents.to_string()),
+        }
+    }
+}
+
+/// Split a conflicted file into the "ours", "theirs" and base versions.
+///
+/// Lines outside conflict hunks go to every version. The base is only
+/// rebuilt from diff3 (`|||||||`) sections, so it is `None` for plain merge
+/// conflict style. Returns `None` if there are no complete conflict hunks.
+pub(crate) fn split_conflict(contents: &str) -> Option<Conflict> {
+    enum Section {
+        Both,
+        Ours,
+        Base,
+        Theirs,
+    }
+
+    let mut section = Section::Both;
+    let mut found = false;
+    let mut ours = String::new();
+    let mut theirs = String::new();
+    let mut base = String::new();
+    let mut hunk_has_base = false;
+    let mut every_hunk_has_base = true;
+
+    for line in contents.lines() {
+        section = match section {
+            Section::Both if line.starts_with("<<<<<<<") => {
+                found = true;
+                hunk_has_base = false;
+                Section::Ours
+            }
+            Section::Ours if line.starts_with("|||||||") => {
+                hunk_has_base = true;
+                Section::Base
+            }
+            Section::Ours | Section::Base if line == "=======" => Section::Theirs,
+            Section::Theirs if line.starts_with(">>>>>>>") => {
+                every_hunk_has_base &= hunk_has_base;
+                Section::Both
+            }
+            current => {
+                match current {
+                    Section::Both => {
+                        ours.push_str(line);
+                        ours.push('\n');
+                        theirs.push_str(line);
+                        theirs.push('\n');
+                        base.push_str(line);
+                        base.push('\n');
+                    }
+                    Section::Ours => {
+                        ours.push_str(line);
+                        ours.push('\n');
+                    }
+                    Section::Theirs => {
+                        theirs.push_str(line);
+                        theirs.push('\n');
+                    }
+                    Section::Base => {
+                        base.push_str(line);
+                        base.push('\n');
+                    }
+                }
+                current
+            }
+        };
+    }
+
+    match section {
+        Section::Both if found => Some(Conflict {
+            ours,
+            theirs,
+            base: every_hunk_has_base.then_some(base),
+        }),
+        _ => None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -305,6 +809,59 @@ mod tests {
         assert!(loaded.secrets.contains_key("TEST_KEY"));
     }
 
+    #[test]
+    fn test_config_split_secrets_roundtrip() {
+        let _ctx = setup_test_dir();
+
+        let identity = age::x25519::Identity::generate();
+        let mut config = Config::new();
+        config
+            .recipients
+            .insert("alice".to_string(), identity.to_public().to_string());
+        config.secrets.insert(
+            "TEST_KEY".to_string(),
+            "-----BEGIN AGE ENCRYPTED FILE-----\ntest\n-----END AGE ENCRYPTED FILE-----"
+                .to_string(),
+        );
+        config.dugout.split_secrets = true;
+        config.save().unwrap();
+
+        let main = std::fs::read_to_string(".dugout.toml").unwrap();
+        let sidecar = std::fs::read_to_string(".dugout-secrets.toml").unwrap();
+        assert!(!main.contains("TEST_KEY"));
+        assert!(main.contains("alice"));
+        assert!(sidecar.contains("TEST_KEY"));
+        assert!(!sidecar.contains("alice"));
+
+        let mut loaded = Config::load().unwrap();
+        assert!(loaded.secrets.contains_key("TEST_KEY"));
+
+        // Turning the split off moves secrets back and drops the sidecar
+        loaded.dugout.split_secrets = false;
+        loaded.save().unwrap();
+        assert!(!std::path::Path::new(".dugout-secrets.toml").exists());
+        let main = std::fs::read_to_string(".dugout.toml").unwrap();
+        assert!(main.contains("TEST_KEY"));
+        assert!(Config::load().unwrap().secrets.contains_key("TEST_KEY"));
+    }
+
+    #[test]
+    fn test_config_without_descriptions_table_loads() {
+        let _ctx = setup_test_dir();
+
+        let identity = age::x25519::Identity::generate();
+        let pubkey = identity.to_public().to_string();
+        let contents = format!(
+            "[dugout]\nversion = \"0.1.0\"\n\n[recipients]\nalice = \"{}\"\n\n[secrets]\n",
+            pubkey
+        );
+        std::fs::write(".dugout.toml", contents).unwrap();
+
+        let loaded = Config::load().unwrap();
+        assert!(loaded.descriptions.is_empty());
+        assert!(loaded.updated.is_empty());
+    }
+
     #[test]
     fn test_config_validate_valid() {
         let _ctx = setup_test_dir();
@@ -349,6 +906,127 @@ mod tests {
         // Should fail because secret key has invalid characters
     }
 
+    #[test]
+    fn test_config_validate_bad_pattern() {
+        let _ctx = setup_test_dir();
+
+        let identity = age::x25519::Identity::generate();
+        let pubkey = identity.to_public().to_string();
+
+        let mut config = Config::new();
+        config.recipients.insert("alice".to_string(), pubkey);
+        config
+            .validations
+            .insert("PORT".to_string(), "^[0-9+$".to_string());
+
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_find_conflict_marker() {
+        assert_eq!(find_conflict_marker("[secrets]\nA = \"x\"\n"), None);
+        assert_eq!(
+            find_conflict_marker("[secrets]\n<<<<<<< HEAD\nA = \"x\"\n=======\n>>>>>>> main\n"),
+            Some(2)
+        );
+    }
+
+    #[test]
+    fn test_split_conflict() {
+        let contents = "[secrets]\n<<<<<<< HEAD\nA = \"1\"\n||||||| base\nA = \"0\"\n=======\nB = \"2\"\n>>>>>>> main\nC = \"3\"\n";
+        let conflict = split_conflict(contents).unwrap();
+        assert_eq!(conflict.ours, "[secrets]\nA = \"1\"\nC = \"3\"\n");
+        assert_eq!(conflict.theirs, "[secrets]\nB = \"2\"\nC = \"3\"\n");
+        assert_eq!(
+            conflict.base.as_deref(),
+            Some("[secrets]\nA = \"0\"\nC = \"3\"\n")
+        );
+
+        // Without diff3 sections there is no base to rebuild
+        let conflict =
+            split_conflict("<<<<<<< HEAD\nA = \"1\"\n=======\nB = \"2\"\n>>>>>>> main\n").unwrap();
+        assert!(conflict.base.is_none());
+
+        assert!(split_conflict("[secrets]\n").is_none());
+        assert!(split_conflict("<<<<<<< HEAD\nA = \"1\"\n").is_none());
+    }
+
+    #[test]
+    fn test_profile_for_prefers_exact_name() {
+        let mut config = Config::new();
+        let hybrid = Profile {
+            cipher: None,
+            kms: Some(KmsConfig {
+                key: "arn:aws:kms:us-east-1:123:key/abc".to_string(),
+            }),
+        };
+        config.profiles.insert("prod*".to_string(), hybrid);
+        config
+            .profiles
+            .insert("prod-eu".to_string(), Profile::default());
+
+        let (name, _) = config.profile_for("prod-eu").unwrap();
+        assert_eq!(name, "prod-eu");
+        let (name, profile) = config.profile_for("prod-us").unwrap();
+        assert_eq!(name, "prod*");
+        assert_eq!(
+            profile.kms_key(name).unwrap(),
+            Some("arn:aws:kms:us-east-1:123:key/abc")
+        );
+        assert!(config.profile_for("dev").is_none());
+    }
+
+    #[test]
+    fn test_profile_kms_key_rejects_contradictions() {
+        let kms = Some(KmsConfig {
+            key: "arn:aws:kms:us-east-1:123:key/abc".to_string(),
+        });
+
+        let age = Profile {
+            cipher: Some("age".to_string()),
+            kms: None,
+        };
+        assert_eq!(age.kms_key("dev").unwrap(), None);
+
+        let hybrid_without_key = Profile {
+            cipher: Some("hybrid".to_string()),
+            kms: None,
+        };
+        assert!(hybrid_without_key.kms_key("prod").is_err());
+
+        let age_with_key = Profile {
+            cipher: Some("age".to_string()),
+            kms: kms.clone(),
+        };
+        assert!(age_with_key.kms_key("prod").is_err());
+
+        let unknown = Profile {
+            cipher: Some("rot13".to_string()),
+            kms,
+        };
+        let err = unknown.kms_key("prod").unwrap_err().to_string();
+        assert!(err.contains("profile 'prod'"));
+    }
+
+    #[test]
+    fn test_vault_for_branch() {
+        let mut config = Config::new();
+        config
+            .vault_from_branch
+            .insert("main".to_string(), "prod".to_string());
+        config
+            .vault_from_branch
+            .insert("release/*".to_string(), "staging".to_string());
+        config
+            .vault_from_branch
+            .insert("release/hotfix".to_string(), "prod".to_string());
+
+        assert_eq!(config.vault_for_branch("main"), Some("prod"));
+        assert_eq!(config.vault_for_branch("release/1.2"), Some("staging"));
+        assert_eq!(config.vault_for_branch("release/hotfix"), Some("prod"));
+        assert_eq!(config.vault_for_branch("feature/x"), None);
+    }
+
     #[test]
     fn test_config_path_for_vault() {
         assert_eq!(
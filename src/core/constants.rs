@@ -5,6 +5,12 @@
 /// Configuration file name (.dugout.toml).
 pub const CONFIG_FILE: &str = ".dugout.toml";
 
+/// Secrets sidecar file name for the default vault (`.dugout-secrets.toml`).
+///
+/// Vault files all start with `.dugout.`, so the `-` keeps every sidecar
+/// name clear of them whatever the vault is called.
+pub const SIDECAR_FILE: &str = ".dugout-secrets.toml";
+
 /// Environment variables file name (.env).
 #[allow(dead_code)]
 pub const ENV_FILE: &str = ".env";
@@ -12,6 +18,27 @@ pub const ENV_FILE: &str = ".env";
 /// Key storage directory relative to HOME (~/.dugout/keys).
 pub const KEY_DIR: &str = ".dugout/keys";
 
+/// Operation log directory relative to HOME (~/.dugout/log).
+pub const LOG_DIR: &str = ".dugout/log";
+
+/// Environment variable that turns off the local operation log when set.
+pub const NO_OPLOG_ENV: &str = "DUGOUT_NO_OPLOG";
+
+/// Read cache directory relative to HOME (~/.dugout/cache).
+pub const CACHE_DIR: &str = ".dugout/cache";
+
+/// Hook allow-list relative to HOME (~/.dugout/hooks.allow).
+pub const HOOKS_ALLOW_FILE: &str = ".dugout/hooks.allow";
+
+/// Environment variable holding the passphrase for a protected identity.
+pub const PASSPHRASE_ENV: &str = "DUGOUT_PASSPHRASE";
+
+/// Environment variable holding the key for `.env.vault` files.
+pub const DOTENV_KEY_ENV: &str = "DOTENV_KEY";
+
+/// Per-project opt-in marker for the shell auto-load hook.
+pub const AUTOLOAD_MARKER: &str = ".dugout/autoload";
+
 /// Gitignore entries to protect secrets.
 ///
 /// These entries ensure that .env files are not accidentally committed.
@@ -43,6 +70,26 @@ pub fn vault_path(vault: Option<&str>) -> std::path::PathBuf {
     }
 }
 
+/// Get the secrets sidecar path for given vault.
+///
+/// Used when the vault sets `split_secrets`.
+///
+/// - `None` → `.dugout-secrets.toml` (default)
+/// - `Some("dev")` → `.dugout-secrets.dev.toml`
+///
+/// # Panics
+///
+/// Panics if vault name contains path separators (defense in depth).
+pub fn sidecar_path(vault: Option<&str>) -> std::path::PathBuf {
+    match vault {
+        None | Some("default") => std::path::PathBuf::from(SIDECAR_FILE),
+        Some(name) => {
+            assert!(is_safe_vault_name(name), "unsafe vault name: {}", name);
+            std::path::PathBuf::from(format!(".dugout-secrets.{}.toml", name))
+        }
+    }
+}
+
 /// Get request directory for given vault.
 ///
 /// - `None` → `.dugout/requests/default`
@@ -103,6 +150,26 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_sidecar_path() {
+        assert_eq!(
+            sidecar_path(None),
+            std::path::PathBuf::from(".dugout-secrets.toml")
+        );
+        assert_eq!(
+            sidecar_path(Some("dev")),
+            std::path::PathBuf::from(".dugout-secrets.dev.toml")
+        );
+        assert_eq!(
+            sidecar_path(Some("secrets")),
+            std::path::PathBuf::from(".dugout-secrets.secrets.toml")
+        );
+        assert_eq!(
+            vault_name_from_path(std::path::Path::new(".dugout.secrets.toml")),
+            Some("secrets".to_string())
+        );
+    }
+
     #[test]
     fn test_request_dir_default() {
         assert_eq!(
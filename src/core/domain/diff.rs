@@ -4,30 +4,69 @@
 
 use std::collections::{HashMap, HashSet};
 
+use serde::Serialize;
+use zeroize::Zeroizing;
+
+use crate::core::glob;
+
 /// The sync state of a single secret
-#[derive(Debug, Clone, PartialEq, Eq)]
+///
+/// Serialized from the point of view of the vault relative to `.env`:
+/// `added`, `removed`, `changed`, or `unchanged`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub enum EntryStatus {
     /// Secret exists in both vault and .env with matching values
+    #[serde(rename = "unchanged")]
     Synced,
     /// Secret exists in both but values differ
+    #[serde(rename = "changed")]
     Modified,
     /// Secret exists in vault but not in .env
+    #[serde(rename = "added")]
     VaultOnly,
     /// Secret exists in .env but not in vault
+    #[serde(rename = "removed")]
     EnvOnly,
 }
 
 /// A single entry in a diff comparison
-#[derive(Debug, Clone)]
+///
+/// Values are kept for `--show-values` but never serialized or debug-printed.
+#[derive(Clone, Serialize)]
 pub struct DiffEntry {
     key: String,
     status: EntryStatus,
+    #[serde(skip)]
+    vault_value: Option<Zeroizing<String>>,
+    #[serde(skip)]
+    env_value: Option<Zeroizing<String>>,
+}
+
+impl std::fmt::Debug for DiffEntry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DiffEntry")
+            .field("key", &self.key)
+            .field("status", &self.status)
+            .finish_non_exhaustive()
+    }
 }
 
 impl DiffEntry {
     /// Create a new diff entry
     pub fn new(key: String, status: EntryStatus) -> Self {
-        Self { key, status }
+        Self {
+            key,
+            status,
+            vault_value: None,
+            env_value: None,
+        }
+    }
+
+    /// Attach the compared plaintext values
+    pub fn with_values(mut self, vault: Option<&str>, env: Option<&str>) -> Self {
+        self.vault_value = vault.map(|v| Zeroizing::new(v.to_string()));
+        self.env_value = env.map(|v| Zeroizing::new(v.to_string()));
+        self
     }
 
     /// Secret key name
@@ -44,6 +83,16 @@ impl DiffEntry {
     pub fn is_synced(&self) -> bool {
         matches!(self.status, EntryStatus::Synced)
     }
+
+    /// Plaintext value in the vault, if the key is there
+    pub fn vault_value(&self) -> Option<&str> {
+        self.vault_value.as_deref().map(String::as_str)
+    }
+
+    /// Value in .env, if the key is there
+    pub fn env_value(&self) -> Option<&str> {
+        self.env_value.as_deref().map(String::as_str)
+    }
 }
 
 /// Comparison between vault secrets and .env file
@@ -78,7 +127,10 @@ impl Diff {
                 (None, None) => unreachable!("key must exist in at least one map"),
             };
 
-            entries.push(DiffEntry::new((*key).clone(), status));
+            entries.push(DiffEntry::new((*key).clone(), status).with_values(
+                vault_value.map(String::as_str),
+                env_value.map(String::as_str),
+            ));
         }
 
         // Sort by key name for consistent output
@@ -87,6 +139,18 @@ impl Diff {
         Self { entries }
     }
 
+    /// Drop entries whose key matches any of the given patterns.
+    ///
+    /// Patterns are exact key names or globs (`BUILD_*`). Ignored keys are
+    /// excluded from every view, including [`Diff::is_synced`].
+    pub fn ignore(mut self, patterns: &[String]) -> Self {
+        if !patterns.is_empty() {
+            self.entries
+                .retain(|e| !glob::matches_any(patterns, &e.key));
+        }
+        self
+    }
+
     /// All entries
     pub fn entries(&self) -> &[DiffEntry] {
         &self.entries
@@ -242,6 +306,24 @@ mod tests {
         assert_eq!(diff.len(), 0);
     }
 
+    #[test]
+    fn test_diff_ignore() {
+        let vault = vec![
+            ("API_KEY".to_string(), "same".to_string()),
+            ("BUILD_TIME".to_string(), "100".to_string()),
+        ];
+        let env = vec![
+            ("API_KEY".to_string(), "same".to_string()),
+            ("BUILD_TIME".to_string(), "200".to_string()),
+        ];
+
+        let diff = Diff::compute(&vault, &env).ignore(&["BUILD_*".to_string()]);
+
+        assert_eq!(diff.len(), 1);
+        assert!(diff.is_synced());
+        assert!(diff.modified().is_empty());
+    }
+
     #[test]
     fn test_diff_entry_is_synced() {
         let synced = DiffEntry::new("KEY".to_string(), EntryStatus::Synced);
@@ -250,4 +332,26 @@ mod tests {
         assert!(synced.is_synced());
         assert!(!modified.is_synced());
     }
+
+    #[test]
+    fn test_diff_entry_serializes_without_values() {
+        let vault = vec![("API_KEY".to_string(), "secret123".to_string())];
+        let env = vec![
+            ("API_KEY".to_string(), "different".to_string()),
+            ("LOCAL".to_string(), "only-here".to_string()),
+        ];
+        let diff = Diff::compute(&vault, &env);
+
+        let json = serde_json::to_string(diff.entries()).unwrap();
+        assert_eq!(
+            json,
+            r#"[{"key":"API_KEY","status":"changed"},{"key":"LOCAL","status":"removed"}]"#
+        );
+        assert!(!format!("{:?}", diff).contains("secret123"));
+
+        let entry = &diff.entries()[0];
+        assert_eq!(entry.vault_value(), Some("secret123"));
+        assert_eq!(entry.env_value(), Some("different"));
+        assert_eq!(diff.entries()[1].vault_value(), None);
+    }
 }
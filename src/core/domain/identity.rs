@@ -3,17 +3,30 @@
 //! Wraps an age private key with secure memory handling.
 
 use std::borrow::Cow;
+use std::collections::HashMap;
 use std::fs;
+use std::io::{IsTerminal, Read, Write};
 use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock};
 
-use age::x25519;
+use age::secrecy::SecretString;
+use age::{ssh, x25519};
 use tracing::debug;
 
+use crate::core::cipher::{self, AgeIdentity};
 use crate::core::constants;
 use crate::core::types::PublicKey;
 #[cfg(unix)]
 use crate::error::ValidationError;
-use crate::error::{Result, StoreError};
+use crate::error::{ConfigError, Result, StoreError};
+
+/// First line of a passphrase-protected key file (age ASCII armor).
+const ARMOR_BEGIN: &str = "-----BEGIN AGE ENCRYPTED FILE-----";
+
+/// Identities unlocked from passphrase-protected files in this process,
+/// keyed by file contents so a copied key file isn't prompted for twice.
+/// Held in memory only.
+static UNLOCKED: OnceLock<Mutex<HashMap<String, x25519::Identity>>> = OnceLock::new();
 
 /// Source of an identity
 #[derive(Debug, Clone)]
@@ -23,22 +36,43 @@ pub enum IdentitySource {
     /// Identity loaded from macOS Keychain
     #[cfg(target_os = "macos")]
     Keychain { account: String },
+    /// Identity loaded from Windows Credential Manager
+    #[cfg(target_os = "windows")]
+    CredentialManager { target: String },
+    /// Identity loaded from the Secret Service (Linux desktop keyring)
+    #[cfg(feature = "secret-service")]
+    SecretService { account: String },
     /// Identity loaded from environment variable
     Environment { name: String },
 }
 
 /// A private key identity for decrypting secrets
 pub struct Identity {
-    inner: x25519::Identity,
+    key: Key,
     source: IdentitySource,
 }
 
+/// Private key material behind an identity
+enum Key {
+    /// Native age key (`AGE-SECRET-KEY-...`)
+    X25519(x25519::Identity),
+    /// OpenSSH ed25519 key, with the public key vaults list it under
+    Ssh {
+        identity: ssh::Identity,
+        public_key: PublicKey,
+    },
+}
+
 impl Identity {
     /// Create a new Identity from components
     ///
-    /// This constructor is primarily for use by storage backends (e.g., Keychain)
+    /// This constructor is primarily for use by storage backends (e.g., Keychain,
+    /// Credential Manager)
     pub fn from_parts(inner: x25519::Identity, source: IdentitySource) -> Self {
-        Self { inner, source }
+        Self {
+            key: Key::X25519(inner),
+            source,
+        }
     }
 
     /// Load an identity from the key directory
@@ -57,16 +91,12 @@ impl Identity {
         }
 
         let contents = fs::read_to_string(&key_path).map_err(StoreError::ReadFailed)?;
-
-        let inner: x25519::Identity = contents
-            .trim()
-            .parse()
-            .map_err(|e: &str| StoreError::InvalidFormat(e.to_string()))?;
+        let inner = Self::parse_key_file(&key_path, &contents)?;
 
         debug!("identity loaded");
 
         Ok(Self {
-            inner,
+            key: Key::X25519(inner),
             source: IdentitySource::Filesystem(key_path),
         })
     }
@@ -98,19 +128,81 @@ impl Identity {
         debug!(path = %key_path.display(), "identity saved");
 
         Ok(Self {
-            inner,
+            key: Key::X25519(inner),
+            source: IdentitySource::Filesystem(key_path),
+        })
+    }
+
+    /// Load an SSH ed25519 identity (`~/.ssh/id_ed25519`).
+    ///
+    /// Used as a fallback for vaults that list the user's SSH key as a
+    /// recipient. Passphrase-protected SSH keys are not supported.
+    pub fn load_ssh() -> Result<Self> {
+        let key_path = Self::ssh_key_path()?;
+        debug!(path = %key_path.display(), "loading SSH identity");
+
+        if !key_path.exists() {
+            return Err(StoreError::NoPrivateKey(key_path.display().to_string()).into());
+        }
+
+        let contents = fs::read_to_string(&key_path).map_err(StoreError::ReadFailed)?;
+        let identity =
+            ssh::Identity::from_buffer(contents.as_bytes(), Some(key_path.display().to_string()))
+                .map_err(StoreError::ReadFailed)?;
+        if !matches!(identity, ssh::Identity::Unencrypted(_)) {
+            return Err(StoreError::InvalidFormat(format!(
+                "{} is passphrase-protected or not an ed25519 key",
+                key_path.display()
+            ))
+            .into());
+        }
+
+        let pub_path = key_path.with_extension("pub");
+        let pub_contents = fs::read_to_string(&pub_path).map_err(StoreError::ReadFailed)?;
+        let public_key = cipher::parse_recipient(&pub_contents)?.to_string();
+
+        Ok(Self {
+            key: Key::Ssh {
+                identity,
+                public_key,
+            },
             source: IdentitySource::Filesystem(key_path),
         })
     }
 
+    /// SSH ed25519 private key path (`~/.ssh/id_ed25519`)
+    fn ssh_key_path() -> Result<PathBuf> {
+        let home = std::env::var_os("HOME")
+            .map(PathBuf::from)
+            .or_else(dirs::home_dir)
+            .ok_or_else(|| {
+                StoreError::GenerationFailed("unable to determine home directory".to_string())
+            })?;
+        Ok(home.join(".ssh").join("id_ed25519"))
+    }
+
     /// Corresponding public key
     pub fn public_key(&self) -> PublicKey {
-        self.inner.to_public().to_string()
+        match &self.key {
+            Key::X25519(inner) => inner.to_public().to_string(),
+            Key::Ssh { public_key, .. } => public_key.clone(),
+        }
     }
 
     /// Reference to the inner age identity for decryption
-    pub fn as_age(&self) -> &x25519::Identity {
-        &self.inner
+    pub fn as_age(&self) -> &AgeIdentity {
+        match &self.key {
+            Key::X25519(inner) => inner,
+            Key::Ssh { identity, .. } => identity,
+        }
+    }
+
+    /// The native age key, if this isn't an SSH identity
+    pub fn as_x25519(&self) -> Option<&x25519::Identity> {
+        match &self.key {
+            Key::X25519(inner) => Some(inner),
+            Key::Ssh { .. } => None,
+        }
     }
 
     /// Key file path or virtual path for non-filesystem sources
@@ -121,6 +213,14 @@ impl Identity {
             IdentitySource::Keychain { account } => {
                 Cow::Owned(PathBuf::from(format!("<keychain:{}>", account)))
             }
+            #[cfg(target_os = "windows")]
+            IdentitySource::CredentialManager { target } => {
+                Cow::Owned(PathBuf::from(format!("<credman:{}>", target)))
+            }
+            #[cfg(feature = "secret-service")]
+            IdentitySource::SecretService { account } => {
+                Cow::Owned(PathBuf::from(format!("<secret-service:{}>", account)))
+            }
             IdentitySource::Environment { name } => {
                 Cow::Owned(PathBuf::from(format!("<env:{}>", name)))
             }
@@ -136,7 +236,7 @@ impl Identity {
     ///
     /// Checks `DUGOUT_HOME` first (returns it directly as the `.dugout` dir),
     /// then `HOME`, then falls back to `dirs::home_dir()`.
-    fn resolve_home() -> Result<PathBuf> {
+    pub(crate) fn resolve_home() -> Result<PathBuf> {
         if let Ok(dugout_home) = std::env::var("DUGOUT_HOME") {
             return Ok(PathBuf::from(dugout_home));
         }
@@ -194,24 +294,33 @@ impl Identity {
         }
 
         let contents = fs::read_to_string(&key_path).map_err(StoreError::ReadFailed)?;
-
-        let inner: x25519::Identity = contents
-            .trim()
-            .parse()
-            .map_err(|e: &str| StoreError::InvalidFormat(e.to_string()))?;
+        let inner = Self::parse_key_file(&key_path, &contents)?;
 
         debug!("global identity loaded");
 
         Ok(Self {
-            inner,
+            key: Key::X25519(inner),
             source: IdentitySource::Filesystem(key_path),
         })
     }
 
     /// Generate and save a global identity
     pub fn generate_global() -> Result<Self> {
+        Self::write_global(None)
+    }
+
+    /// Generate and save a global identity encrypted with a passphrase
+    ///
+    /// The key file holds the secret key as an age file encrypted to an
+    /// scrypt recipient. Loading it asks for the passphrase (see
+    /// [`Identity::load_global`]); the public key file stays plaintext.
+    pub fn generate_global_protected(passphrase: SecretString) -> Result<Self> {
+        Self::write_global(Some(passphrase))
+    }
+
+    fn write_global(passphrase: Option<SecretString>) -> Result<Self> {
         let global_dir = Self::global_dir()?;
-        debug!(path = %global_dir.display(), "generating global identity");
+        debug!(path = %global_dir.display(), protected = passphrase.is_some(), "generating global identity");
 
         let inner = x25519::Identity::generate();
 
@@ -223,8 +332,11 @@ impl Identity {
         // Write private key
         use age::secrecy::ExposeSecret;
         let secret_str = inner.to_string();
-        fs::write(&key_path, format!("{}\n", secret_str.expose_secret()))
-            .map_err(StoreError::WriteFailed)?;
+        let contents = match passphrase {
+            Some(passphrase) => encrypt_with_passphrase(secret_str.expose_secret(), passphrase)?,
+            None => format!("{}\n", secret_str.expose_secret()),
+        };
+        fs::write(&key_path, contents).map_err(StoreError::WriteFailed)?;
 
         // Write public key
         let pubkey = inner.to_public().to_string();
@@ -242,7 +354,7 @@ impl Identity {
         debug!(path = %key_path.display(), "global identity saved");
 
         Ok(Self {
-            inner,
+            key: Key::X25519(inner),
             source: IdentitySource::Filesystem(key_path),
         })
     }
@@ -255,49 +367,115 @@ impl Identity {
     ///
     /// Returns `None` if neither is set or the key is invalid.
     pub fn from_env() -> Option<Self> {
+        Self::try_from_env().ok().flatten()
+    }
+
+    /// Load identity from environment variables, reporting unusable ones.
+    ///
+    /// Same lookup as [`Identity::from_env`], but a variable that is set
+    /// without yielding a key is an error rather than `None`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ConfigError::InvalidValue` naming the variable, or
+    /// `ValidationError::InvalidPermissions` for a key file that isn't 0600.
+    pub fn try_from_env() -> Result<Option<Self>> {
+        let mut failure = None;
+
         // Inline key
         if let Ok(key) = std::env::var("DUGOUT_IDENTITY") {
             debug!("found DUGOUT_IDENTITY env var");
             if let Ok(inner) = key.trim().parse::<x25519::Identity>() {
-                return Some(Self {
-                    inner,
+                return Ok(Some(Self {
+                    key: Key::X25519(inner),
                     source: IdentitySource::Environment {
                         name: "DUGOUT_IDENTITY".to_string(),
                     },
-                });
+                }));
             }
             debug!("DUGOUT_IDENTITY value is not a valid age key");
+            failure = Some(invalid_env("DUGOUT_IDENTITY", "not a valid age secret key"));
         }
 
         // Key file path
         if let Ok(path_str) = std::env::var("DUGOUT_IDENTITY_FILE") {
             debug!(path = %path_str, "found DUGOUT_IDENTITY_FILE env var");
-            let path = PathBuf::from(&path_str);
-            if !path.exists() {
-                debug!("DUGOUT_IDENTITY_FILE path does not exist");
-                return None;
+            match Self::from_env_file(Path::new(&path_str)) {
+                Ok(identity) => return Ok(Some(identity)),
+                Err(e) => failure = Some(e),
             }
+        }
 
-            #[cfg(unix)]
-            if Self::validate_file_permissions(&path, 0o600).is_err() {
-                debug!("DUGOUT_IDENTITY_FILE has insecure permissions");
-                return None;
-            }
+        failure.map_or(Ok(None), Err)
+    }
 
-            if let Ok(contents) = fs::read_to_string(&path) {
-                if let Ok(inner) = contents.trim().parse::<x25519::Identity>() {
-                    return Some(Self {
-                        inner,
-                        source: IdentitySource::Environment {
-                            name: "DUGOUT_IDENTITY_FILE".to_string(),
-                        },
-                    });
-                }
+    /// Load the key file named by `DUGOUT_IDENTITY_FILE`.
+    fn from_env_file(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            debug!("DUGOUT_IDENTITY_FILE path does not exist");
+            return Err(invalid_env(
+                "DUGOUT_IDENTITY_FILE",
+                &format!("{} does not exist", path.display()),
+            ));
+        }
+
+        #[cfg(unix)]
+        if let Err(e) = Self::validate_file_permissions(path, 0o600) {
+            debug!("DUGOUT_IDENTITY_FILE has insecure permissions");
+            return Err(e);
+        }
+
+        if let Ok(contents) = fs::read_to_string(path) {
+            if let Ok(inner) = contents.trim().parse::<x25519::Identity>() {
+                return Ok(Self {
+                    key: Key::X25519(inner),
+                    source: IdentitySource::Environment {
+                        name: "DUGOUT_IDENTITY_FILE".to_string(),
+                    },
+                });
             }
-            debug!("DUGOUT_IDENTITY_FILE contents are not a valid age key");
         }
+        debug!("DUGOUT_IDENTITY_FILE contents are not a valid age key");
+        Err(invalid_env(
+            "DUGOUT_IDENTITY_FILE",
+            &format!("{} does not hold a valid age secret key", path.display()),
+        ))
+    }
 
-        None
+    /// Parse a key file, unlocking it first if it is passphrase-protected.
+    ///
+    /// The passphrase comes from `DUGOUT_PASSPHRASE`, or a hidden prompt when
+    /// stdin is a terminal.
+    fn parse_key_file(path: &Path, contents: &str) -> Result<x25519::Identity> {
+        let contents = contents.trim();
+        if !contents.starts_with(ARMOR_BEGIN) {
+            return contents
+                .parse()
+                .map_err(|e: &str| StoreError::InvalidFormat(e.to_string()).into());
+        }
+
+        let mut unlocked = UNLOCKED
+            .get_or_init(Default::default)
+            .lock()
+            .unwrap_or_else(|e| e.into_inner());
+        if let Some(inner) = unlocked.get(contents) {
+            return Ok(inner.clone());
+        }
+
+        let passphrase = match std::env::var(constants::PASSPHRASE_ENV) {
+            Ok(value) => SecretString::from(value),
+            Err(_) if std::io::stdin().is_terminal() => SecretString::from(
+                dialoguer::Password::new()
+                    .with_prompt(format!("Passphrase for {}", path.display()))
+                    .interact()?,
+            ),
+            Err(_) => return Err(StoreError::PassphraseRequired(path.display().to_string()).into()),
+        };
+
+        debug!(path = %path.display(), "unlocking passphrase-protected identity");
+        let inner = decrypt_with_passphrase(contents, passphrase)?;
+        unlocked.insert(contents.to_string(), inner.clone());
+        Ok(inner)
     }
 
     /// Load the global public key without loading the full identity
@@ -333,6 +511,58 @@ impl Identity {
     }
 }
 
+/// Error for an identity variable that is set but unusable.
+fn invalid_env(field: &'static str, reason: &str) -> crate::error::Error {
+    ConfigError::InvalidValue {
+        field,
+        reason: reason.to_string(),
+    }
+    .into()
+}
+
+/// Encrypt a secret key to an age scrypt recipient, ASCII-armored.
+fn encrypt_with_passphrase(secret: &str, passphrase: SecretString) -> Result<String> {
+    let encryptor = age::Encryptor::with_user_passphrase(passphrase);
+
+    let mut encrypted = Vec::new();
+    let mut writer = encryptor
+        .wrap_output(age::armor::ArmoredWriter::wrap_output(
+            &mut encrypted,
+            age::armor::Format::AsciiArmor,
+        )?)
+        .map_err(|e| StoreError::GenerationFailed(format!("{}", e)))?;
+    writer.write_all(secret.as_bytes())?;
+    writer
+        .finish()
+        .and_then(|armored| armored.finish())
+        .map_err(|e| StoreError::GenerationFailed(format!("{}", e)))?;
+
+    String::from_utf8(encrypted)
+        .map_err(|e| StoreError::GenerationFailed(format!("UTF-8 error: {}", e)).into())
+}
+
+/// Decrypt an armored, passphrase-protected key file into an identity.
+fn decrypt_with_passphrase(armored: &str, passphrase: SecretString) -> Result<x25519::Identity> {
+    let reader = age::armor::ArmoredReader::new(armored.as_bytes());
+    let decryptor =
+        age::Decryptor::new(reader).map_err(|e| StoreError::PassphraseFailed(format!("{}", e)))?;
+
+    let identity = age::scrypt::Identity::new(passphrase);
+    let mut reader = decryptor
+        .decrypt(std::iter::once(&identity as &dyn age::Identity))
+        .map_err(|e| StoreError::PassphraseFailed(format!("{}", e)))?;
+
+    let mut secret = zeroize::Zeroizing::new(String::new());
+    reader
+        .read_to_string(&mut secret)
+        .map_err(|e| StoreError::PassphraseFailed(format!("{}", e)))?;
+
+    secret
+        .trim()
+        .parse()
+        .map_err(|e: &str| StoreError::InvalidFormat(e.to_string()).into())
+}
+
 impl std::fmt::Debug for Identity {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("Identity")
@@ -376,6 +606,31 @@ mod tests {
         assert!(pubkey.starts_with("age1"));
     }
 
+    #[test]
+    fn test_passphrase_roundtrip() {
+        use age::secrecy::ExposeSecret;
+
+        let inner = x25519::Identity::generate();
+        let secret = inner.to_string();
+        let armored = encrypt_with_passphrase(
+            secret.expose_secret(),
+            SecretString::from("hunter2".to_string()),
+        )
+        .unwrap();
+        assert!(armored.starts_with(ARMOR_BEGIN));
+        assert!(!armored.contains("AGE-SECRET-KEY"));
+
+        let unlocked =
+            decrypt_with_passphrase(&armored, SecretString::from("hunter2".to_string())).unwrap();
+        assert_eq!(
+            unlocked.to_public().to_string(),
+            inner.to_public().to_string()
+        );
+
+        let err = decrypt_with_passphrase(&armored, SecretString::from("wrong".to_string()));
+        assert!(err.is_err());
+    }
+
     #[test]
     fn test_path() {
         let tmp = TempDir::new().unwrap();
@@ -0,0 +1,314 @@
+//! Import options and cloud secret manager formats.
+
+use base64::Engine;
+use serde_json::Value;
+
+use crate::core::types::SecretKey;
+use crate::error::{Error, Result, ValidationError};
+
+/// Controls which `.env` entries `Vault::import_with` takes and how they are named.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ImportOptions {
+    /// Shape of the imported file
+    pub format: ImportFormat,
+    /// Only import keys starting with this prefix
+    pub prefix: Option<String>,
+    /// Remove the prefix from imported key names
+    pub strip_prefix: bool,
+    /// Expand `${VAR}` / `$VAR` references to earlier entries
+    pub expand: bool,
+    /// Expand undefined references to an empty string instead of failing
+    pub allow_undefined: bool,
+    /// Fail on keys that appear more than once, instead of keeping the last
+    pub strict: bool,
+    /// What to do with keys the vault already has
+    pub on_conflict: OnConflict,
+}
+
+/// What an import does with a key the vault already has.
+///
+/// `Error` (the default) matches `set` without `--force`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OnConflict {
+    /// Fail the import before writing anything
+    #[default]
+    Error,
+    /// Keep the stored value
+    Skip,
+    /// Replace the stored value
+    Overwrite,
+}
+
+/// What `Vault::import_with` did with each imported key.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ImportResult {
+    /// Keys the vault didn't have
+    pub created: Vec<SecretKey>,
+    /// Existing keys replaced (`OnConflict::Overwrite`)
+    pub overwritten: Vec<SecretKey>,
+    /// Existing keys left alone (`OnConflict::Skip`)
+    pub skipped: Vec<SecretKey>,
+}
+
+impl ImportResult {
+    /// Number of secrets written
+    pub fn written(&self) -> usize {
+        self.created.len() + self.overwritten.len()
+    }
+}
+
+impl ImportOptions {
+    /// Map a `.env` key to the vault key it should be stored under.
+    ///
+    /// Returns `None` when the key is filtered out by the prefix.
+    pub fn map_key<'a>(&self, key: &'a str) -> Option<&'a str> {
+        match self.prefix.as_deref() {
+            None => Some(key),
+            Some(prefix) => {
+                let rest = key.strip_prefix(prefix)?;
+                Some(if self.strip_prefix { rest } else { key })
+            }
+        }
+    }
+}
+
+/// Shape of the file passed to `Vault::import_with`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ImportFormat {
+    /// A `.env` file
+    #[default]
+    Dotenv,
+    /// `aws secretsmanager get-secret-value` (or `batch-get-secret-value`) JSON
+    AwsSecretsManager,
+    /// `gcloud secrets versions access --format=json` JSON
+    GcpSecretManager,
+    /// A dotenv-vault `.env.vault` file, decrypted with `DOTENV_KEY`
+    EnvVault,
+}
+
+impl ImportFormat {
+    /// Name and producing command, for error messages.
+    fn describe(self) -> (&'static str, &'static str) {
+        match self {
+            Self::Dotenv => ("dotenv", "dugout secrets export"),
+            Self::AwsSecretsManager => (
+                "aws-secretsmanager",
+                "aws secretsmanager get-secret-value --secret-id <id>",
+            ),
+            Self::GcpSecretManager => (
+                "gcp-secret-manager",
+                "gcloud secrets versions access latest --secret <id> --format json",
+            ),
+            Self::EnvVault => ("env-vault", "dugout secrets export --format env-vault"),
+        }
+    }
+
+    /// Extract key/value pairs from a cloud secret manager's JSON output.
+    ///
+    /// A secret whose payload is a JSON object contributes one pair per
+    /// field (the common "key/value" secret). Any other payload becomes a
+    /// single pair, keyed by the secret's name upper-cased with separators
+    /// turned into `_` (`prod/db-password` becomes `DB_PASSWORD`). A JSON
+    /// array of secrets (or AWS's `SecretValues` batch output) is flattened.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ValidationError::UnrecognizedImport` if the JSON doesn't have
+    /// the expected shape.
+    pub fn parse_json(self, content: &str) -> Result<Vec<(String, String)>> {
+        let json: Value = serde_json::from_str(content).map_err(|e| self.error(e.to_string()))?;
+        let entries = match (&json, self) {
+            (Value::Array(entries), _) => entries.iter().collect(),
+            (Value::Object(map), Self::AwsSecretsManager) => match map.get("SecretValues") {
+                Some(Value::Array(entries)) => entries.iter().collect(),
+                _ => vec![&json],
+            },
+            _ => vec![&json],
+        };
+
+        let mut pairs = Vec::new();
+        for entry in entries {
+            let (name, payload) = match self {
+                Self::Dotenv | Self::EnvVault => {
+                    return Err(self.error("not a JSON format".to_string()))
+                }
+                Self::AwsSecretsManager => self.aws_secret(entry)?,
+                Self::GcpSecretManager => self.gcp_secret(entry)?,
+            };
+            pairs.extend(self.payload_pairs(name, payload)?);
+        }
+        Ok(pairs)
+    }
+
+    /// Name and payload of one `get-secret-value` result.
+    fn aws_secret(self, entry: &Value) -> Result<(Option<String>, String)> {
+        let name = entry
+            .get("Name")
+            .and_then(Value::as_str)
+            .map(str::to_string);
+        if let Some(text) = entry.get("SecretString").and_then(Value::as_str) {
+            return Ok((name, text.to_string()));
+        }
+        match entry.get("SecretBinary").and_then(Value::as_str) {
+            Some(encoded) => Ok((name, self.decode(encoded)?)),
+            None => Err(self.error("expected a SecretString or SecretBinary field".to_string())),
+        }
+    }
+
+    /// Name and payload of one secret version.
+    fn gcp_secret(self, entry: &Value) -> Result<(Option<String>, String)> {
+        // projects/<project>/secrets/<id>/versions/<version>
+        let name = entry.get("name").and_then(Value::as_str).and_then(|name| {
+            let mut parts = name.split('/');
+            parts.find(|part| *part == "secrets")?;
+            parts.next().map(str::to_string)
+        });
+        match entry.pointer("/payload/data").and_then(Value::as_str) {
+            Some(encoded) => Ok((name, self.decode(encoded)?)),
+            None => Err(self.error("expected a payload.data field".to_string())),
+        }
+    }
+
+    /// Split a decoded payload into pairs.
+    pub(crate) fn payload_pairs(
+        self,
+        name: Option<String>,
+        payload: String,
+    ) -> Result<Vec<(String, String)>> {
+        let Ok(Value::Object(fields)) = serde_json::from_str::<Value>(&payload) else {
+            let name =
+                name.ok_or_else(|| self.error("secret has no name to use as a key".to_string()))?;
+            return Ok(vec![(key_from_name(&name), payload)]);
+        };
+        fields
+            .into_iter()
+            .map(|(key, value)| match value {
+                Value::String(text) => Ok((key, text)),
+                Value::Number(_) | Value::Bool(_) => Ok((key, value.to_string())),
+                _ => Err(self.error(format!("value for '{}' is not a string", key))),
+            })
+            .collect()
+    }
+
+    fn decode(self, encoded: &str) -> Result<String> {
+        let engine = base64::engine::general_purpose::STANDARD;
+        let bytes = engine
+            .decode(encoded)
+            .or_else(|_| base64::engine::general_purpose::URL_SAFE.decode(encoded))
+            .map_err(|e| self.error(format!("payload is not base64: {}", e)))?;
+        String::from_utf8(bytes).map_err(|_| self.error("payload is not UTF-8 text".to_string()))
+    }
+
+    fn error(self, reason: String) -> Error {
+        let (format, command) = self.describe();
+        ValidationError::UnrecognizedImport {
+            format,
+            command,
+            reason,
+        }
+        .into()
+    }
+}
+
+/// Turn a cloud secret name into a key: last path segment, upper-cased,
+/// with anything but letters and digits replaced by `_`.
+fn key_from_name(name: &str) -> String {
+    name.rsplit('/')
+        .next()
+        .unwrap_or(name)
+        .chars()
+        .map(|ch| {
+            if ch.is_ascii_alphanumeric() {
+                ch.to_ascii_uppercase()
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_map_key_without_prefix() {
+        let opts = ImportOptions::default();
+        assert_eq!(opts.map_key("ANY_KEY"), Some("ANY_KEY"));
+    }
+
+    #[test]
+    fn test_map_key_filters_and_strips() {
+        let mut opts = ImportOptions {
+            prefix: Some("SVC_".to_string()),
+            strip_prefix: false,
+            ..Default::default()
+        };
+        assert_eq!(opts.map_key("SVC_TOKEN"), Some("SVC_TOKEN"));
+        assert_eq!(opts.map_key("OTHER_TOKEN"), None);
+
+        opts.strip_prefix = true;
+        assert_eq!(opts.map_key("SVC_TOKEN"), Some("TOKEN"));
+        assert_eq!(opts.map_key("SVC_"), Some(""));
+    }
+
+    #[test]
+    fn test_parse_aws_secret_string_object() {
+        let json = r#"{
+            "ARN": "arn:aws:secretsmanager:us-east-1:123:secret:prod/app-AbCd",
+            "Name": "prod/app",
+            "SecretString": "{\"DB_HOST\":\"db.internal\",\"DB_PORT\":5432}",
+            "VersionStages": ["AWSCURRENT"]
+        }"#;
+        let pairs = ImportFormat::AwsSecretsManager.parse_json(json).unwrap();
+        assert_eq!(
+            pairs,
+            vec![
+                ("DB_HOST".to_string(), "db.internal".to_string()),
+                ("DB_PORT".to_string(), "5432".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_aws_plain_and_binary_secrets() {
+        let json = r#"{"SecretValues": [
+            {"Name": "prod/stripe-key", "SecretString": "sk_live_123"},
+            {"Name": "prod/cert", "SecretBinary": "c2VjcmV0"}
+        ]}"#;
+        let pairs = ImportFormat::AwsSecretsManager.parse_json(json).unwrap();
+        assert_eq!(
+            pairs,
+            vec![
+                ("STRIPE_KEY".to_string(), "sk_live_123".to_string()),
+                ("CERT".to_string(), "secret".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_gcp_payload() {
+        let json = r#"{
+            "name": "projects/123/secrets/db-password/versions/3",
+            "payload": {"data": "aHVudGVyMg==", "dataCrc32c": "1234"}
+        }"#;
+        let pairs = ImportFormat::GcpSecretManager.parse_json(json).unwrap();
+        assert_eq!(
+            pairs,
+            vec![("DB_PASSWORD".to_string(), "hunter2".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_parse_unknown_shape_errors() {
+        let err = ImportFormat::GcpSecretManager
+            .parse_json(r#"{"SecretString": "x"}"#)
+            .unwrap_err();
+        assert!(err.to_string().contains("payload.data"));
+        assert!(err.to_string().contains("gcloud secrets versions access"));
+
+        assert!(ImportFormat::AwsSecretsManager
+            .parse_json("KEY=value")
+            .is_err());
+    }
+}
@@ -0,0 +1,83 @@
+//! Operation log types.
+//!
+//! Every vault operation appends a `LogEvent` to the local operation log.
+//! Events carry metadata only (key and member names), never secret values.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Schema version written with every event.
+pub const LOG_SCHEMA_VERSION: u32 = 1;
+
+/// A single recorded vault operation.
+///
+/// Serialized as one JSON object per line:
+///
+/// ```json
+/// {"version":1,"timestamp":"2026-01-01T00:00:00+00:00","actor":"alice",
+///  "project":"myapp","vault":"default","action":"set","keys":["API_KEY"]}
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LogEvent {
+    /// Schema version (currently 1)
+    pub version: u32,
+    /// RFC3339 timestamp in UTC
+    pub timestamp: String,
+    /// Local user that performed the operation
+    pub actor: String,
+    /// Project identifier
+    pub project: String,
+    /// Vault name (`default` for `.dugout.toml`)
+    pub vault: String,
+    /// Operation, e.g. `set`, `rm`, `read`, `team.add`
+    pub action: String,
+    /// Secret keys or member names the operation touched
+    pub keys: Vec<String>,
+}
+
+impl LogEvent {
+    /// Create an event stamped with the current time and user.
+    pub fn new(project: &str, vault: Option<&str>, action: &str, keys: Vec<String>) -> Self {
+        Self {
+            version: LOG_SCHEMA_VERSION,
+            timestamp: Utc::now().to_rfc3339(),
+            actor: whoami::username(),
+            project: project.to_string(),
+            vault: vault.unwrap_or("default").to_string(),
+            action: action.to_string(),
+            keys,
+        }
+    }
+
+    /// Parsed timestamp, or `None` if malformed.
+    pub fn time(&self) -> Option<DateTime<Utc>> {
+        DateTime::parse_from_rfc3339(&self.timestamp)
+            .ok()
+            .map(|ts| ts.with_timezone(&Utc))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_log_event_schema_fields() {
+        let event = LogEvent::new("proj", None, "set", vec!["API_KEY".to_string()]);
+        let json: serde_json::Value = serde_json::to_value(&event).unwrap();
+
+        for field in [
+            "version",
+            "timestamp",
+            "actor",
+            "project",
+            "vault",
+            "action",
+            "keys",
+        ] {
+            assert!(json.get(field).is_some(), "missing field {}", field);
+        }
+        assert_eq!(json["vault"], "default");
+        assert!(event.time().is_some());
+    }
+}
@@ -0,0 +1,31 @@
+//! Merge conflict resolution types.
+
+/// One side of a git merge conflict.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Side {
+    /// The current branch (`<<<<<<<` hunk)
+    Ours,
+    /// The branch being merged in (`>>>>>>>` hunk)
+    Theirs,
+}
+
+/// Result of resolving a conflicted vault file.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Resolution {
+    /// Keys added on their side, taken as-is
+    pub added: Vec<String>,
+    /// Keys removed on one side, dropped from the merge
+    pub removed: Vec<String>,
+    /// Keys on one side only, kept because there was no diff3 base to show
+    /// whether the other side removed them
+    pub unverified: Vec<String>,
+    /// Keys changed on both sides to the same plaintext
+    pub identical: Vec<String>,
+    /// Keys changed on both sides to different values, or changed on one
+    /// side and removed on the other, with the side kept
+    pub chosen: Vec<(String, Side)>,
+    /// Recipients removed on one side, dropped from the merge
+    pub removed_recipients: Vec<String>,
+    /// Whether secrets were re-encrypted for a merged recipient set
+    pub reencrypted: bool,
+}
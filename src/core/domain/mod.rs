@@ -4,16 +4,24 @@ pub mod audit;
 mod diff;
 mod env;
 pub mod identity;
+mod import;
+mod log;
+mod merge;
 mod recipient;
+mod request;
 mod secret;
 mod sync;
 mod vault_info;
 
 pub use audit::{Finding, Severity};
 pub use diff::{Diff, DiffEntry, EntryStatus};
-pub use env::Env;
+pub use env::{Env, EnvLine, LineEnding, Quoting};
 pub use identity::{Identity, IdentitySource};
-pub use recipient::Recipient;
-pub use secret::Secret;
-pub use sync::SyncResult;
+pub use import::{ImportFormat, ImportOptions, ImportResult, OnConflict};
+pub use log::{LogEvent, LOG_SCHEMA_VERSION};
+pub use merge::{Resolution, Side};
+pub use recipient::{key_fingerprint, Recipient, Role};
+pub use request::AccessRequest;
+pub use secret::{Secret, SetOutcome, StoredFormat};
+pub use sync::{SyncPlan, SyncResult, SyncStatus};
 pub use vault_info::VaultInfo;
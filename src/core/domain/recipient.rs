@@ -2,6 +2,9 @@
 //!
 //! Provides a validated type for team members who can decrypt secrets.
 
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
 use crate::core::cipher;
 use crate::core::types::{MemberName, PublicKey};
 use crate::error::Result;
@@ -35,6 +38,51 @@ impl Recipient {
     pub fn public_key(&self) -> &str {
         &self.public_key
     }
+
+    /// Short fingerprint of the public key (see [`key_fingerprint`])
+    pub fn fingerprint(&self) -> String {
+        key_fingerprint(&self.public_key)
+    }
+}
+
+/// Short, stable fingerprint of a public key for checking it out-of-band
+///
+/// The first 8 bytes of the SHA-256 of the trimmed key, as
+/// `SHA256:1a2b3c4d5e6f7a8b`. `knock` shows it to the requester and
+/// `admit` to the admin, so the two can compare it outside git.
+pub fn key_fingerprint(public_key: &str) -> String {
+    let hash = Sha256::digest(public_key.trim().as_bytes());
+    let hex: String = hash[..8].iter().map(|b| format!("{:02x}", b)).collect();
+    format!("SHA256:{}", hex)
+}
+
+/// What a team member may do beyond reading secrets
+///
+/// Roles are metadata only; every recipient can decrypt regardless.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Role {
+    /// Can admit new members
+    Admin,
+    /// Reads secrets
+    #[default]
+    Member,
+}
+
+impl Role {
+    /// The name stored in `[roles]`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Admin => "admin",
+            Self::Member => "member",
+        }
+    }
+}
+
+impl std::fmt::Display for Role {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.pad(self.as_str())
+    }
 }
 
 #[cfg(test)]
@@ -54,6 +102,18 @@ mod tests {
         assert_eq!(recipient.public_key(), &pubkey);
     }
 
+    #[test]
+    fn test_key_fingerprint_is_stable() {
+        let key = age::x25519::Identity::generate().to_public().to_string();
+        let fingerprint = key_fingerprint(&key);
+
+        assert!(fingerprint.starts_with("SHA256:"));
+        assert_eq!(fingerprint.len(), "SHA256:".len() + 16);
+        assert_eq!(key_fingerprint(&format!("{}\n", key)), fingerprint);
+        let other = age::x25519::Identity::generate().to_public().to_string();
+        assert_ne!(key_fingerprint(&other), fingerprint);
+    }
+
     #[test]
     fn test_recipient_new_invalid_key() {
         let result = Recipient::new("bob".to_string(), "not-a-valid-key".to_string());
@@ -0,0 +1,194 @@
+//! Access request representation.
+//!
+//! `dugout knock` writes `.dugout/requests/<vault>/<name>.pub`: the
+//! requester's public key, and for age keys a `signature:` line proving
+//! they hold the matching private key. The signature covers the vault name,
+//! so a request can't be copied into another vault's directory. Files from
+//! older versions have only the key and parse as unsigned.
+
+use age::x25519;
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+
+use crate::core::cipher::sign;
+use crate::core::types::{MemberName, PublicKey};
+use crate::error::{Result, ValidationError};
+
+const SIGNATURE_PREFIX: &str = "signature:";
+
+/// A pending request to join a vault's team
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccessRequest {
+    vault: String,
+    name: MemberName,
+    public_key: PublicKey,
+    signature: Option<Vec<u8>>,
+}
+
+impl AccessRequest {
+    /// Create a request to join `vault`, signed by `identity`
+    ///
+    /// # Errors
+    ///
+    /// Returns `CipherError` if the identity can't sign.
+    pub fn signed(vault: Option<&str>, name: &str, identity: &x25519::Identity) -> Result<Self> {
+        let vault = vault.unwrap_or("default");
+        let public_key = identity.to_public().to_string();
+        let signature = sign::sign(identity, &message(vault, name, &public_key))?;
+        Ok(Self {
+            vault: vault.to_string(),
+            name: name.to_string(),
+            public_key,
+            signature: Some(signature.to_vec()),
+        })
+    }
+
+    /// Create a request without a signature (SSH keys can't sign one)
+    pub fn unsigned(vault: Option<&str>, name: &str, public_key: &str) -> Self {
+        Self {
+            vault: vault.unwrap_or("default").to_string(),
+            name: name.to_string(),
+            public_key: public_key.trim().to_string(),
+            signature: None,
+        }
+    }
+
+    /// Parse the contents of `name`'s request file in `vault`'s directory
+    ///
+    /// # Errors
+    ///
+    /// Returns `ValidationError::InvalidRequest` if the file is empty, has
+    /// unexpected lines, or the signature isn't valid base64.
+    pub fn parse(vault: Option<&str>, name: &str, contents: &str) -> Result<Self> {
+        let invalid = |reason: &str| ValidationError::InvalidRequest {
+            name: name.to_string(),
+            reason: reason.to_string(),
+        };
+
+        let mut lines = contents.lines().map(str::trim).filter(|l| !l.is_empty());
+        let public_key = lines.next().ok_or_else(|| invalid("the file is empty"))?;
+
+        let mut signature = None;
+        for line in lines {
+            let Some(encoded) = line.strip_prefix(SIGNATURE_PREFIX) else {
+                return Err(invalid("unexpected line after the public key").into());
+            };
+            if signature.is_some() {
+                return Err(invalid("more than one signature").into());
+            }
+            let bytes = STANDARD
+                .decode(encoded.trim())
+                .map_err(|_| invalid("the signature is not valid base64"))?;
+            signature = Some(bytes);
+        }
+
+        Ok(Self {
+            vault: vault.unwrap_or("default").to_string(),
+            name: name.to_string(),
+            public_key: public_key.to_string(),
+            signature,
+        })
+    }
+
+    /// Requester's name
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Requester's public key
+    pub fn public_key(&self) -> &str {
+        &self.public_key
+    }
+
+    /// Whether the request carries a signature
+    pub fn is_signed(&self) -> bool {
+        self.signature.is_some()
+    }
+
+    /// Check the request's signature
+    ///
+    /// Unsigned requests pass unless `require_signature`; a signature that
+    /// doesn't match the embedded key, name and vault never does.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ValidationError::InvalidRequest` if the signature is bad, or
+    /// missing when required.
+    pub fn verify(&self, require_signature: bool) -> Result<()> {
+        let reason = match &self.signature {
+            None if require_signature => "it is not signed",
+            None => return Ok(()),
+            Some(signature)
+                if sign::verify(
+                    &self.public_key,
+                    &message(&self.vault, &self.name, &self.public_key),
+                    signature,
+                ) =>
+            {
+                return Ok(())
+            }
+            Some(_) => "the signature does not match its key",
+        };
+        Err(ValidationError::InvalidRequest {
+            name: self.name.clone(),
+            reason: reason.to_string(),
+        }
+        .into())
+    }
+
+    /// Render the request file contents
+    pub fn to_file(&self) -> String {
+        match &self.signature {
+            Some(signature) => format!(
+                "{}\n{} {}\n",
+                self.public_key,
+                SIGNATURE_PREFIX,
+                STANDARD.encode(signature)
+            ),
+            None => format!("{}\n", self.public_key),
+        }
+    }
+}
+
+/// Signed bytes: binds the key to the vault and the requester's name, so a
+/// request can't be renamed or moved without invalidating it.
+fn message(vault: &str, name: &str, public_key: &str) -> Vec<u8> {
+    format!("dugout-knock-v2\n{}\n{}\n{}", vault, name, public_key).into_bytes()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_signed_request_round_trips() {
+        let identity = x25519::Identity::generate();
+        let request = AccessRequest::signed(None, "alice", &identity).unwrap();
+
+        let parsed = AccessRequest::parse(Some("default"), "alice", &request.to_file()).unwrap();
+        assert_eq!(parsed, request);
+        assert!(parsed.is_signed());
+        assert!(parsed.verify(true).is_ok());
+
+        // Renaming the file breaks the signature
+        let renamed = AccessRequest::parse(None, "mallory", &request.to_file()).unwrap();
+        assert!(renamed.verify(false).is_err());
+
+        // So does copying it into another vault's requests
+        let moved = AccessRequest::parse(Some("prod"), "alice", &request.to_file()).unwrap();
+        assert!(moved.verify(false).is_err());
+    }
+
+    #[test]
+    fn test_unsigned_and_malformed_requests() {
+        let key = x25519::Identity::generate().to_public().to_string();
+        let unsigned = AccessRequest::parse(None, "bob", &format!("{}\n", key)).unwrap();
+        assert!(!unsigned.is_signed());
+        assert!(unsigned.verify(false).is_ok());
+        assert!(unsigned.verify(true).is_err());
+
+        assert!(AccessRequest::parse(None, "bob", "").is_err());
+        assert!(AccessRequest::parse(None, "bob", &format!("{}\nextra\n", key)).is_err());
+        assert!(AccessRequest::parse(None, "bob", &format!("{}\nsignature: !!\n", key)).is_err());
+    }
+}
@@ -1,20 +1,53 @@
 //! Secret type.
 //!
-//! Represents a single encrypted secret with its key and ciphertext.
+//! Represents a single encrypted secret with its key, ciphertext, and
+//! optional description.
 
+use crate::core::cipher::Envelope;
 use crate::core::types::{EncryptedValue, SecretKey};
 
+/// How a secret's ciphertext is stored, determined without decrypting.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum StoredFormat {
+    /// Raw age ciphertext
+    Age,
+    /// Hybrid envelope, with the KMS provider name if it carries a KMS half
+    HybridEnvelope(Option<String>),
+}
+
+/// What [`Vault::set`](crate::Vault::set) did to the stored secret.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SetOutcome {
+    /// The key didn't exist before
+    Created,
+    /// The key existed with a different value and was re-encrypted
+    Updated,
+    /// The key already held this value; nothing was written
+    Unchanged,
+}
+
 /// An encrypted secret with its key name
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Secret {
     key: SecretKey,
     value: EncryptedValue,
+    description: Option<String>,
 }
 
 impl Secret {
     /// Create a new secret from a key and encrypted value
     pub fn new(key: SecretKey, value: EncryptedValue) -> Self {
-        Self { key, value }
+        Self {
+            key,
+            value,
+            description: None,
+        }
+    }
+
+    /// Attach a human-readable description
+    pub fn with_description(mut self, description: Option<String>) -> Self {
+        self.description = description;
+        self
     }
 
     /// Secret's key name
@@ -26,6 +59,24 @@ impl Secret {
     pub fn encrypted(&self) -> &str {
         &self.value
     }
+
+    /// Optional human-readable description
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    /// Inspect the stored ciphertext format (no decryption)
+    pub fn stored_format(&self) -> StoredFormat {
+        match Envelope::parse(&self.value) {
+            Some(envelope) => StoredFormat::HybridEnvelope(envelope.provider),
+            None => StoredFormat::Age,
+        }
+    }
+
+    /// Whether the ciphertext is a hybrid KMS envelope
+    pub fn is_envelope(&self) -> bool {
+        matches!(self.stored_format(), StoredFormat::HybridEnvelope(_))
+    }
 }
 
 impl std::fmt::Display for Secret {
@@ -44,6 +95,41 @@ mod tests {
 
         assert_eq!(secret.key(), "API_KEY");
         assert_eq!(secret.encrypted(), "age-encryption-v1...");
+        assert_eq!(secret.description(), None);
+    }
+
+    #[test]
+    fn test_secret_with_description() {
+        let secret = Secret::new("API_KEY".to_string(), "encrypted".to_string())
+            .with_description(Some("Stripe live key".to_string()));
+
+        assert_eq!(secret.description(), Some("Stripe live key"));
+    }
+
+    #[test]
+    fn test_secret_stored_format() {
+        use crate::core::cipher::KmsProvider;
+
+        let age = Secret::new(
+            "A".to_string(),
+            "-----BEGIN AGE ENCRYPTED FILE-----".to_string(),
+        );
+        assert_eq!(age.stored_format(), StoredFormat::Age);
+        assert!(!age.is_envelope());
+
+        let sealed = Envelope::new(
+            "age".to_string(),
+            Some("kms".to_string()),
+            Some(&KmsProvider::Gcp),
+        )
+        .seal()
+        .unwrap();
+        let hybrid = Secret::new("B".to_string(), sealed);
+        assert_eq!(
+            hybrid.stored_format(),
+            StoredFormat::HybridEnvelope(Some("gcp".to_string()))
+        );
+        assert!(hybrid.is_envelope());
     }
 
     #[test]
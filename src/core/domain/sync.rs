@@ -1,3 +1,5 @@
+use crate::core::types::{MemberName, SecretKey};
+
 /// Result of a sync operation.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct SyncResult {
@@ -8,3 +10,49 @@ pub struct SyncResult {
     /// Whether re-encryption was actually needed
     pub was_needed: bool,
 }
+
+/// What a sync would change, computed without touching the vault.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SyncPlan {
+    /// Why a sync is or isn't needed
+    pub status: SyncStatus,
+    /// Secrets that would be re-encrypted
+    pub secrets: Vec<SecretKey>,
+    /// Recipients added since the last sync
+    pub added: Vec<MemberName>,
+    /// Recipients removed (or expired) since the last sync
+    pub removed: Vec<MemberName>,
+    /// Recipients whose key changed since the last sync
+    pub rekeyed: Vec<MemberName>,
+    /// Whether the last sync stored a recipient snapshot to compare against
+    pub snapshot: bool,
+}
+
+/// Whether a vault's secrets are encrypted for its current recipients.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SyncStatus {
+    /// The stored recipients hash matches, or there are no secrets
+    #[default]
+    InSync,
+    /// Secrets exist but no recipients hash was ever stored
+    NeverSynced,
+    /// The recipient set changed since the last sync
+    RecipientsChanged,
+}
+
+impl SyncStatus {
+    /// Whether secrets need re-encrypting
+    pub fn needs_sync(self) -> bool {
+        self != Self::InSync
+    }
+}
+
+impl std::fmt::Display for SyncStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.pad(match self {
+            Self::InSync => "in sync",
+            Self::NeverSynced => "no recipients hash stored",
+            Self::RecipientsChanged => "recipients changed since last sync",
+        })
+    }
+}
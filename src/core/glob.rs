@@ -0,0 +1,76 @@
+//! Minimal glob matching for secret key names.
+//!
+//! Supports `*` (any run of characters) and `?` (any single character).
+//! Key names are plain ASCII identifiers, so no escaping or character
+//! classes are needed.
+
+/// Check whether `key` matches a glob `pattern`.
+pub fn matches(pattern: &str, key: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let key: Vec<char> = key.chars().collect();
+
+    let (mut p, mut k) = (0, 0);
+    let mut star: Option<usize> = None;
+    let mut mark = 0;
+
+    while k < key.len() {
+        if p < pattern.len() && (pattern[p] == '?' || pattern[p] == key[k]) {
+            p += 1;
+            k += 1;
+        } else if p < pattern.len() && pattern[p] == '*' {
+            star = Some(p);
+            mark = k;
+            p += 1;
+        } else if let Some(s) = star {
+            p = s + 1;
+            mark += 1;
+            k = mark;
+        } else {
+            return false;
+        }
+    }
+
+    while p < pattern.len() && pattern[p] == '*' {
+        p += 1;
+    }
+
+    p == pattern.len()
+}
+
+/// Check whether `key` matches any of the given patterns.
+pub fn matches_any(patterns: &[String], key: &str) -> bool {
+    patterns.iter().any(|p| matches(p, key))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exact_match() {
+        assert!(matches("API_KEY", "API_KEY"));
+        assert!(!matches("API_KEY", "API_KEYS"));
+    }
+
+    #[test]
+    fn test_star_match() {
+        assert!(matches("AWS_*", "AWS_SECRET"));
+        assert!(matches("*_URL", "DATABASE_URL"));
+        assert!(matches("*", "ANYTHING"));
+        assert!(!matches("AWS_*", "GCP_KEY"));
+    }
+
+    #[test]
+    fn test_question_match() {
+        assert!(matches("KEY_?", "KEY_1"));
+        assert!(!matches("KEY_?", "KEY_12"));
+    }
+
+    #[test]
+    fn test_matches_any() {
+        let patterns = vec!["BUILD_*".to_string(), "VERSION".to_string()];
+        assert!(matches_any(&patterns, "BUILD_TIME"));
+        assert!(matches_any(&patterns, "VERSION"));
+        assert!(!matches_any(&patterns, "API_KEY"));
+    }
+}
@@ -0,0 +1,176 @@
+//! Config-defined hooks run after secrets change.
+//!
+//! Hooks are shell commands from the `[hooks]` section of the vault config.
+//! They receive the changed key names (never values) in the environment and
+//! run after the config has been saved. Running is best-effort: a hook that
+//! fails to start or exits non-zero is reported with a warning, and the
+//! write it follows stands.
+//!
+//! Because the config is committed, a hook only runs once the user has
+//! allowed it on their machine (`dugout hooks allow`). The allow-list lives
+//! in `~/.dugout/hooks.allow`, outside the repository, and records each
+//! command together with the project directory, so editing a hook or
+//! cloning a repository needs a fresh approval.
+
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::PathBuf;
+use std::process::Command;
+
+use sha2::{Digest, Sha256};
+use tracing::{debug, warn};
+
+use crate::core::constants;
+use crate::core::domain::Identity;
+use crate::error::Result;
+
+/// Environment variable naming the event that triggered the hook.
+pub const EVENT_ENV: &str = "DUGOUT_EVENT";
+
+/// Environment variable holding the changed key names, space-separated.
+pub const KEYS_ENV: &str = "DUGOUT_KEYS";
+
+/// Environment variable holding the vault name (unset for the default vault).
+pub const VAULT_ENV: &str = "DUGOUT_VAULT";
+
+/// Variables carrying key material, removed from every hook's environment.
+const SECRET_ENV: &[&str] = &[
+    "DUGOUT_IDENTITY",
+    "DUGOUT_IDENTITY_FILE",
+    constants::PASSPHRASE_ENV,
+    constants::DOTENV_KEY_ENV,
+];
+
+/// Run a hook command, warning (not returning) on failure.
+///
+/// Commands that haven't been allowed on this machine are skipped.
+pub fn run(command: &str, event: &str, vault: Option<&str>, keys: &[&str]) {
+    if !is_allowed(command) {
+        warn!(
+            event,
+            command,
+            "{} hook not run: allow it with `dugout hooks allow` after reviewing it",
+            event
+        );
+        return;
+    }
+    debug!(event, command, "running hook");
+
+    let mut cmd = shell(command);
+    cmd.env(EVENT_ENV, event).env(KEYS_ENV, keys.join(" "));
+    for name in SECRET_ENV {
+        cmd.env_remove(name);
+    }
+    match vault {
+        Some(name) => cmd.env(VAULT_ENV, name),
+        None => cmd.env_remove(VAULT_ENV),
+    };
+
+    match cmd.status() {
+        Ok(status) if status.success() => {}
+        Ok(status) => warn!(event, %status, "{} hook failed", event),
+        Err(e) => warn!(event, error = %e, "{} hook could not be run", event),
+    }
+}
+
+/// Whether `command` has been allowed for the current project.
+pub fn is_allowed(command: &str) -> bool {
+    match (allowed(), fingerprint(command)) {
+        (Ok(entries), Ok(entry)) => entries.contains(&entry),
+        _ => false,
+    }
+}
+
+/// Allow `command` to run for the current project.
+///
+/// Returns `false` if it was already allowed.
+///
+/// # Errors
+///
+/// Returns an error if the allow-list can't be written.
+pub fn allow(command: &str) -> Result<bool> {
+    let entry = fingerprint(command)?;
+    if allowed()?.contains(&entry) {
+        return Ok(false);
+    }
+
+    let path = allow_path()?;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let mut options = OpenOptions::new();
+    options.create(true).append(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    writeln!(options.open(&path)?, "{}", entry)?;
+    Ok(true)
+}
+
+/// Stop allowing `command` for the current project.
+///
+/// Returns `false` if it wasn't allowed.
+///
+/// # Errors
+///
+/// Returns an error if the allow-list can't be rewritten.
+pub fn deny(command: &str) -> Result<bool> {
+    let entry = fingerprint(command)?;
+    let entries = allowed()?;
+    if !entries.contains(&entry) {
+        return Ok(false);
+    }
+
+    let kept: String = entries
+        .iter()
+        .filter(|e| **e != entry)
+        .map(|e| format!("{}\n", e))
+        .collect();
+    fs::write(allow_path()?, kept)?;
+    Ok(true)
+}
+
+/// Path of the per-user hook allow-list.
+fn allow_path() -> Result<PathBuf> {
+    Ok(Identity::resolve_home()?.join(constants::HOOKS_ALLOW_FILE))
+}
+
+/// Entries in the allow-list.
+fn allowed() -> Result<Vec<String>> {
+    let path = allow_path()?;
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    Ok(fs::read_to_string(path)?
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(String::from)
+        .collect())
+}
+
+/// Allow-list entry for `command` in the current project directory.
+fn fingerprint(command: &str) -> Result<String> {
+    let dir = std::env::current_dir()?.canonicalize()?;
+    let mut hasher = Sha256::new();
+    hasher.update(dir.to_string_lossy().as_bytes());
+    hasher.update([0]);
+    hasher.update(command.as_bytes());
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+#[cfg(unix)]
+fn shell(command: &str) -> Command {
+    let mut cmd = Command::new("sh");
+    cmd.arg("-c").arg(command);
+    cmd
+}
+
+#[cfg(windows)]
+fn shell(command: &str) -> Command {
+    let mut cmd = Command::new("cmd");
+    cmd.arg("/C").arg(command);
+    cmd
+}
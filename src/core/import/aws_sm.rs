@@ -0,0 +1,111 @@
+//! AWS Secrets Manager import source.
+//!
+//! Fetches one secret with `GetSecretValue` and turns it into key/value
+//! pairs the same way `--format aws-secretsmanager` does: a JSON object
+//! payload gives one pair per field, anything else a single pair keyed by
+//! the secret's name. Enable with `--features aws`.
+//!
+//! Credentials come from the environment or the default provider chain.
+
+#[cfg(any(feature = "aws", test))]
+use crate::core::domain::ImportFormat;
+use crate::error::Result;
+
+/// Fetch a secret by name or ARN and split it into key/value pairs.
+///
+/// # Errors
+///
+/// Returns an error if the build lacks the `aws` feature, the request
+/// fails, or the payload can't be read.
+#[cfg(feature = "aws")]
+pub fn fetch(secret_id: &str) -> Result<Vec<(String, String)>> {
+    use crate::error::Error;
+    use tracing::debug;
+
+    debug!(secret_id, "fetching from AWS Secrets Manager");
+
+    // Create a tokio runtime for the async AWS SDK
+    let rt = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .map_err(|e| Error::Other(format!("failed to create runtime: {}", e)))?;
+
+    let (name, payload) = rt.block_on(async {
+        let config = aws_config::load_defaults(aws_config::BehaviorVersion::latest()).await;
+        let client = aws_sdk_secretsmanager::Client::new(&config);
+
+        let result = client
+            .get_secret_value()
+            .secret_id(secret_id)
+            .send()
+            .await
+            .map_err(|e| {
+                Error::Other(format!(
+                    "failed to fetch '{}' from AWS Secrets Manager: {}",
+                    secret_id, e
+                ))
+            })?;
+
+        let name = result.name().unwrap_or(secret_id).to_string();
+        let payload = match (result.secret_string(), result.secret_binary()) {
+            (Some(text), _) => text.to_string(),
+            (None, Some(blob)) => String::from_utf8(blob.as_ref().to_vec())
+                .map_err(|_| Error::Other(format!("secret '{}' is not UTF-8 text", secret_id)))?,
+            (None, None) => {
+                return Err(Error::Other(format!("secret '{}' has no value", secret_id)))
+            }
+        };
+        Ok((name, payload))
+    })?;
+
+    pairs(&name, payload)
+}
+
+/// Fetch a secret by name or ARN and split it into key/value pairs.
+///
+/// # Errors
+///
+/// Always fails: this build lacks the `aws` feature.
+#[cfg(not(feature = "aws"))]
+pub fn fetch(_secret_id: &str) -> Result<Vec<(String, String)>> {
+    Err(crate::error::Error::Other(
+        "AWS Secrets Manager support is not compiled into this build\n  → Rebuild with: cargo install dugout --features aws"
+            .to_string(),
+    ))
+}
+
+/// Split a fetched payload into pairs.
+#[cfg(any(feature = "aws", test))]
+fn pairs(name: &str, payload: String) -> Result<Vec<(String, String)>> {
+    ImportFormat::AwsSecretsManager.payload_pairs(Some(name.to_string()), payload)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pairs_from_json_payload() {
+        let pairs = pairs(
+            "prod/app",
+            r#"{"DB_URL": "postgres://db", "PORT": 5432}"#.to_string(),
+        )
+        .unwrap();
+        assert_eq!(
+            pairs,
+            vec![
+                ("DB_URL".to_string(), "postgres://db".to_string()),
+                ("PORT".to_string(), "5432".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_pairs_from_string_payload() {
+        let pairs = pairs("prod/db-password", "hunter2".to_string()).unwrap();
+        assert_eq!(
+            pairs,
+            vec![("DB_PASSWORD".to_string(), "hunter2".to_string())]
+        );
+    }
+}
@@ -0,0 +1,7 @@
+//! Import sources that fetch secrets from a remote service.
+//!
+//! File-based imports go through [`Vault::import_with`](crate::core::vault::Vault::import_with);
+//! these sources return key/value pairs for the caller to write.
+
+pub mod aws_sm;
+pub mod onepassword;
@@ -0,0 +1,134 @@
+//! 1Password import source.
+//!
+//! Runs `op item get ITEM --format json` and turns the item's fields into
+//! key/value pairs. Field labels become keys: upper-cased, with anything but
+//! letters and digits replaced by `_` (`api key` becomes `API_KEY`). Fields
+//! without a value, and labels that still aren't valid keys, are skipped
+//! with a warning.
+//!
+//! The `op` CLI must be installed and signed in.
+
+use std::process::{Command, Stdio};
+
+use serde::Deserialize;
+use tracing::{debug, warn};
+
+use crate::core::vault::validate_key;
+use crate::error::{Error, Result};
+
+/// Item JSON printed by `op item get --format json` (only what we read).
+#[derive(Deserialize)]
+struct Item {
+    #[serde(default)]
+    fields: Vec<Field>,
+}
+
+#[derive(Deserialize)]
+struct Field {
+    #[serde(default)]
+    label: String,
+    #[serde(default)]
+    value: Option<String>,
+}
+
+/// Fetch an item by name or ID and split its fields into key/value pairs.
+///
+/// # Errors
+///
+/// Returns an error if `op` isn't on `PATH`, isn't signed in or can't find
+/// the item (its stderr is included), or prints something other than item
+/// JSON.
+pub fn fetch(item: &str) -> Result<Vec<(String, String)>> {
+    let path = which::which("op").map_err(|_| {
+        Error::Other(
+            "op not found on PATH\n  → Install the 1Password CLI: https://developer.1password.com/docs/cli"
+                .to_string(),
+        )
+    })?;
+
+    debug!(item, "fetching 1Password item");
+    let output = Command::new(path)
+        .args(["item", "get", item, "--format", "json"])
+        .stdin(Stdio::inherit())
+        .output()
+        .map_err(|e| Error::Other(format!("failed to run op: {}", e)))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(Error::Other(format!(
+            "op exited with {}: {}\n  → Check the item name, and sign in with: eval $(op signin)",
+            output.status,
+            stderr.trim()
+        )));
+    }
+
+    pairs(&String::from_utf8_lossy(&output.stdout))
+}
+
+/// Extract pairs from item JSON.
+fn pairs(json: &str) -> Result<Vec<(String, String)>> {
+    let item: Item = serde_json::from_str(json)
+        .map_err(|e| Error::Other(format!("op printed unexpected item JSON: {}", e)))?;
+
+    let mut pairs = Vec::new();
+    for field in item.fields {
+        let Some(value) = field.value.filter(|value| !value.is_empty()) else {
+            continue;
+        };
+        let key = key_from_label(&field.label);
+        if let Err(e) = validate_key(&key) {
+            warn!(label = %field.label, "skipping 1Password field: {}", e);
+            continue;
+        }
+        pairs.push((key, value));
+    }
+    Ok(pairs)
+}
+
+/// Upper-case a field label, replacing anything but letters and digits with `_`.
+fn key_from_label(label: &str) -> String {
+    label
+        .trim()
+        .chars()
+        .map(|ch| {
+            if ch.is_ascii_alphanumeric() {
+                ch.to_ascii_uppercase()
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pairs_maps_labels_to_keys() {
+        let json = r#"{
+            "id": "abc",
+            "title": "Stripe",
+            "fields": [
+                {"id": "username", "label": "username", "value": "admin"},
+                {"id": "x1", "label": "api key", "value": "sk-123"},
+                {"id": "notes", "label": "notesPlain"},
+                {"id": "x2", "label": "2fa", "value": "otp"},
+                {"id": "x3", "label": "", "value": "orphan"}
+            ]
+        }"#;
+        let pairs = pairs(json).unwrap();
+        assert_eq!(
+            pairs,
+            vec![
+                ("USERNAME".to_string(), "admin".to_string()),
+                ("API_KEY".to_string(), "sk-123".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_pairs_rejects_non_item_json() {
+        assert!(pairs("not json").is_err());
+    }
+}
@@ -9,8 +9,14 @@ pub mod types;
 pub mod vault;
 
 // Internal implementation - exposed to CLI but not public API
+pub(crate) mod cache;
 pub(crate) mod cipher;
 pub(crate) mod config;
 pub(crate) mod constants;
 pub(crate) mod detect;
+pub(crate) mod glob;
+pub(crate) mod hooks;
+pub(crate) mod import;
+pub(crate) mod oplog;
 pub(crate) mod store;
+pub(crate) mod template;
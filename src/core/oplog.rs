@@ -0,0 +1,100 @@
+//! Local operation log.
+//!
+//! Appends one JSON line per vault operation to
+//! `~/.dugout/log/<project>.ndjson`. Writing is best-effort: a failure to
+//! log never fails the operation itself. Set `DUGOUT_NO_OPLOG=1` to turn
+//! logging off.
+//!
+//! Once the log passes [`MAX_LOG_BYTES`] it is moved to `<project>.ndjson.1`
+//! (replacing any older one) and a fresh file is started, so at most two
+//! generations are kept.
+
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use tracing::debug;
+
+use crate::core::constants;
+use crate::core::domain::{Identity, LogEvent};
+use crate::error::Result;
+
+/// Size at which the log is rotated.
+pub const MAX_LOG_BYTES: u64 = 1024 * 1024;
+
+/// Path of the operation log for a project.
+pub fn path(project_id: &str) -> Result<PathBuf> {
+    Ok(Identity::resolve_home()?
+        .join(constants::LOG_DIR)
+        .join(format!("{}.ndjson", project_id)))
+}
+
+/// Path of the previous, rotated log generation.
+fn rotated_path(path: &Path) -> PathBuf {
+    let mut rotated = path.as_os_str().to_owned();
+    rotated.push(".1");
+    PathBuf::from(rotated)
+}
+
+/// Append an event to the project's log, rotating it first if it is full.
+pub fn append(event: &LogEvent) -> Result<()> {
+    let path = path(&event.project)?;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    if fs::metadata(&path).is_ok_and(|m| m.len() >= MAX_LOG_BYTES) {
+        debug!(path = %path.display(), "rotating operation log");
+        fs::rename(&path, rotated_path(&path))?;
+    }
+
+    let mut options = OpenOptions::new();
+    options.create(true).append(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    let mut file = options.open(&path)?;
+    writeln!(file, "{}", serde_json::to_string(event)?)?;
+    Ok(())
+}
+
+/// Record an event, logging (not returning) any failure.
+///
+/// Does nothing when `DUGOUT_NO_OPLOG` is set.
+pub fn record(event: LogEvent) {
+    if std::env::var_os(constants::NO_OPLOG_ENV).is_some() {
+        return;
+    }
+    if let Err(e) = append(&event) {
+        debug!(error = %e, action = %event.action, "failed to write operation log");
+    }
+}
+
+/// Read all events for a project, oldest first.
+///
+/// Includes the rotated generation. Malformed lines are skipped.
+pub fn read(project_id: &str) -> Result<Vec<LogEvent>> {
+    let path = path(project_id)?;
+
+    let mut events = Vec::new();
+    for path in [rotated_path(&path), path] {
+        if !path.exists() {
+            continue;
+        }
+        let contents = fs::read_to_string(&path)?;
+        events.extend(
+            contents
+                .lines()
+                .filter(|line| !line.trim().is_empty())
+                .filter_map(|line| match serde_json::from_str(line) {
+                    Ok(event) => Some(event),
+                    Err(e) => {
+                        debug!(error = %e, "skipping malformed log line");
+                        None
+                    }
+                }),
+        );
+    }
+    Ok(events)
+}
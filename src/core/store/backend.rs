@@ -1,34 +1,43 @@
 //! Backend selection logic for identity storage
 //!
-//! This module determines which storage backend to use (Keychain vs Filesystem)
-//! based on platform and explicit user configuration.
+//! This module determines which storage backend to use (Keychain, Credential
+//! Manager, Secret Service, or Filesystem) based on platform, enabled features,
+//! and explicit user configuration.
 
 use super::{Filesystem, Store};
 use tracing::info;
-#[cfg(target_os = "macos")]
+#[cfg(any(target_os = "macos", target_os = "windows"))]
 use tracing::warn;
 
 #[cfg(target_os = "macos")]
 use super::keychain::Keychain;
-#[cfg(target_os = "macos")]
+#[cfg(feature = "secret-service")]
+use super::secret_service::SecretService;
+#[cfg(target_os = "windows")]
+use super::wincred::CredentialManager;
+#[cfg(any(target_os = "macos", target_os = "windows", feature = "secret-service"))]
 use crate::core::domain::Identity;
-#[cfg(target_os = "macos")]
+#[cfg(any(target_os = "macos", target_os = "windows", feature = "secret-service"))]
 use crate::error::Result;
 
 /// Default backend selection
 ///
 /// On macOS: Use Keychain (default) or Filesystem (if DUGOUT_NO_KEYCHAIN=1)
+/// On Windows: Use Credential Manager (default) or Filesystem (if DUGOUT_NO_KEYCHAIN=1)
+/// With the `secret-service` feature: Use the Secret Service if a session bus
+/// and keyring are reachable, otherwise Filesystem (headless CI, SSH)
 /// Other platforms: Always use Filesystem
 ///
-/// Users can explicitly disable Keychain with DUGOUT_NO_KEYCHAIN=1 environment variable.
+/// Users can explicitly disable the OS credential store with DUGOUT_NO_KEYCHAIN=1
+/// environment variable.
 pub fn default_backend() -> Box<dyn Store> {
     #[cfg(target_os = "macos")]
     {
         if should_use_keychain() {
             info!("Using macOS Keychain backend (hardware-backed security)");
             if let Ok(keychain) = Keychain::new() {
-                return Box::new(KeychainThenFilesystem {
-                    keychain,
+                return Box::new(PlatformThenFilesystem {
+                    platform: keychain,
                     filesystem: Filesystem,
                 });
             } else {
@@ -38,41 +47,74 @@ pub fn default_backend() -> Box<dyn Store> {
             info!("Using Filesystem backend (DUGOUT_NO_KEYCHAIN=1)");
         }
     }
+    #[cfg(target_os = "windows")]
+    {
+        if should_use_keychain() {
+            info!("Using Windows Credential Manager backend");
+            if let Ok(credman) = CredentialManager::new() {
+                return Box::new(PlatformThenFilesystem {
+                    platform: credman,
+                    filesystem: Filesystem,
+                });
+            } else {
+                warn!("Failed to initialize Credential Manager backend");
+            }
+        } else {
+            info!("Using Filesystem backend (DUGOUT_NO_KEYCHAIN=1)");
+        }
+    }
+    #[cfg(feature = "secret-service")]
+    {
+        if should_use_keychain() {
+            match SecretService::new() {
+                Ok(secret_service) => {
+                    info!("Using Secret Service backend");
+                    return Box::new(PlatformThenFilesystem {
+                        platform: secret_service,
+                        filesystem: Filesystem,
+                    });
+                }
+                Err(e) => info!(error = %e, "Secret Service unavailable"),
+            }
+        }
+    }
     info!("Using Filesystem backend");
 
     Box::new(Filesystem)
 }
 
-/// Determine if Keychain should be used
+/// Determine if the OS credential store should be used
 ///
-/// macOS: enabled by default, disabled if DUGOUT_NO_KEYCHAIN is set
-/// Other platforms: always disabled (no keychain support)
-#[cfg(target_os = "macos")]
+/// macOS, Windows, and the `secret-service` feature: enabled by default,
+/// disabled if DUGOUT_NO_KEYCHAIN is set
+/// Other platforms: always disabled (no credential store support)
+#[cfg(any(target_os = "macos", target_os = "windows", feature = "secret-service"))]
 fn should_use_keychain() -> bool {
     // Use keychain unless explicitly disabled
     std::env::var("DUGOUT_NO_KEYCHAIN").is_err()
 }
 
-#[cfg(target_os = "macos")]
-struct KeychainThenFilesystem {
-    keychain: Keychain,
+/// OS credential store first, filesystem keys as a fallback for loading
+#[cfg(any(target_os = "macos", target_os = "windows", feature = "secret-service"))]
+struct PlatformThenFilesystem<S: Store> {
+    platform: S,
     filesystem: Filesystem,
 }
 
-#[cfg(target_os = "macos")]
-impl Store for KeychainThenFilesystem {
+#[cfg(any(target_os = "macos", target_os = "windows", feature = "secret-service"))]
+impl<S: Store> Store for PlatformThenFilesystem<S> {
     fn generate_keypair(&self, project_id: &str) -> Result<String> {
-        self.keychain.generate_keypair(project_id)
+        self.platform.generate_keypair(project_id)
     }
 
     fn load_identity(&self, project_id: &str) -> Result<Identity> {
-        self.keychain
+        self.platform
             .load_identity(project_id)
             .or_else(|_| self.filesystem.load_identity(project_id))
     }
 
     fn has_key(&self, project_id: &str) -> bool {
-        self.keychain.has_key(project_id) || self.filesystem.has_key(project_id)
+        self.platform.has_key(project_id) || self.filesystem.has_key(project_id)
     }
 }
 
@@ -86,4 +128,23 @@ mod tests {
         // Just verify we can create a backend and call methods on it
         assert!(!backend.has_key("nonexistent")); // Should return false for non-existent key
     }
+
+    #[cfg(feature = "secret-service")]
+    #[test]
+    fn test_secret_service_without_session_bus_is_unavailable() {
+        let previous = std::env::var_os("DBUS_SESSION_BUS_ADDRESS");
+        std::env::set_var(
+            "DBUS_SESSION_BUS_ADDRESS",
+            "unix:path=/nonexistent/dugout-bus",
+        );
+
+        // Headless: the store can't connect, and the default backend still works
+        assert!(SecretService::new().is_err());
+        assert!(!default_backend().has_key("nonexistent"));
+
+        match previous {
+            Some(value) => std::env::set_var("DBUS_SESSION_BUS_ADDRESS", value),
+            None => std::env::remove_var("DBUS_SESSION_BUS_ADDRESS"),
+        }
+    }
 }
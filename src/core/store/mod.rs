@@ -1,7 +1,9 @@
 //! Key management operations.
 //!
 //! Provides key generation and storage abstraction with implementations
-//! for different storage backends.
+//! for different storage backends: the filesystem everywhere, plus the
+//! macOS Keychain, Windows Credential Manager, or Linux Secret Service (with
+//! the `secret-service` feature) where available.
 //!
 //! ## Adding a New Storage Backend
 //!
@@ -36,6 +38,12 @@ mod fs;
 #[cfg(target_os = "macos")]
 pub mod keychain;
 
+#[cfg(target_os = "windows")]
+pub mod wincred;
+
+#[cfg(feature = "secret-service")]
+pub mod secret_service;
+
 pub use backend::default_backend;
 pub use fs::Filesystem;
 
@@ -91,8 +99,9 @@ pub trait Store {
 /// Creates the key directory if it doesn't exist and stores the private
 /// key with restricted permissions (0600 on Unix).
 ///
-/// On macOS, stores in Keychain by default (or filesystem if Keychain is disabled).
-/// On other platforms, stores in filesystem.
+/// On macOS, stores in Keychain by default, and on Windows in Credential
+/// Manager (or filesystem if either is disabled). On other platforms, stores
+/// in filesystem.
 ///
 /// # Arguments
 ///
@@ -111,8 +120,8 @@ pub fn generate_keypair(project_id: &str) -> Result<String> {
 
 /// Load the private key (identity) for a project.
 ///
-/// On macOS, tries Keychain first, then falls back to filesystem.
-/// On other platforms, loads from filesystem.
+/// On macOS and Windows, tries Keychain or Credential Manager first, then
+/// falls back to filesystem. On other platforms, loads from filesystem.
 ///
 /// # Arguments
 ///
@@ -132,8 +141,8 @@ pub fn load_identity(project_id: &str) -> Result<Identity> {
 
 /// Check if a keypair exists for a project.
 ///
-/// On macOS, checks Keychain then filesystem.
-/// On other platforms, checks filesystem.
+/// On macOS and Windows, checks Keychain or Credential Manager, then
+/// filesystem. On other platforms, checks filesystem.
 ///
 /// # Arguments
 ///
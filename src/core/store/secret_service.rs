@@ -0,0 +1,181 @@
+//! Secret Service backend for secure identity storage on Linux desktops
+//!
+//! This module provides a Store implementation that uses the freedesktop
+//! Secret Service API over DBus (GNOME Keyring, KWallet) to store age
+//! identities in the user's default keyring. Enable with
+//! `--features secret-service`.
+
+use std::collections::HashMap;
+
+use ::secret_service::blocking::{Collection, SecretService as Connection};
+use ::secret_service::EncryptionType;
+use tracing::{debug, error, info};
+
+use crate::core::domain::identity::{Identity, IdentitySource};
+use crate::error::{Error, Result, StoreError};
+use age::x25519;
+
+/// Secret Service backend for storing identities
+pub struct SecretService {
+    service: String,
+}
+
+impl SecretService {
+    /// Service attribute for all dugout identities in the keyring
+    const SERVICE_NAME: &'static str = "com.usemantle.dugout";
+
+    /// Create a new Secret Service backend
+    ///
+    /// # Errors
+    ///
+    /// Returns `StoreError::SecretServiceError` if no session bus or Secret
+    /// Service provider is available (e.g. headless CI).
+    pub fn new() -> Result<Self> {
+        let store = Self {
+            service: Self::SERVICE_NAME.to_string(),
+        };
+        // Fail early so callers can fall back to the filesystem
+        store.with_collection(|_| Ok(()))?;
+        Ok(store)
+    }
+
+    /// Attributes identifying an account's item
+    fn attributes<'a>(&'a self, account: &'a str) -> HashMap<&'a str, &'a str> {
+        HashMap::from([("service", self.service.as_str()), ("account", account)])
+    }
+
+    /// Run `f` against the unlocked default collection
+    fn with_collection<T>(
+        &self,
+        f: impl FnOnce(&Collection<'_>) -> std::result::Result<T, ::secret_service::Error>,
+    ) -> Result<T> {
+        let fail = |e: ::secret_service::Error| -> Error {
+            StoreError::SecretServiceError(e.to_string()).into()
+        };
+        let connection = Connection::connect(EncryptionType::Dh).map_err(fail)?;
+        let collection = connection.get_default_collection().map_err(fail)?;
+        collection.ensure_unlocked().map_err(fail)?;
+        f(&collection).map_err(fail)
+    }
+
+    /// Store an identity in the keyring
+    pub fn store_identity(&self, account: &str, secret: &str, force: bool) -> Result<()> {
+        info!(
+            account = %account,
+            service = %self.service,
+            "Storing identity in Secret Service"
+        );
+
+        // Check if key already exists
+        if !force && self.secret_service_has_key(account) {
+            error!(account = %account, "Identity already exists in Secret Service");
+            return Err(StoreError::SecretServiceError(format!(
+                "Identity '{}' already exists in the keyring. Use --force to overwrite.",
+                account
+            ))
+            .into());
+        }
+
+        let label = format!("dugout identity ({})", account);
+        self.with_collection(|collection| {
+            collection.create_item(
+                &label,
+                self.attributes(account),
+                secret.as_bytes(),
+                true,
+                "text/plain",
+            )?;
+            Ok(())
+        })?;
+
+        info!(account = %account, "✓ Identity stored in Secret Service");
+        Ok(())
+    }
+
+    /// Load an identity from the keyring
+    pub(crate) fn load_from_secret_service(&self, account: &str) -> Result<Identity> {
+        info!(
+            account = %account,
+            service = %self.service,
+            "Loading identity from Secret Service"
+        );
+
+        let secret = self.with_collection(|collection| {
+            match collection.search_items(self.attributes(account))?.first() {
+                Some(item) => item.get_secret().map(Some),
+                None => Ok(None),
+            }
+        })?;
+        let Some(secret) = secret else {
+            debug!(account = %account, "identity not found in Secret Service");
+            return Err(StoreError::NoPrivateKey(format!("secret-service:{}", account)).into());
+        };
+
+        let secret_str = String::from_utf8(secret).map_err(|e| {
+            error!(error = %e, "Invalid UTF-8 in Secret Service data");
+            StoreError::InvalidFormat(format!("Invalid UTF-8 in Secret Service data: {}", e))
+        })?;
+
+        // Parse as age identity
+        let inner: x25519::Identity = secret_str.trim().parse().map_err(|e: &str| {
+            error!(error = %e, "Invalid age identity format in Secret Service");
+            StoreError::InvalidFormat(e.to_string())
+        })?;
+
+        info!(account = %account, "✓ Loaded identity from Secret Service");
+
+        Ok(Identity::from_parts(
+            inner,
+            IdentitySource::SecretService {
+                account: account.to_string(),
+            },
+        ))
+    }
+
+    /// Check if an identity exists in the keyring
+    fn secret_service_has_key(&self, account: &str) -> bool {
+        let found = self.with_collection(|collection| {
+            Ok(!collection
+                .search_items(self.attributes(account))?
+                .is_empty())
+        });
+        match found {
+            Ok(found) => found,
+            Err(e) => {
+                // Other errors (e.g., locked keyring) - treat as not found
+                debug!(account = %account, error = %e, "error checking Secret Service");
+                false
+            }
+        }
+    }
+}
+
+impl super::Store for SecretService {
+    fn generate_keypair(&self, project_id: &str) -> Result<String> {
+        info!(
+            project_id = %project_id,
+            backend = "SecretService",
+            "Generating keypair with Secret Service backend"
+        );
+
+        // Generate identity in memory
+        let inner = x25519::Identity::generate();
+        let public_key = inner.to_public().to_string();
+
+        // Store in the keyring - NO FILESYSTEM FALLBACK
+        use age::secrecy::ExposeSecret;
+        let secret = inner.to_string();
+        self.store_identity(project_id, secret.expose_secret(), false)?;
+
+        Ok(public_key)
+    }
+
+    fn load_identity(&self, project_id: &str) -> Result<Identity> {
+        // Load from the keyring - NO FILESYSTEM FALLBACK
+        self.load_from_secret_service(project_id)
+    }
+
+    fn has_key(&self, project_id: &str) -> bool {
+        self.secret_service_has_key(project_id)
+    }
+}
@@ -0,0 +1,275 @@
+//! Windows Credential Manager backend for secure identity storage
+//!
+//! This module provides a Store implementation that uses the Windows
+//! Credential Manager to store age identities, protected by the user's
+//! logon credentials (DPAPI) instead of a plaintext file.
+
+use tracing::{debug, error, info};
+use windows::core::{HSTRING, PWSTR};
+use windows::Win32::Foundation::ERROR_NOT_FOUND;
+use windows::Win32::Security::Credentials::{
+    CredDeleteW, CredFree, CredReadW, CredWriteW, CREDENTIALW, CRED_FLAGS,
+    CRED_PERSIST_LOCAL_MACHINE, CRED_TYPE_GENERIC,
+};
+
+use crate::core::domain::identity::{Identity, IdentitySource};
+use crate::error::{Result, StoreError};
+use age::x25519;
+
+/// Credential Manager backend for storing identities
+pub struct CredentialManager {
+    service: String,
+}
+
+impl CredentialManager {
+    /// Prefix of the target name for all dugout identities
+    const SERVICE_NAME: &'static str = "com.usemantle.dugout";
+
+    /// Create a new Credential Manager backend
+    pub fn new() -> Result<Self> {
+        Ok(Self {
+            service: Self::SERVICE_NAME.to_string(),
+        })
+    }
+
+    /// Credential target name for an account (`com.usemantle.dugout/<account>`)
+    fn target(&self, account: &str) -> HSTRING {
+        HSTRING::from(format!("{}/{}", self.service, account))
+    }
+
+    /// Store an identity in the Credential Manager
+    pub fn store_identity(&self, account: &str, secret: &str, force: bool) -> Result<()> {
+        info!(
+            account = %account,
+            service = %self.service,
+            "Storing identity in Windows Credential Manager"
+        );
+
+        // Check if key already exists
+        if !force && self.credman_has_key(account) {
+            error!(account = %account, "Identity already exists in Credential Manager");
+            return Err(StoreError::CredentialManagerError(format!(
+                "Identity '{}' already exists in Credential Manager. Use --force to overwrite.",
+                account
+            ))
+            .into());
+        }
+
+        // CredWriteW replaces an existing credential with the same target
+        let target = self.target(account);
+        let mut blob = secret.as_bytes().to_vec();
+        let credential = CREDENTIALW {
+            Flags: CRED_FLAGS(0),
+            Type: CRED_TYPE_GENERIC,
+            TargetName: PWSTR(target.as_ptr() as *mut u16),
+            CredentialBlobSize: blob.len() as u32,
+            CredentialBlob: blob.as_mut_ptr(),
+            Persist: CRED_PERSIST_LOCAL_MACHINE,
+            ..Default::default()
+        };
+
+        // SAFETY: `credential` only borrows `target` and `blob`, which outlive the call
+        let result = unsafe { CredWriteW(&credential, 0) };
+        zeroize::Zeroize::zeroize(&mut blob);
+
+        match result {
+            Ok(()) => {
+                info!(account = %account, "✓ Identity stored in Credential Manager");
+                Ok(())
+            }
+            Err(e) => {
+                error!(
+                    account = %account,
+                    error = %e,
+                    "Failed to store identity in Credential Manager"
+                );
+                Err(StoreError::CredentialManagerError(format!(
+                    "Credential Manager storage failed: {}. Set DUGOUT_NO_KEYCHAIN=1 to store locally instead.",
+                    e
+                ))
+                .into())
+            }
+        }
+    }
+
+    /// Read the raw secret stored for an account
+    fn read_secret(&self, account: &str) -> windows::core::Result<String> {
+        let target = self.target(account);
+        let mut credential: *mut CREDENTIALW = std::ptr::null_mut();
+
+        // SAFETY: on success `credential` points to a buffer owned by the
+        // system, which is released with CredFree after copying the blob
+        unsafe {
+            CredReadW(&target, CRED_TYPE_GENERIC, 0, &mut credential)?;
+            let blob = std::slice::from_raw_parts(
+                (*credential).CredentialBlob,
+                (*credential).CredentialBlobSize as usize,
+            );
+            let secret = String::from_utf8_lossy(blob).into_owned();
+            CredFree(credential as *const _);
+            Ok(secret)
+        }
+    }
+
+    /// Load an identity from the Credential Manager
+    pub(crate) fn load_from_credman(&self, account: &str) -> Result<Identity> {
+        info!(
+            account = %account,
+            service = %self.service,
+            "Loading identity from Windows Credential Manager"
+        );
+
+        match self.read_secret(account) {
+            Ok(secret_str) => {
+                // Parse as age identity
+                let inner: x25519::Identity = secret_str.trim().parse().map_err(|e: &str| {
+                    error!(error = %e, "Invalid age identity format in Credential Manager");
+                    StoreError::InvalidFormat(e.to_string())
+                })?;
+
+                info!(account = %account, "✓ Loaded identity from Credential Manager");
+
+                Ok(Identity::from_parts(
+                    inner,
+                    IdentitySource::CredentialManager {
+                        target: format!("{}/{}", self.service, account),
+                    },
+                ))
+            }
+            Err(e) if e.code() == ERROR_NOT_FOUND.to_hresult() => {
+                debug!(account = %account, "identity not found in Credential Manager");
+                Err(StoreError::NoPrivateKey(format!("credman:{}", account)).into())
+            }
+            Err(e) => {
+                error!(
+                    account = %account,
+                    error = %e,
+                    "Failed to load identity from Credential Manager"
+                );
+                Err(
+                    StoreError::CredentialManagerError(format!("Credential Manager error: {}", e))
+                        .into(),
+                )
+            }
+        }
+    }
+
+    /// Delete an identity from the Credential Manager
+    pub fn delete_identity(&self, account: &str) -> Result<()> {
+        info!(
+            account = %account,
+            service = %self.service,
+            "Deleting identity from Credential Manager"
+        );
+
+        let target = self.target(account);
+        // SAFETY: `target` is a valid null-terminated wide string for the call
+        match unsafe { CredDeleteW(&target, CRED_TYPE_GENERIC, 0) } {
+            Ok(()) => {
+                info!(account = %account, "✓ Deleted identity from Credential Manager");
+                Ok(())
+            }
+            // Item not found is not an error for delete
+            Err(e) if e.code() == ERROR_NOT_FOUND.to_hresult() => {
+                info!(account = %account, "Identity not found in Credential Manager (already deleted)");
+                Ok(())
+            }
+            Err(e) => {
+                error!(account = %account, error = %e, "Failed to delete from Credential Manager");
+                Err(StoreError::CredentialManagerError(format!(
+                    "Failed to delete from Credential Manager: {}",
+                    e
+                ))
+                .into())
+            }
+        }
+    }
+
+    /// Check if an identity exists in the Credential Manager
+    fn credman_has_key(&self, account: &str) -> bool {
+        match self.read_secret(account) {
+            Ok(_) => {
+                debug!(account = %account, "identity exists in Credential Manager");
+                true
+            }
+            Err(e) => {
+                // Not found, or unreadable - treat both as missing
+                debug!(account = %account, error = %e, "identity not found in Credential Manager");
+                false
+            }
+        }
+    }
+}
+
+impl super::Store for CredentialManager {
+    fn generate_keypair(&self, project_id: &str) -> Result<String> {
+        info!(
+            project_id = %project_id,
+            backend = "CredentialManager",
+            "Generating keypair with Credential Manager backend"
+        );
+
+        // Generate identity in memory
+        let inner = x25519::Identity::generate();
+        let public_key = inner.to_public().to_string();
+
+        // Store in Credential Manager - NO FILESYSTEM FALLBACK
+        use age::secrecy::ExposeSecret;
+        let secret = inner.to_string();
+        self.store_identity(project_id, secret.expose_secret(), false)?;
+
+        info!(
+            project_id = %project_id,
+            backend = "CredentialManager",
+            "✓ Identity generated and stored in Credential Manager"
+        );
+        Ok(public_key)
+    }
+
+    fn load_identity(&self, project_id: &str) -> Result<Identity> {
+        info!(
+            project_id = %project_id,
+            backend = "CredentialManager",
+            "Loading identity from Credential Manager"
+        );
+
+        // Load from Credential Manager - NO FILESYSTEM FALLBACK
+        self.load_from_credman(project_id)
+    }
+
+    fn has_key(&self, project_id: &str) -> bool {
+        self.credman_has_key(project_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::Store;
+    use super::*; // Bring Store trait into scope
+
+    #[test]
+    fn test_credman_backend_creation() {
+        let credman = CredentialManager::new().unwrap();
+        assert_eq!(credman.service, CredentialManager::SERVICE_NAME);
+    }
+
+    #[test]
+    fn test_credman_storage_roundtrip() {
+        let project_id = "test-credman-storage";
+        let credman = CredentialManager::new().unwrap();
+        let _ = credman.delete_identity(project_id);
+
+        let pubkey = credman.generate_keypair(project_id).unwrap();
+        assert!(pubkey.starts_with("age1"));
+        assert!(credman.has_key(project_id));
+
+        let identity = credman.load_identity(project_id).unwrap();
+        assert_eq!(identity.public_key(), pubkey);
+
+        // Without --force an existing identity is kept
+        assert!(credman.store_identity(project_id, "x", false).is_err());
+
+        credman.delete_identity(project_id).unwrap();
+        assert!(!credman.has_key(project_id));
+    }
+}
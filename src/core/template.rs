@@ -0,0 +1,91 @@
+//! `{{KEY}}` placeholder substitution for `dugout render`.
+//!
+//! A placeholder is a secret key between double braces, optionally padded
+//! with spaces (`{{ KEY }}`). Anything else in braces, such as a Jinja or
+//! Helm expression, is copied through untouched.
+
+use zeroize::Zeroizing;
+
+/// Keys referenced by `template`, in order of first use.
+pub fn placeholders(template: &str) -> Vec<&str> {
+    let mut keys = Vec::new();
+    scan(template, |token| {
+        if let Token::Key(key) = token {
+            if !keys.contains(&key) {
+                keys.push(key);
+            }
+        }
+    });
+    keys
+}
+
+/// Replace each placeholder with `lookup(key)`.
+///
+/// Placeholders `lookup` has no value for are kept as written.
+pub fn render<'v>(template: &str, lookup: impl Fn(&str) -> Option<&'v str>) -> Zeroizing<String> {
+    let mut out = Zeroizing::new(String::with_capacity(template.len()));
+    scan(template, |token| match token {
+        Token::Text(text) => out.push_str(text),
+        Token::Key(key) => match lookup(key) {
+            Some(value) => out.push_str(value),
+            None => {
+                out.push_str("{{");
+                out.push_str(key);
+                out.push_str("}}");
+            }
+        },
+    });
+    out
+}
+
+enum Token<'a> {
+    Text(&'a str),
+    Key(&'a str),
+}
+
+fn scan<'a>(template: &'a str, mut emit: impl FnMut(Token<'a>)) {
+    let mut rest = template;
+    while let Some(start) = rest.find("{{") {
+        emit(Token::Text(&rest[..start]));
+        let after = &rest[start + 2..];
+        match after.find("}}") {
+            Some(end) if is_key(after[..end].trim()) => {
+                emit(Token::Key(after[..end].trim()));
+                rest = &after[end + 2..];
+            }
+            _ => {
+                emit(Token::Text("{{"));
+                rest = after;
+            }
+        }
+    }
+    emit(Token::Text(rest));
+}
+
+fn is_key(name: &str) -> bool {
+    name.starts_with(|ch: char| ch.is_ascii_alphabetic() || ch == '_')
+        && name
+            .chars()
+            .all(|ch| ch.is_ascii_alphanumeric() || ch == '_')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_placeholders_in_order() {
+        let template = "url={{DB_URL}}\nkey={{ API_KEY }}\nagain={{DB_URL}}\n";
+        assert_eq!(placeholders(template), vec!["DB_URL", "API_KEY"]);
+    }
+
+    #[test]
+    fn test_render_substitutes_and_keeps_other_braces() {
+        let template = "a={{ A }} b={{B}} helm={{ .Values.x }} open={{A";
+        let rendered = render(template, |key| (key == "A").then_some("1"));
+        assert_eq!(
+            rendered.as_str(),
+            "a=1 b={{B}} helm={{ .Values.x }} open={{A"
+        );
+    }
+}
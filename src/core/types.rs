@@ -12,7 +12,7 @@ pub type SecretKey = String;
 /// Contains the age-encrypted and ASCII-armored representation of a secret.
 pub type EncryptedValue = String;
 
-/// An age public key string (starts with "age1...").
+/// An age public key string (`age1...`, or `ssh-ed25519 AAAA...` for SSH keys).
 ///
 /// Used for encrypting secrets for specific recipients.
 pub type PublicKey = String;
@@ -2,17 +2,29 @@
 //!
 //! The primary interface for all dugout operations.
 
+use crate::core::cache;
 use crate::core::cipher;
 use crate::core::config::{self, Config};
 use crate::core::constants;
-use crate::core::domain::{Diff, Env, Identity, Recipient, Secret, SyncResult, VaultInfo};
+use crate::core::domain::{
+    audit, key_fingerprint, AccessRequest, Diff, Env, Identity, ImportFormat, ImportOptions,
+    ImportResult, LineEnding, LogEvent, OnConflict, Quoting, Recipient, Resolution, Role, Secret,
+    SetOutcome, Side, SyncPlan, SyncResult, SyncStatus, VaultInfo,
+};
+use crate::core::glob;
+use crate::core::hooks;
+use crate::core::oplog;
 use crate::core::store;
 use crate::core::types::{MemberName, PublicKey, SecretKey};
-use crate::error::{ConfigError, Result, SecretError, ValidationError};
+use crate::error::{ConfigError, Error, Result, SecretError, StoreError, ValidationError};
+use chrono::{DateTime, Utc};
 use sha2::{Digest, Sha256};
-use tracing::{debug, info, instrument};
+use tracing::{debug, info, instrument, warn};
 use zeroize::Zeroizing;
 
+/// Vaults with fewer secrets than this are re-encrypted on the calling thread.
+const PARALLEL_REENCRYPT_MIN: usize = 16;
+
 /// The primary interface for all dugout operations
 ///
 /// Owns the config, manages keys, and provides all secret operations.
@@ -20,8 +32,9 @@ pub struct Vault {
     config: Config,
     project_id: String,
     identity: Identity,
-    backend: cipher::CipherBackend,
+    backend: std::sync::Arc<cipher::CipherBackend>,
     vault_name: Option<String>,
+    progress: Option<Box<dyn FnMut(usize, usize) + Send>>,
 }
 
 impl std::fmt::Debug for Vault {
@@ -32,6 +45,7 @@ impl std::fmt::Debug for Vault {
             .field("identity", &self.identity)
             .field("backend", &self.backend)
             .field("vault_name", &self.vault_name)
+            .field("progress", &self.progress.is_some())
             .finish()
     }
 }
@@ -50,37 +64,382 @@ impl Vault {
     /// Returns error if the configuration is invalid or cannot be read.
     pub fn open_vault(vault: Option<&str>) -> Result<Self> {
         let config = Config::load_from(vault)?;
+        Self::from_config(config, vault)
+    }
+
+    /// Build a vault from an already-loaded config, resolving the identity.
+    fn from_config(config: Config, vault: Option<&str>) -> Result<Self> {
         let project_id = config.project_id();
 
         // Identity resolution order:
         // 1. DUGOUT_IDENTITY / DUGOUT_IDENTITY_FILE env vars (CI/CD)
         // 2. Project-local identity (~/.dugout/keys/<project>/)
         // 3. Global identity (~/.dugout/identity)
+        // 4. SSH key (~/.ssh/id_ed25519), if the vault lists any SSH recipients
+        // A key that couldn't be unlocked explains the failure better than AccessDenied
+        let mut unlock_error = None;
+        let mut loaded_any = false;
+        let mut usable = |loaded: Result<Identity>| match loaded {
+            Ok(id) => {
+                loaded_any = true;
+                Some(id).filter(|id| identity_has_access(&config, id))
+            }
+            Err(
+                e @ Error::Store(
+                    StoreError::PassphraseRequired(_) | StoreError::PassphraseFailed(_),
+                ),
+            ) => {
+                unlock_error.get_or_insert(e);
+                None
+            }
+            Err(_) => None,
+        };
         let identity = Identity::from_env()
-            .filter(|id| identity_has_access(&config, id))
+            .and_then(|id| usable(Ok(id)))
+            .or_else(|| usable(store::load_identity(&project_id)))
             .or_else(|| {
-                store::load_identity(&project_id)
-                    .ok()
-                    .filter(|id| identity_has_access(&config, id))
+                if store::has_global().unwrap_or(false) {
+                    usable(store::load_global_identity())
+                } else {
+                    None
+                }
             })
             .or_else(|| {
-                store::has_global()
-                    .ok()
-                    .filter(|has| *has)
-                    .and_then(|_| store::load_global_identity().ok())
-                    .filter(|id| identity_has_access(&config, id))
-            })
-            .ok_or(ConfigError::AccessDenied)?;
+                let has_ssh = config.recipients.values().any(|k| k.starts_with("ssh-"));
+                if has_ssh {
+                    usable(Identity::load_ssh())
+                } else {
+                    None
+                }
+            });
+        let identity = match (identity, unlock_error) {
+            (Some(identity), _) => identity,
+            (None, Some(e)) => return Err(e),
+            (None, None) => return Err(access_denied(&config, loaded_any).into()),
+        };
 
-        let backend = cipher::CipherBackend::from_config(&config)?;
+        let backend = cipher::CipherBackend::shared(&config)?;
 
-        Ok(Self {
+        let v = Self {
             config,
             project_id,
             identity,
             backend,
             vault_name: vault.map(|s| s.to_string()),
-        })
+            progress: None,
+        };
+        if let Some((synced, configured)) = v.backend_mismatch() {
+            warn!(
+                "secrets were last synced with the '{}' cipher, but the vault now uses '{}'\n  → Run `dugout sync --force` to re-encrypt them",
+                synced, configured
+            );
+        }
+        Ok(v)
+    }
+
+    /// Resolve git merge conflicts in a vault file.
+    ///
+    /// Both sides of the conflict are parsed and merged, together with the
+    /// secrets sidecar when the vault has one. Keys changed on both
+    /// sides are decrypted and compared, so only genuinely different values
+    /// are passed to `choose`.
+    ///
+    /// A secret or recipient on only one side is kept if it was added there
+    /// and dropped if it was removed on the other, which is read from the
+    /// diff3 merge base. A secret changed on one side and removed on the
+    /// other is passed to `choose`. Without a base, one-sided secrets are
+    /// kept and listed in [`Resolution::unverified`]. Aliases, profiles,
+    /// branch mappings, KMS, transit, hook and audit settings are merged the
+    /// same way. Requires decrypt access.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ConfigError` if neither file has conflict hunks, either side
+    /// fails to parse, a recipient has different keys on each side, a
+    /// recipient is on only one side and the file has no diff3 merge base,
+    /// a setting was changed differently on each side, or an alias ends up
+    /// pointing at a secret that is no longer in the merged vault.
+    /// Returns `CipherError` if a conflicting secret can't be decrypted.
+    pub fn resolve_conflicts<F>(vault: Option<&str>, mut choose: F) -> Result<Resolution>
+    where
+        F: FnMut(&str) -> Result<Side>,
+    {
+        let path = Config::config_path_for(vault);
+        if !path.exists() {
+            return Err(ConfigError::NotInitialized.into());
+        }
+        let contents = std::fs::read_to_string(&path).map_err(ConfigError::ReadFile)?;
+        let sidecar_path = constants::sidecar_path(vault);
+        let sidecar = if sidecar_path.exists() {
+            Some(std::fs::read_to_string(&sidecar_path).map_err(ConfigError::ReadFile)?)
+        } else {
+            None
+        };
+
+        // Either file can hold the conflict: secrets live in the sidecar
+        // when the vault sets split_secrets
+        let main_conflict = config::split_conflict(&contents);
+        let sidecar_conflict = sidecar.as_deref().and_then(config::split_conflict);
+        if main_conflict.is_none() && sidecar_conflict.is_none() {
+            return Err(
+                ConfigError::Other(format!("no merge conflicts in {}", path.display())).into(),
+            );
+        }
+        let main = main_conflict.unwrap_or_else(|| config::Conflict::unchanged(&contents));
+        let sidecar =
+            sidecar.map(|s| sidecar_conflict.unwrap_or_else(|| config::Conflict::unchanged(&s)));
+
+        let mut merged = Config::parse_split(&main.ours, sidecar.as_ref().map(|s| &*s.ours))?;
+        let theirs = Config::parse_split(&main.theirs, sidecar.as_ref().map(|s| &*s.theirs))?;
+        let base = match (&main.base, &sidecar) {
+            (Some(base), None) => Some(Config::parse_split(base, None)?),
+            (Some(base), Some(sidecar)) => match &sidecar.base {
+                Some(sidecar_base) => Some(Config::parse_split(base, Some(sidecar_base))?),
+                None => None,
+            },
+            (None, _) => None,
+        };
+
+        // A recipient on one side only was either added there or removed on
+        // the other; only the merge base tells which, and guessing "added"
+        // would give a removed member their access back
+        let mut removed = Vec::new();
+        let one_sided = merged
+            .recipients
+            .keys()
+            .filter(|name| !theirs.recipients.contains_key(*name))
+            .chain(
+                theirs
+                    .recipients
+                    .keys()
+                    .filter(|name| !merged.recipients.contains_key(*name)),
+            );
+        for name in one_sided {
+            match &base {
+                Some(base) if base.recipients.contains_key(name) => removed.push(name.clone()),
+                Some(_) => {}
+                None => {
+                    return Err(ConfigError::Other(format!(
+                        "recipient '{}' is only on one side and may have been removed on the other; \
+                         resolve it by hand, or redo the merge with `git config merge.conflictStyle diff3`",
+                        name
+                    ))
+                    .into());
+                }
+            }
+        }
+
+        let recipients_changed = merged.recipients != theirs.recipients;
+        for (name, key) in theirs.recipients {
+            if removed.contains(&name) {
+                continue;
+            }
+            match merged.recipients.get(&name) {
+                Some(existing) if *existing != key => {
+                    return Err(ConfigError::Other(format!(
+                        "recipient '{}' has a different key on each side; resolve it by hand",
+                        name
+                    ))
+                    .into());
+                }
+                Some(_) => {}
+                None => {
+                    merged.recipients.insert(name, key);
+                }
+            }
+        }
+        for (name, expires) in theirs.recipient_expiry {
+            merged.recipient_expiry.entry(name).or_insert(expires);
+        }
+        for (name, note) in theirs.recipient_notes {
+            merged.recipient_notes.entry(name).or_insert(note);
+        }
+        for (name, role) in theirs.roles {
+            merged.roles.entry(name).or_insert(role);
+        }
+        for name in &removed {
+            merged.recipients.remove(name);
+            merged.recipient_expiry.remove(name);
+            merged.recipient_notes.remove(name);
+            merged.roles.remove(name);
+        }
+
+        let base_config = base.as_ref();
+        merge_table(
+            "aliases",
+            &mut merged.aliases,
+            &theirs.aliases,
+            base_config.map(|b| &b.aliases),
+        )?;
+        merge_table(
+            "profiles",
+            &mut merged.profiles,
+            &theirs.profiles,
+            base_config.map(|b| &b.profiles),
+        )?;
+        merge_table(
+            "vault_from_branch",
+            &mut merged.vault_from_branch,
+            &theirs.vault_from_branch,
+            base_config.map(|b| &b.vault_from_branch),
+        )?;
+        merged.kms = merge_value(
+            "[kms]",
+            merged.kms.as_ref(),
+            theirs.kms.as_ref(),
+            base_config.map(|b| b.kms.as_ref()),
+        )?;
+        merged.vault = merge_value(
+            "[vault]",
+            merged.vault.as_ref(),
+            theirs.vault.as_ref(),
+            base_config.map(|b| b.vault.as_ref()),
+        )?;
+        merged.hooks = merge_value(
+            "[hooks]",
+            merged.hooks.as_ref(),
+            theirs.hooks.as_ref(),
+            base_config.map(|b| b.hooks.as_ref()),
+        )?;
+        merged.audit = merge_value(
+            "[audit]",
+            merged.audit.as_ref(),
+            theirs.audit.as_ref(),
+            base_config.map(|b| b.audit.as_ref()),
+        )?;
+        merged.validate()?;
+
+        let mut v = Self::from_config(merged, vault)?;
+        let mut resolution = Resolution {
+            removed_recipients: removed,
+            ..Resolution::default()
+        };
+
+        // Like recipients, a secret on one side only was added there or
+        // removed on the other, and only the merge base tells which
+        let base_secrets = base.as_ref().map(|b| &b.secrets);
+        let mut dropped = Vec::new();
+        let ours_only: Vec<SecretKey> = v
+            .config
+            .secrets
+            .keys()
+            .filter(|key| !theirs.secrets.contains_key(*key))
+            .cloned()
+            .collect();
+        for key in ours_only {
+            match base_secrets.map(|b| b.get(&key)) {
+                None => resolution.unverified.push(key),
+                Some(None) => {}
+                Some(Some(original)) if v.config.secrets.get(&key) == Some(original) => {
+                    resolution.removed.push(key.clone());
+                    dropped.push(key);
+                }
+                Some(Some(_)) => {
+                    // Changed here, removed on their side
+                    let side = choose(&key)?;
+                    if side == Side::Theirs {
+                        dropped.push(key.clone());
+                    }
+                    resolution.chosen.push((key, side));
+                }
+            }
+        }
+
+        for (key, their_value) in theirs.secrets {
+            let our_value = match v.config.secrets.get(&key) {
+                None => {
+                    match base_secrets.map(|b| b.get(&key)) {
+                        None => resolution.unverified.push(key.clone()),
+                        Some(None) => {}
+                        Some(Some(original)) if *original == their_value => {
+                            resolution.removed.push(key.clone());
+                            dropped.push(key);
+                            continue;
+                        }
+                        Some(Some(_)) => {
+                            // Changed on their side, removed here
+                            let side = choose(&key)?;
+                            resolution.chosen.push((key.clone(), side));
+                            if side == Side::Ours {
+                                dropped.push(key);
+                                continue;
+                            }
+                            v.config.secrets.insert(key, their_value);
+                            continue;
+                        }
+                    }
+                    v.config.secrets.insert(key.clone(), their_value);
+                    resolution.added.push(key);
+                    continue;
+                }
+                Some(ours) if *ours == their_value => continue,
+                Some(ours) => ours.clone(),
+            };
+
+            let ours = Zeroizing::new(v.backend.decrypt(&our_value, v.identity.as_age())?);
+            let theirs_plain =
+                Zeroizing::new(v.backend.decrypt(&their_value, v.identity.as_age())?);
+            if ours == theirs_plain {
+                resolution.identical.push(key);
+                continue;
+            }
+
+            let side = choose(&key)?;
+            if side == Side::Theirs {
+                v.config.secrets.insert(key.clone(), their_value);
+                if let Some(desc) = theirs.descriptions.get(&key) {
+                    v.config.descriptions.insert(key.clone(), desc.clone());
+                }
+                if let Some(desc) = theirs.encrypted_descriptions.get(&key) {
+                    v.config
+                        .encrypted_descriptions
+                        .insert(key.clone(), desc.clone());
+                }
+                if let Some(ts) = theirs.updated.get(&key) {
+                    v.config.updated.insert(key.clone(), ts.clone());
+                }
+            }
+            resolution.chosen.push((key, side));
+        }
+
+        for (key, desc) in theirs.descriptions {
+            v.config.descriptions.entry(key).or_insert(desc);
+        }
+        for (key, desc) in theirs.encrypted_descriptions {
+            v.config.encrypted_descriptions.entry(key).or_insert(desc);
+        }
+        for (key, ts) in theirs.updated {
+            v.config.updated.entry(key).or_insert(ts);
+        }
+        for (key, pattern) in theirs.validations {
+            v.config.validations.entry(key).or_insert(pattern);
+        }
+        for key in &dropped {
+            v.config.secrets.remove(key);
+            v.config.descriptions.remove(key);
+            v.config.encrypted_descriptions.remove(key);
+            v.config.updated.remove(key);
+        }
+        for alias in v.config.aliases.keys() {
+            let target = v.resolve_alias(alias)?;
+            if !v.config.secrets.contains_key(target) {
+                return Err(ConfigError::Other(format!(
+                    "alias '{}' points at '{}', which is not in the merged vault; resolve it by hand",
+                    alias, target
+                ))
+                .into());
+            }
+        }
+
+        if recipients_changed && !v.config.secrets.is_empty() {
+            v.reencrypt_all()?;
+            resolution.reencrypted = true;
+        } else {
+            v.update_recipients_hash();
+            v.config.save_to(vault)?;
+        }
+
+        Ok(resolution)
     }
 
     /// Open the default vault (backward compat).
@@ -99,11 +458,16 @@ impl Vault {
     ///
     /// * `vault` - Optional vault name (None = default `.dugout.toml`)
     /// * `name` - Name of the first recipient (the initializing user)
-    /// * `kms_key` - Optional KMS key for hybrid encryption
+    /// * `kms_key` - Optional KMS key for hybrid encryption. When omitted for
+    ///   a named vault, a matching `[profiles]` entry in the default vault
+    ///   decides the cipher.
     ///
     /// # Errors
     ///
     /// Returns `ConfigError::AlreadyInitialized` if vault already exists.
+    /// Returns `ConfigError::InvalidValue` if the matching profile is invalid.
+    /// Returns `ConfigError::BackendNotCompiled` if the KMS provider's feature
+    /// isn't enabled in this build.
     /// Returns error if keypair generation or file operations fail.
     pub fn init_vault(vault: Option<&str>, name: &str, kms_key: Option<String>) -> Result<Self> {
         validate_member_name(name)?;
@@ -112,12 +476,19 @@ impl Vault {
             return Err(ConfigError::AlreadyInitialized.into());
         }
 
+        let kms_key = match (kms_key, vault) {
+            (None, Some(vault)) => profile_kms_key(vault)?,
+            (kms_key, _) => kms_key,
+        };
+
         let mut config = Config::new();
 
         // Enable hybrid mode if KMS key provided
         if let Some(ref key) = kms_key {
             config.kms = Some(crate::core::config::KmsConfig { key: key.clone() });
         }
+        // Fail before any key or file is written if the backend can't be used
+        cipher::CipherBackend::from_config(&config)?.ensure_available()?;
 
         let project_id = config.project_id();
 
@@ -147,7 +518,12 @@ impl Vault {
                 } else {
                     // Global identity is in Keychain/store backend - write it out
                     use age::secrecy::ExposeSecret;
-                    let secret = global_identity.as_age().to_string();
+                    let secret = global_identity
+                        .as_x25519()
+                        .ok_or_else(|| {
+                            StoreError::InvalidFormat("not an age identity".to_string())
+                        })?
+                        .to_string();
                     std::fs::write(&project_key_path, format!("{}\n", secret.expose_secret()))?;
                 }
 
@@ -170,10 +546,12 @@ impl Vault {
         config
             .recipients
             .insert(name.to_string(), public_key.clone());
+        config.roles.insert(name.to_string(), Role::Admin);
+        let backend = cipher::CipherBackend::shared(&config)?;
+        config.dugout.last_synced_backend = Some(backend.name().to_string());
         config.save_to(vault)?;
 
         config::ensure_gitignore()?;
-        let backend = cipher::CipherBackend::from_config(&config)?;
 
         Ok(Self {
             config,
@@ -181,6 +559,7 @@ impl Vault {
             identity,
             backend,
             vault_name: vault.map(|s| s.to_string()),
+            progress: None,
         })
     }
 
@@ -215,21 +594,44 @@ impl Vault {
     // --- Secrets ---
     /// Set a secret, encrypting for all configured recipients
     ///
+    /// Overwriting (`force`) an existing key first decrypts it: if the value
+    /// is the same, nothing is re-encrypted or written and
+    /// [`SetOutcome::Unchanged`] is returned.
+    ///
     /// # Errors
     ///
     /// Returns `ValidationError` if key or value is invalid.
     /// Returns `SecretError::AlreadyExists` if key exists and `force` is false.
     #[instrument(skip(self, value))]
-    pub fn set(&mut self, key: &str, value: &str, force: bool) -> Result<Secret> {
+    pub fn set(&mut self, key: &str, value: &str, force: bool) -> Result<SetOutcome> {
         debug!(key = %key, force = force, "setting secret");
 
         // Validate input
         validate_key(key)?;
         validate_value(key, value)?;
+        validate_pattern(&self.config, key, value)?;
+        self.reject_alias(key)?;
 
-        if self.config.secrets.contains_key(key) && !force {
-            return Err(SecretError::AlreadyExists(key.to_string()).into());
-        }
+        let outcome = match self.config.secrets.get(key) {
+            None => SetOutcome::Created,
+            Some(_) if !force => {
+                return Err(SecretError::AlreadyExists(key.to_string()).into());
+            }
+            Some(existing) => {
+                // A value we can't decrypt is replaced rather than compared
+                let current = self
+                    .backend
+                    .decrypt(existing, self.identity.as_age())
+                    .map(Zeroizing::new);
+                match current {
+                    Ok(current) if current.as_str() == value => {
+                        debug!(key = %key, "value unchanged, skipping write");
+                        return Ok(SetOutcome::Unchanged);
+                    }
+                    _ => SetOutcome::Updated,
+                }
+            }
+        };
 
         let recipients = get_recipients_as_strings(&self.config);
         if recipients.is_empty() {
@@ -238,14 +640,71 @@ impl Vault {
 
         let encrypted = self.backend.encrypt(value, &recipients)?;
 
-        self.config
-            .secrets
-            .insert(key.to_string(), encrypted.clone());
+        self.config.secrets.insert(key.to_string(), encrypted);
+        self.touch(key);
         self.update_recipients_hash();
         self.config.save_to(self.vault_name.as_deref())?;
+        self.record("set", &[key]);
+        self.run_hook("post_set", &[key]);
 
         debug!(key = %key, "secret set, saving config");
-        Ok(Secret::new(key.to_string(), encrypted))
+        Ok(outcome)
+    }
+
+    /// Set several secrets and save the config once.
+    ///
+    /// Every pair is validated and encrypted before anything is written, so a
+    /// bad pair aborts the whole batch.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ValidationError` if any key or value is invalid.
+    /// Returns `SecretError::AlreadyExists` if any key exists and `force` is false.
+    #[instrument(skip(self, pairs))]
+    pub fn set_many<V: AsRef<str>>(
+        &mut self,
+        pairs: &[(String, V)],
+        force: bool,
+    ) -> Result<Vec<Secret>> {
+        debug!(count = pairs.len(), force = force, "setting secrets");
+
+        for (key, value) in pairs {
+            let value = value.as_ref();
+            validate_key(key)?;
+            validate_value(key, value)?;
+            validate_pattern(&self.config, key, value)?;
+            self.reject_alias(key)?;
+            if self.config.secrets.contains_key(key) && !force {
+                return Err(SecretError::AlreadyExists(key.clone()).into());
+            }
+        }
+
+        let recipients = get_recipients_as_strings(&self.config);
+        if recipients.is_empty() {
+            return Err(ConfigError::NoRecipients.into());
+        }
+
+        let encrypted = pairs
+            .iter()
+            .map(|(key, value)| Ok((key, self.backend.encrypt(value.as_ref(), &recipients)?)))
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut secrets = Vec::with_capacity(encrypted.len());
+        for (key, value) in encrypted {
+            self.config.secrets.insert(key.clone(), value.clone());
+            self.touch(key);
+            secrets.push(
+                Secret::new(key.clone(), value)
+                    .with_description(self.config.descriptions.get(key).cloned()),
+            );
+        }
+        self.update_recipients_hash();
+        self.config.save_to(self.vault_name.as_deref())?;
+        let keys: Vec<&str> = pairs.iter().map(|(key, _)| key.as_str()).collect();
+        self.record("set", &keys);
+        self.run_hook("post_set", &keys);
+
+        Ok(secrets)
     }
 
     /// Get a decrypted secret
@@ -255,175 +714,1198 @@ impl Vault {
     /// # Errors
     ///
     /// Returns `SecretError::NotFound` if the key doesn't exist.
+    /// Returns `SecretError::AliasCycle` if the key is part of an alias cycle.
     /// Returns `CipherError` if decryption fails.
     #[instrument(skip(self))]
     pub fn get(&self, key: &str) -> Result<Zeroizing<String>> {
+        let key = self.resolve_alias(key)?;
         let encrypted = self.config.secrets.get(key).ok_or_else(|| {
             let available: Vec<String> = self.config.secrets.keys().cloned().collect();
             SecretError::not_found_with_suggestions(key.to_string(), &available)
         })?;
 
-        let plaintext = self.backend.decrypt(encrypted, self.identity.as_age())?;
+        let plaintext = self
+            .backend
+            .decrypt(encrypted, self.identity.as_age())
+            .map_err(|e| self.explain_decrypt_failure(key, encrypted, e))?;
+        self.record("read", &[key]);
 
         Ok(Zeroizing::new(plaintext))
     }
 
-    /// Remove a secret.
-    ///
-    /// # Arguments
+    /// Get several decrypted secrets, decrypting only the requested keys.
     ///
-    /// * `key` - Secret key name
+    /// Returns (key, plaintext) pairs in the order requested. Aliases resolve
+    /// to their target's value but keep the requested name.
     ///
     /// # Errors
     ///
-    /// Returns `SecretError::NotFound` if the key doesn't exist.
+    /// Returns `SecretError::NotFound` if any key doesn't exist (checked before
+    /// anything is decrypted).
+    /// Returns `CipherError` if decryption fails.
     #[instrument(skip(self))]
-    pub fn remove(&mut self, key: &str) -> Result<()> {
-        debug!(key = %key, "removing secret");
-
-        if self.config.secrets.remove(key).is_none() {
-            let available: Vec<String> = self.config.secrets.keys().cloned().collect();
-            return Err(
-                SecretError::not_found_with_suggestions(key.to_string(), &available).into(),
-            );
+    pub fn get_many(&self, keys: &[&str]) -> Result<Vec<(String, Zeroizing<String>)>> {
+        let mut resolved = Vec::with_capacity(keys.len());
+        for key in keys {
+            let target = self.resolve_alias(key)?;
+            let encrypted = self.config.secrets.get(target).ok_or_else(|| {
+                let available: Vec<String> = self.config.secrets.keys().cloned().collect();
+                SecretError::not_found_with_suggestions(key.to_string(), &available)
+            })?;
+            resolved.push((*key, encrypted));
         }
-        self.config.save_to(self.vault_name.as_deref())?;
-        Ok(())
-    }
 
-    /// List all secrets.
-    pub fn list(&self) -> Vec<Secret> {
-        self.config
-            .secrets
-            .iter()
-            .map(|(key, value)| Secret::new(key.clone(), value.clone()))
-            .collect()
+        let pairs = resolved
+            .into_iter()
+            .map(|(key, encrypted)| {
+                let plaintext = self.backend.decrypt(encrypted, self.identity.as_age())?;
+                Ok((key.to_string(), Zeroizing::new(plaintext)))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        self.record("read", keys);
+        Ok(pairs)
     }
 
-    /// Decrypt all secrets
+    /// Get a decrypted secret, reusing a recent read from the local cache.
     ///
-    /// Returns vector of (key, plaintext_value) pairs with values in `Zeroizing` for secure cleanup.
+    /// Hits are served from `~/.dugout/cache` without touching the vault's
+    /// backend or KMS. Misses read through [`Vault::get`] and are cached for
+    /// `ttl`. Any change to the secret or the recipients is a miss.
     ///
     /// # Errors
     ///
-    /// Returns error if decryption of any secret fails.
+    /// Same as [`Vault::get`]. Failing to write the cache is not an error.
     #[instrument(skip(self))]
-    pub fn decrypt_all(&self) -> Result<Vec<(SecretKey, Zeroizing<String>)>> {
-        debug!(count = self.config.secrets.len(), "decrypting all secrets");
+    pub fn get_cached(&self, key: &str, ttl: std::time::Duration) -> Result<Zeroizing<String>> {
+        let target = self.resolve_alias(key)?;
+        let Some(encrypted) = self.config.secrets.get(target) else {
+            return self.get(key);
+        };
 
-        let mut pairs = Vec::new();
-        for (key, encrypted) in &self.config.secrets {
-            let plaintext = self.backend.decrypt(encrypted, self.identity.as_age())?;
-            pairs.push((key.clone(), Zeroizing::new(plaintext)));
+        let entry = cache::entry_name(&self.recipients_fingerprint(), target, encrypted);
+        if let Some(value) = cache::load(&self.project_id, &entry, &self.identity) {
+            debug!(key = %key, "read cache hit");
+            self.record("read", &[key]);
+            return Ok(value);
         }
 
-        Ok(pairs)
+        let value = self.get(key)?;
+        if let Err(e) = cache::store(&self.project_id, &entry, &value, &self.identity, ttl) {
+            debug!(error = %e, "failed to write read cache");
+        }
+        Ok(value)
     }
 
-    /// Re-encrypt all secrets for the current recipient set
+    /// Remove a secret or alias.
+    ///
+    /// # Arguments
     ///
-    /// Call this after adding or removing team members.
+    /// * `key` - Secret key or alias name
     ///
     /// # Errors
     ///
-    /// Returns error if decryption or re-encryption fails.
-    pub fn reencrypt_all(&mut self) -> Result<()> {
-        let recipients = get_recipients_as_strings(&self.config);
+    /// Returns `SecretError::NotFound` if the key doesn't exist.
+    /// Returns `SecretError::RemoveFailed` if aliases still point at the key.
+    #[instrument(skip(self))]
+    pub fn remove(&mut self, key: &str) -> Result<()> {
+        debug!(key = %key, "removing secret");
 
-        let mut updated = std::collections::BTreeMap::new();
-        for (key, encrypted) in &self.config.secrets {
-            // Use Zeroizing to ensure plaintext is wiped after re-encryption
-            let plaintext =
-                Zeroizing::new(self.backend.decrypt(encrypted, self.identity.as_age())?);
-            let reencrypted = self.backend.encrypt(&plaintext, &recipients)?;
-            updated.insert(key.clone(), reencrypted);
+        // Aliases can be targets too (A -> B -> SECRET)
+        let dependents = self.aliases_of(key);
+        if !dependents.is_empty() {
+            return Err(SecretError::RemoveFailed(format!(
+                "{} is the target of aliases: {}",
+                key,
+                dependents.join(", ")
+            ))
+            .into());
         }
 
-        self.config.secrets = updated;
-        self.update_recipients_hash();
-        self.config.save_to(self.vault_name.as_deref())?;
+        if self.config.aliases.remove(key).is_some() {
+            self.config.save_to(self.vault_name.as_deref())?;
+            self.record("rm", &[key]);
+            return Ok(());
+        }
 
+        if self.config.secrets.remove(key).is_none() {
+            let available: Vec<String> = self.config.secrets.keys().cloned().collect();
+            return Err(
+                SecretError::not_found_with_suggestions(key.to_string(), &available).into(),
+            );
+        }
+        self.config.descriptions.remove(key);
+        self.config.encrypted_descriptions.remove(key);
+        self.config.updated.remove(key);
+        self.config.save_to(self.vault_name.as_deref())?;
+        self.record("rm", &[key]);
         Ok(())
     }
 
-    // --- Team ---
-    /// Add a team member and re-encrypt all secrets for them
+    /// Rename a secret without re-encrypting it.
+    ///
+    /// The ciphertext, description, and timestamp move to the new key, and
+    /// aliases pointing at the old key are updated.
     ///
     /// # Errors
     ///
-    /// Returns `CipherError` if the public key is invalid.
-    /// Returns error if re-encryption fails.
-    #[instrument(skip(self, key))]
-    pub fn add_recipient(&mut self, name: &str, key: &str) -> Result<()> {
-        info!(name = %name, "adding team member");
-
-        validate_member_name(name)?;
+    /// Returns `ValidationError` if the new key is invalid.
+    /// Returns `SecretError::NotFound` if `old` doesn't exist.
+    /// Returns `SecretError::AlreadyExists` if `new` exists and `force` is false.
+    #[instrument(skip(self))]
+    pub fn rename(&mut self, old: &str, new: &str, force: bool) -> Result<()> {
+        debug!(old = %old, new = %new, force = force, "renaming secret");
 
-        // Validate the key format first - this will return a clear error if invalid
-        cipher::parse_recipient(key)?;
+        validate_key(new)?;
+        self.reject_alias(new)?;
 
-        self.config
-            .recipients
-            .insert(name.to_string(), key.to_string());
-        self.config.save_to(self.vault_name.as_deref())?;
+        if !self.config.secrets.contains_key(old) {
+            let available: Vec<String> = self.config.secrets.keys().cloned().collect();
+            return Err(
+                SecretError::not_found_with_suggestions(old.to_string(), &available).into(),
+            );
+        }
+        if old == new {
+            return Ok(());
+        }
+        if self.config.secrets.contains_key(new) && !force {
+            return Err(SecretError::AlreadyExists(new.to_string()).into());
+        }
 
-        // Re-encrypt all secrets for the new recipient set
-        if !self.config.secrets.is_empty() {
-            self.reencrypt_all()?;
+        if let Some(encrypted) = self.config.secrets.remove(old) {
+            self.config.secrets.insert(new.to_string(), encrypted);
+        }
+        self.config.descriptions.remove(new);
+        if let Some(desc) = self.config.descriptions.remove(old) {
+            self.config.descriptions.insert(new.to_string(), desc);
+        }
+        self.config.encrypted_descriptions.remove(new);
+        if let Some(desc) = self.config.encrypted_descriptions.remove(old) {
+            self.config
+                .encrypted_descriptions
+                .insert(new.to_string(), desc);
+        }
+        self.config.updated.remove(new);
+        if let Some(ts) = self.config.updated.remove(old) {
+            self.config.updated.insert(new.to_string(), ts);
+        }
+        for target in self.config.aliases.values_mut() {
+            if *target == old {
+                *target = new.to_string();
+            }
         }
 
+        self.config.save_to(self.vault_name.as_deref())?;
+        self.record("rename", &[old, new]);
         Ok(())
     }
 
-    /// Remove a team member and re-encrypt all secrets without them
+    /// Copy a secret to a new key without re-encrypting it.
+    ///
+    /// The ciphertext is already encrypted for this vault's recipients, so
+    /// it is stored under `dst` as-is, along with the description and
+    /// timestamp; `recipients_hash` is unchanged. `src` may be an alias.
     ///
     /// # Errors
     ///
-    /// Returns `ConfigError::RecipientNotFound` if the member doesn't exist.
-    /// Returns error if re-encryption fails.
+    /// Returns `ValidationError` if `dst` is invalid.
+    /// Returns `SecretError::NotFound` if `src` doesn't exist.
+    /// Returns `SecretError::AlreadyExists` if `dst` exists and `force` is false.
     #[instrument(skip(self))]
-    pub fn remove_recipient(&mut self, name: &str) -> Result<()> {
-        info!(name = %name, "removing team member");
+    pub fn copy(&mut self, src: &str, dst: &str, force: bool) -> Result<()> {
+        debug!(src = %src, dst = %dst, force = force, "copying secret");
 
-        if self.config.recipients.remove(name).is_none() {
-            return Err(ConfigError::RecipientNotFound(name.to_string()).into());
-        }
-        self.config.save_to(self.vault_name.as_deref())?;
+        validate_key(dst)?;
+        self.reject_alias(dst)?;
 
-        // Re-encrypt all secrets without the removed recipient
-        if !self.config.secrets.is_empty() {
-            self.reencrypt_all()?;
+        let src = self.resolve_alias(src)?.to_string();
+        let Some(encrypted) = self.config.secrets.get(&src).cloned() else {
+            let available: Vec<String> = self.config.secrets.keys().cloned().collect();
+            return Err(SecretError::not_found_with_suggestions(src, &available).into());
+        };
+        if self.config.secrets.contains_key(dst) && !force {
+            return Err(SecretError::AlreadyExists(dst.to_string()).into());
+        }
+        if src == dst {
+            return Ok(());
         }
 
+        self.config.secrets.insert(dst.to_string(), encrypted);
+        copy_entry(&mut self.config.descriptions, &src, dst);
+        copy_entry(&mut self.config.encrypted_descriptions, &src, dst);
+        copy_entry(&mut self.config.updated, &src, dst);
+
+        self.config.save_to(self.vault_name.as_deref())?;
+        self.record("copy", &[&src, dst]);
         Ok(())
     }
 
-    /// List all team members
-    pub fn recipients(&self) -> Vec<Recipient> {
-        list_recipients(&self.config)
-            .into_iter()
-            .filter_map(|(name, key)| Recipient::new(name, key).ok())
-            .collect()
+    /// Groups of secret keys that differ only by case.
+    pub fn case_duplicates(&self) -> Vec<Vec<String>> {
+        audit::case_duplicates(self.config.secrets.keys().map(String::as_str))
     }
 
-    /// Migrate legacy request files to per-vault directories.
+    /// Collapse secret keys that differ only by case down to one key each.
     ///
-    /// Moves files from `.dugout/requests/*.pub` to `.dugout/requests/default/*.pub`.
-    /// This is called automatically when listing pending requests.
-    fn migrate_legacy_requests() -> Result<()> {
-        let legacy_dir = std::path::Path::new(".dugout/requests");
-        let new_dir = constants::request_dir(None); // .dugout/requests/default
+    /// Each group's values are decrypted and compared, then `choose` is called
+    /// with the group and whether all values are identical, and returns the
+    /// key to keep. The other keys are removed (with their descriptions and
+    /// timestamps) and aliases pointing at them are moved to the kept key.
+    /// Nothing is written until every group has been decided.
+    ///
+    /// Returns each kept key with the keys removed in its favour.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CipherError` if a value can't be decrypted, any error from
+    /// `choose`, or `ValidationError` if `choose` returns a key outside the group.
+    #[instrument(skip(self, choose))]
+    pub fn dedup_case(
+        &mut self,
+        mut choose: impl FnMut(&[String], bool) -> Result<String>,
+    ) -> Result<Vec<(String, Vec<String>)>> {
+        let mut decisions = Vec::new();
+        for group in self.case_duplicates() {
+            let mut values = Vec::with_capacity(group.len());
+            for key in &group {
+                let plaintext = self
+                    .backend
+                    .decrypt(&self.config.secrets[key], self.identity.as_age())?;
+                values.push(Zeroizing::new(plaintext));
+            }
+            let identical = values.windows(2).all(|pair| pair[0] == pair[1]);
 
-        if !legacy_dir.exists() {
-            return Ok(());
+            let kept = choose(&group, identical)?;
+            if !group.contains(&kept) {
+                return Err(ValidationError::InvalidKey {
+                    key: kept,
+                    reason: format!("expected one of {}", group.join(", ")),
+                }
+                .into());
+            }
+            let dropped: Vec<String> = group.into_iter().filter(|key| *key != kept).collect();
+            decisions.push((kept, dropped));
+        }
+        if decisions.is_empty() {
+            return Ok(decisions);
         }
 
-        // Check for .pub files directly in .dugout/requests/ (legacy location)
-        let mut has_legacy_files = false;
-        for entry in std::fs::read_dir(legacy_dir)? {
-            let entry = entry?;
-            let path = entry.path();
-            if path.is_file() && path.extension().and_then(|s| s.to_str()) == Some("pub") {
+        for (kept, dropped) in &decisions {
+            for key in dropped {
+                self.config.secrets.remove(key);
+                self.config.descriptions.remove(key);
+                self.config.encrypted_descriptions.remove(key);
+                self.config.updated.remove(key);
+                for target in self.config.aliases.values_mut() {
+                    if target == key {
+                        *target = kept.clone();
+                    }
+                }
+            }
+        }
+        self.config.save_to(self.vault_name.as_deref())?;
+
+        let removed: Vec<&str> = decisions
+            .iter()
+            .flat_map(|(_, dropped)| dropped.iter().map(String::as_str))
+            .collect();
+        self.record("dedup", &removed);
+        Ok(decisions)
+    }
+
+    /// List all secrets.
+    pub fn list(&self) -> Vec<Secret> {
+        self.config
+            .secrets
+            .iter()
+            .map(|(key, value)| {
+                Secret::new(key.clone(), value.clone())
+                    .with_description(self.config.descriptions.get(key).cloned())
+            })
+            .collect()
+    }
+
+    /// Set or clear the description for an existing secret.
+    ///
+    /// Descriptions are stored in plaintext in the `[descriptions]` table,
+    /// or encrypted for the recipients in `[encrypted_descriptions]` when the
+    /// vault sets `encrypt_descriptions`. An empty description removes the entry.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SecretError::NotFound` if the key doesn't exist.
+    #[instrument(skip(self, description))]
+    pub fn set_description(&mut self, key: &str, description: &str) -> Result<()> {
+        if !self.config.secrets.contains_key(key) {
+            let available: Vec<String> = self.config.secrets.keys().cloned().collect();
+            return Err(
+                SecretError::not_found_with_suggestions(key.to_string(), &available).into(),
+            );
+        }
+
+        let description = description.trim();
+        self.config.descriptions.remove(key);
+        self.config.encrypted_descriptions.remove(key);
+        if self.config.dugout.encrypt_descriptions && !description.is_empty() {
+            let recipients = get_recipients_as_strings(&self.config);
+            if recipients.is_empty() {
+                return Err(ConfigError::NoRecipients.into());
+            }
+            let encrypted = self.backend.encrypt(description, &recipients)?;
+            self.config
+                .encrypted_descriptions
+                .insert(key.to_string(), encrypted);
+        } else if !description.is_empty() {
+            self.config
+                .descriptions
+                .insert(key.to_string(), description.to_string());
+        }
+        self.config.save_to(self.vault_name.as_deref())?;
+        self.record("describe", &[key]);
+        Ok(())
+    }
+
+    /// Plaintext description for a secret, if one has been set.
+    ///
+    /// Encrypted descriptions are not included; see [`Vault::decrypt_description`].
+    pub fn description(&self, key: &str) -> Option<&str> {
+        self.config.descriptions.get(key).map(|d| d.as_str())
+    }
+
+    /// Whether a secret has an encrypted description.
+    pub fn has_encrypted_description(&self, key: &str) -> bool {
+        self.config.encrypted_descriptions.contains_key(key)
+    }
+
+    /// Description for a secret, decrypting it if it is stored encrypted.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the encrypted description can't be decrypted.
+    pub fn decrypt_description(&self, key: &str) -> Result<Option<Zeroizing<String>>> {
+        if let Some(desc) = self.config.descriptions.get(key) {
+            return Ok(Some(Zeroizing::new(desc.clone())));
+        }
+        match self.config.encrypted_descriptions.get(key) {
+            Some(encrypted) => Ok(Some(Zeroizing::new(
+                self.backend.decrypt(encrypted, self.identity.as_age())?,
+            ))),
+            None => Ok(None),
+        }
+    }
+
+    /// Create or repoint an alias to another secret.
+    ///
+    /// Aliases are stored in the plaintext `[aliases]` table and resolve to
+    /// their target's value on `get`, `export`, and `run`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ValidationError` if the alias name is invalid.
+    /// Returns `SecretError::AlreadyExists` if a secret already has that name.
+    /// Returns `SecretError::NotFound` if the target doesn't exist.
+    /// Returns `SecretError::AliasCycle` if the alias would form a cycle.
+    #[instrument(skip(self))]
+    pub fn alias(&mut self, name: &str, target: &str) -> Result<()> {
+        validate_key(name)?;
+
+        if self.config.secrets.contains_key(name) {
+            return Err(SecretError::AlreadyExists(name.to_string()).into());
+        }
+        if !self.config.secrets.contains_key(target) && !self.config.aliases.contains_key(target) {
+            let available: Vec<String> = self.config.secrets.keys().cloned().collect();
+            return Err(
+                SecretError::not_found_with_suggestions(target.to_string(), &available).into(),
+            );
+        }
+
+        let previous = self
+            .config
+            .aliases
+            .insert(name.to_string(), target.to_string());
+        let resolved = self.resolve_alias(name).map(|_| ());
+        if let Err(e) = resolved {
+            match previous {
+                Some(previous) => self.config.aliases.insert(name.to_string(), previous),
+                None => self.config.aliases.remove(name),
+            };
+            return Err(e);
+        }
+
+        self.config.save_to(self.vault_name.as_deref())?;
+        self.record("alias", &[name, target]);
+        Ok(())
+    }
+
+    /// All aliases as (alias, target) pairs.
+    pub fn aliases(&self) -> Vec<(&str, &str)> {
+        self.config
+            .aliases
+            .iter()
+            .map(|(alias, target)| (alias.as_str(), target.as_str()))
+            .collect()
+    }
+
+    /// When a secret's plaintext was last changed.
+    ///
+    /// Returns `None` for secrets written before timestamps were tracked
+    /// or if the stored timestamp is malformed.
+    pub fn updated_at(&self, key: &str) -> Option<DateTime<Utc>> {
+        self.config
+            .updated
+            .get(key)
+            .and_then(|ts| DateTime::parse_from_rfc3339(ts).ok())
+            .map(|ts| ts.with_timezone(&Utc))
+    }
+
+    /// Decrypt all secrets
+    ///
+    /// Returns vector of (key, plaintext_value) pairs with values in `Zeroizing` for secure cleanup.
+    /// Aliases are included with their target's value.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if decryption of any secret fails.
+    #[instrument(skip(self))]
+    pub fn decrypt_all(&self) -> Result<Vec<(SecretKey, Zeroizing<String>)>> {
+        self.decrypt_where(|_| true)
+    }
+
+    /// Decrypt the secrets and aliases whose keys pass `keep`.
+    ///
+    /// Secrets are only decrypted if they are kept or an alias that is kept
+    /// points at them.
+    fn decrypt_where(
+        &self,
+        keep: impl Fn(&str) -> bool,
+    ) -> Result<Vec<(SecretKey, Zeroizing<String>)>> {
+        debug!(count = self.config.secrets.len(), "decrypting secrets");
+
+        let mut targets = Vec::new();
+        for alias in self.config.aliases.keys().filter(|alias| keep(alias)) {
+            targets.push((alias.clone(), self.resolve_alias(alias)?));
+        }
+
+        let mut decrypted = Vec::new();
+        for (key, encrypted) in &self.config.secrets {
+            if keep(key) || targets.iter().any(|(_, target)| target == key) {
+                let plaintext = self.backend.decrypt(encrypted, self.identity.as_age())?;
+                decrypted.push((key.clone(), Zeroizing::new(plaintext)));
+            }
+        }
+
+        let mut aliased = Vec::new();
+        for (alias, target) in targets {
+            let value = decrypted
+                .iter()
+                .find(|(key, _)| key == target)
+                .map(|(_, value)| value.clone())
+                .ok_or_else(|| {
+                    let available: Vec<String> = self.config.secrets.keys().cloned().collect();
+                    SecretError::not_found_with_suggestions(target.to_string(), &available)
+                })?;
+            aliased.push((alias, value));
+        }
+        let mut pairs: Vec<_> = decrypted.into_iter().filter(|(key, _)| keep(key)).collect();
+        pairs.extend(aliased);
+        pairs.sort_by(|a, b| a.0.cmp(&b.0));
+
+        let keys: Vec<&str> = pairs.iter().map(|(key, _)| key.as_str()).collect();
+        self.record("read", &keys);
+
+        Ok(pairs)
+    }
+
+    /// Check that every stored secret decrypts with the current identity
+    ///
+    /// Each key is paired with `None` if it decrypts, or the error it failed
+    /// with. Plaintext is zeroized immediately and never returned.
+    ///
+    /// # Errors
+    ///
+    /// Per-secret failures are collected rather than returned.
+    pub fn verify_all(&self) -> Result<Vec<(SecretKey, Option<Error>)>> {
+        debug!(count = self.config.secrets.len(), "verifying all secrets");
+
+        let results = self
+            .config
+            .secrets
+            .iter()
+            .map(|(key, encrypted)| {
+                let outcome = self
+                    .backend
+                    .decrypt(encrypted, self.identity.as_age())
+                    .map(Zeroizing::new);
+                (key.clone(), outcome.err())
+            })
+            .collect();
+
+        self.record("verify", &[]);
+        Ok(results)
+    }
+
+    /// Which recipients each secret is encrypted for
+    ///
+    /// A structural check of each secret's age header (the age half of
+    /// hybrid envelopes); nothing is decrypted. SSH stanzas name their key,
+    /// so SSH recipients are matched exactly. x25519 stanzas are anonymous:
+    /// your own key is confirmed by unwrapping the header, and the other
+    /// x25519 members are counted only when the secret has at least as many
+    /// x25519 stanzas as there are of them, since age doesn't reveal which
+    /// one is missing.
+    ///
+    /// Returns each secret with its covered members, both sorted.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CipherError` if a secret isn't valid age ciphertext or a
+    /// recipient key can't be parsed.
+    pub fn recipient_coverage(&self) -> Result<Vec<(SecretKey, Vec<MemberName>)>> {
+        let own_key = self.identity.public_key();
+        let mut ssh = Vec::new();
+        let mut others = Vec::new();
+        let mut own = None;
+        for (name, key) in &self.config.recipients {
+            let recipient = cipher::parse_recipient(key)?;
+            if let Some(tag) = recipient.ssh_tag() {
+                ssh.push((name, tag));
+            } else if key == &own_key {
+                own = Some(name);
+            } else {
+                others.push(name);
+            }
+        }
+
+        let mut coverage = Vec::with_capacity(self.config.secrets.len());
+        for (secret, encrypted) in &self.config.secrets {
+            let age_ct = cipher::Envelope::parse(encrypted)
+                .map(|envelope| envelope.age)
+                .unwrap_or_else(|| encrypted.clone());
+            let stanzas = cipher::header_stanzas(&age_ct)?;
+            let mut covered = Vec::new();
+
+            for (name, tag) in &ssh {
+                let targeted = stanzas
+                    .iter()
+                    .any(|s| s[0] == "ssh-ed25519" && s.get(1) == Some(tag));
+                if targeted {
+                    covered.push((*name).clone());
+                }
+            }
+
+            let mut x25519 = stanzas.iter().filter(|s| s[0] == "X25519").count();
+            if let Some(name) = own {
+                if cipher::is_recipient(&age_ct, self.identity.as_age()) {
+                    x25519 = x25519.saturating_sub(1);
+                    covered.push(name.clone());
+                }
+            }
+            if x25519 >= others.len() {
+                covered.extend(others.iter().map(|name| (*name).clone()));
+            }
+
+            covered.sort();
+            coverage.push((secret.clone(), covered));
+        }
+
+        Ok(coverage)
+    }
+
+    /// Find recipients that secrets were not encrypted for
+    ///
+    /// The inverse of [`Vault::recipient_coverage`], catching partial syncs.
+    /// When a secret has fewer x25519 stanzas than the other x25519
+    /// members, all of them are reported for it.
+    ///
+    /// Returns each affected member with the secrets they can't decrypt.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CipherError` if a secret isn't valid age ciphertext or a
+    /// recipient key can't be parsed.
+    pub fn verify_recipients(&self) -> Result<Vec<(MemberName, Vec<SecretKey>)>> {
+        let mut missing: std::collections::BTreeMap<MemberName, Vec<SecretKey>> =
+            std::collections::BTreeMap::new();
+        for (secret, covered) in self.recipient_coverage()? {
+            for name in self.config.recipients.keys() {
+                if !covered.contains(name) {
+                    missing
+                        .entry(name.clone())
+                        .or_default()
+                        .push(secret.clone());
+                }
+            }
+        }
+
+        debug!(members = missing.len(), "verified recipients");
+        Ok(missing.into_iter().collect())
+    }
+
+    /// Report re-encryption progress to `progress` as `(done, total)`
+    ///
+    /// Used by every operation that re-encrypts the vault (`sync`, team
+    /// changes, key rotation), so callers can show progress without
+    /// threading a callback through each of them.
+    pub fn set_progress(&mut self, progress: impl FnMut(usize, usize) + Send + 'static) {
+        self.progress = Some(Box::new(progress));
+    }
+
+    /// Re-encrypt all secrets for the current recipient set
+    ///
+    /// Call this after adding or removing team members. In hybrid vaults the
+    /// KMS ciphertext is reused unless the KMS key itself changed. Progress
+    /// goes to the callback from [`Vault::set_progress`], if any.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if decryption or re-encryption fails.
+    pub fn reencrypt_all(&mut self) -> Result<()> {
+        let mut progress = self.progress.take();
+        let result = self.reencrypt_all_with_progress(|done, total| {
+            if let Some(progress) = progress.as_mut() {
+                progress(done, total);
+            }
+        });
+        self.progress = progress;
+        result
+    }
+
+    /// Re-encrypt all secrets, calling `progress(done, total)` as each finishes
+    ///
+    /// `progress` runs on the calling thread, even when workers re-encrypt
+    /// in parallel.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if decryption or re-encryption fails.
+    pub fn reencrypt_all_with_progress(
+        &mut self,
+        mut progress: impl FnMut(usize, usize),
+    ) -> Result<()> {
+        let recipients = get_recipients_as_strings(&self.config);
+        let entries: Vec<(&String, &String)> = self.config.secrets.iter().collect();
+
+        let workers = if entries.len() < PARALLEL_REENCRYPT_MIN {
+            1
+        } else {
+            std::thread::available_parallelism()
+                .map(std::num::NonZeroUsize::get)
+                .unwrap_or(1)
+        };
+        let chunk_size = ((entries.len() + workers - 1) / workers).max(1);
+        debug!(count = entries.len(), workers, "re-encrypting secrets");
+
+        // Workers stop early once any of them fails; the first error wins
+        let failed = std::sync::atomic::AtomicBool::new(false);
+        let reencrypt_chunk = |chunk: &[(&String, &String)],
+                               tick: &mut dyn FnMut()|
+         -> Result<Vec<(String, String)>> {
+            let mut out = Vec::with_capacity(chunk.len());
+            for (key, encrypted) in chunk {
+                if failed.load(std::sync::atomic::Ordering::Relaxed) {
+                    break;
+                }
+                // Use Zeroizing to ensure plaintext is wiped after re-encryption
+                let result = self
+                    .backend
+                    .decrypt(encrypted, self.identity.as_age())
+                    .map(Zeroizing::new)
+                    .and_then(|plaintext| {
+                        self.backend.reencrypt(encrypted, &plaintext, &recipients)
+                    });
+                match result {
+                    Ok(reencrypted) => out.push(((*key).clone(), reencrypted)),
+                    Err(e) => {
+                        failed.store(true, std::sync::atomic::Ordering::Relaxed);
+                        return Err(e);
+                    }
+                }
+                tick();
+            }
+            Ok(out)
+        };
+
+        let total = entries.len();
+        let mut done = 0;
+        let results: Vec<Result<Vec<(String, String)>>> = if workers == 1 {
+            vec![reencrypt_chunk(&entries, &mut || {
+                done += 1;
+                progress(done, total);
+            })]
+        } else {
+            // Workers report each secret over a channel; progress is called here
+            let (tx, rx) = std::sync::mpsc::channel();
+            let reencrypt_chunk = &reencrypt_chunk;
+            std::thread::scope(|scope| {
+                let handles: Vec<_> = entries
+                    .chunks(chunk_size)
+                    .map(|chunk| {
+                        let tx = tx.clone();
+                        scope.spawn(move || {
+                            reencrypt_chunk(chunk, &mut || {
+                                let _ = tx.send(());
+                            })
+                        })
+                    })
+                    .collect();
+                drop(tx);
+                for () in rx {
+                    done += 1;
+                    progress(done, total);
+                }
+                handles
+                    .into_iter()
+                    .map(|handle| {
+                        handle
+                            .join()
+                            .unwrap_or_else(|panic| std::panic::resume_unwind(panic))
+                    })
+                    .collect()
+            })
+        };
+
+        let mut updated = std::collections::BTreeMap::new();
+        for chunk in results {
+            updated.extend(chunk?);
+        }
+
+        self.config.secrets = updated;
+        self.reencrypt_descriptions(&recipients)?;
+        self.update_recipients_hash();
+        self.config.dugout.last_synced_backend = Some(self.backend.name().to_string());
+        self.config.save_to(self.vault_name.as_deref())?;
+        self.record("reencrypt", &[]);
+
+        Ok(())
+    }
+
+    /// Re-encrypt encrypted descriptions for `recipients`.
+    ///
+    /// Descriptions are short and few, so this runs on the calling thread.
+    fn reencrypt_descriptions(&mut self, recipients: &[String]) -> Result<()> {
+        let mut updated = std::collections::BTreeMap::new();
+        for (key, encrypted) in &self.config.encrypted_descriptions {
+            let plaintext =
+                Zeroizing::new(self.backend.decrypt(encrypted, self.identity.as_age())?);
+            let reencrypted = self.backend.reencrypt(encrypted, &plaintext, recipients)?;
+            updated.insert(key.clone(), reencrypted);
+        }
+        self.config.encrypted_descriptions = updated;
+        Ok(())
+    }
+
+    /// Rotate the project keypair and re-encrypt all secrets for it
+    ///
+    /// The old private key is archived next to the new one, the recipient
+    /// entry holding the current identity's public key is replaced, and all
+    /// secrets are re-encrypted for the updated recipient set.
+    ///
+    /// # Errors
+    ///
+    /// Returns `StoreError::NoPrivateKey` if there is no project key to rotate.
+    /// Returns `ConfigError::NotRecipient` if the current identity isn't a recipient.
+    /// Returns error if decryption or re-encryption fails.
+    #[instrument(skip(self))]
+    pub fn rotate_keypair(&mut self) -> Result<()> {
+        info!("rotating project keypair");
+
+        if !store::has_key(&self.project_id) {
+            return Err(StoreError::NoPrivateKey(self.project_id.clone()).into());
+        }
+
+        let old_public_key = self.identity.public_key();
+        let owner = self
+            .config
+            .recipients
+            .iter()
+            .find(|(_, key)| **key == old_public_key)
+            .map(|(name, _)| name.clone())
+            .ok_or(ConfigError::NotRecipient)?;
+
+        // Decrypt everything before the old key is moved out of the way
+        let mut decrypted: Vec<(String, Zeroizing<String>)> = Vec::new();
+        for (key, encrypted) in &self.config.secrets {
+            let plaintext = self.backend.decrypt(encrypted, self.identity.as_age())?;
+            decrypted.push((key.clone(), Zeroizing::new(plaintext)));
+        }
+        let mut descriptions: Vec<(String, Zeroizing<String>)> = Vec::new();
+        for (key, encrypted) in &self.config.encrypted_descriptions {
+            let plaintext = self.backend.decrypt(encrypted, self.identity.as_age())?;
+            descriptions.push((key.clone(), Zeroizing::new(plaintext)));
+        }
+
+        archive_key(&self.project_id)?;
+        let new_public_key = store::generate_keypair(&self.project_id)?;
+        self.identity = store::load_identity(&self.project_id)?;
+
+        self.config.recipients.insert(owner, new_public_key);
+        let recipients = get_recipients_as_strings(&self.config);
+
+        for (done, (key, plaintext)) in decrypted.iter().enumerate() {
+            let encrypted = &self.config.secrets[key];
+            let reencrypted = self.backend.reencrypt(encrypted, plaintext, &recipients)?;
+            self.config.secrets.insert(key.clone(), reencrypted);
+            if let Some(progress) = self.progress.as_mut() {
+                progress(done + 1, decrypted.len());
+            }
+        }
+        for (key, plaintext) in &descriptions {
+            let encrypted = &self.config.encrypted_descriptions[key];
+            let reencrypted = self.backend.reencrypt(encrypted, plaintext, &recipients)?;
+            self.config
+                .encrypted_descriptions
+                .insert(key.clone(), reencrypted);
+        }
+
+        self.update_recipients_hash();
+        self.config.dugout.last_synced_backend = Some(self.backend.name().to_string());
+        self.config.save_to(self.vault_name.as_deref())?;
+        self.record("rotate", &[]);
+
+        Ok(())
+    }
+
+    // --- Team ---
+    /// Add a team member and re-encrypt all secrets for them
+    ///
+    /// # Errors
+    ///
+    /// Returns `CipherError` if the public key is invalid.
+    /// Returns error if re-encryption fails.
+    #[instrument(skip(self, key))]
+    pub fn add_recipient(&mut self, name: &str, key: &str) -> Result<()> {
+        self.insert_recipient(name, key, None)
+    }
+
+    /// Add a team member whose access ends at `expires`
+    ///
+    /// Once expired, the member is left out of every re-encryption (the next
+    /// `sync` drops their access) and `sync --prune-expired` removes them.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ConfigError::InvalidValue` if `expires` is in the past.
+    /// Returns `CipherError` if the public key is invalid.
+    /// Returns error if re-encryption fails.
+    #[instrument(skip(self, key))]
+    pub fn add_recipient_with_expiry(
+        &mut self,
+        name: &str,
+        key: &str,
+        expires: DateTime<Utc>,
+    ) -> Result<()> {
+        if expires <= Utc::now() {
+            return Err(ConfigError::InvalidValue {
+                field: "expires",
+                reason: format!("{} is in the past", expires.to_rfc3339()),
+            }
+            .into());
+        }
+        self.insert_recipient(name, key, Some(expires))
+    }
+
+    /// Add or replace a recipient, replacing any previous expiry.
+    fn insert_recipient(
+        &mut self,
+        name: &str,
+        key: &str,
+        expires: Option<DateTime<Utc>>,
+    ) -> Result<()> {
+        info!(name = %name, "adding team member");
+
+        validate_member_name(name)?;
+
+        // Validate the key format first - this will return a clear error if invalid.
+        // Store the canonical form so SSH key comments don't leak into the vault.
+        let key = cipher::parse_recipient(key)?.to_string();
+
+        self.config.recipients.insert(name.to_string(), key);
+        match expires {
+            Some(expires) => self
+                .config
+                .recipient_expiry
+                .insert(name.to_string(), expires.to_rfc3339()),
+            None => self.config.recipient_expiry.remove(name),
+        };
+        self.config.save_to(self.vault_name.as_deref())?;
+        self.record("team.add", &[name]);
+
+        // Re-encrypt all secrets for the new recipient set
+        if !self.config.secrets.is_empty() {
+            self.reencrypt_all()?;
+        }
+
+        Ok(())
+    }
+
+    /// Remove a team member and re-encrypt all secrets without them
+    ///
+    /// # Errors
+    ///
+    /// Returns `ConfigError::RecipientNotFound` if the member doesn't exist.
+    /// Returns error if re-encryption fails.
+    #[instrument(skip(self))]
+    pub fn remove_recipient(&mut self, name: &str) -> Result<()> {
+        info!(name = %name, "removing team member");
+
+        if self.config.recipients.remove(name).is_none() {
+            return Err(ConfigError::RecipientNotFound(name.to_string()).into());
+        }
+        self.config.recipient_expiry.remove(name);
+        self.config.recipient_notes.remove(name);
+        self.config.roles.remove(name);
+        self.config.save_to(self.vault_name.as_deref())?;
+        self.record("team.rm", &[name]);
+
+        // Re-encrypt all secrets without the removed recipient
+        if !self.config.secrets.is_empty() {
+            self.reencrypt_all()?;
+        }
+
+        Ok(())
+    }
+
+    /// Add a roster of team members, re-encrypting once at the end.
+    ///
+    /// Every name and key is validated before anything changes. Merging keeps
+    /// current members and refuses a name whose key differs; replacing makes
+    /// the roster the whole team, but never drops the current identity.
+    /// Returns the names added and removed.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ConfigError::NoRecipients` if the roster is empty.
+    /// Returns `CipherError` if a public key is invalid.
+    /// Returns `ConfigError::Other` on a key conflict or if replacing would
+    /// remove you.
+    /// Returns error if re-encryption fails.
+    #[instrument(skip(self, roster))]
+    pub fn import_recipients(
+        &mut self,
+        roster: &std::collections::BTreeMap<MemberName, PublicKey>,
+        replace: bool,
+    ) -> Result<(Vec<MemberName>, Vec<MemberName>)> {
+        if roster.is_empty() {
+            return Err(ConfigError::NoRecipients.into());
+        }
+
+        let mut incoming = Vec::with_capacity(roster.len());
+        for (name, key) in roster {
+            validate_member_name(name)?;
+            let key = cipher::parse_recipient(key)?.to_string();
+            if !replace {
+                if let Some(existing) = self.config.recipients.get(name) {
+                    if *existing != key {
+                        return Err(ConfigError::Other(format!(
+                            "recipient '{}' has a different key here\n  → Use --replace to take the imported one",
+                            name
+                        ))
+                        .into());
+                    }
+                }
+            }
+            incoming.push((name.clone(), key));
+        }
+
+        let removed: Vec<MemberName> = if replace {
+            self.config
+                .recipients
+                .keys()
+                .filter(|name| !roster.contains_key(*name))
+                .cloned()
+                .collect()
+        } else {
+            Vec::new()
+        };
+        let my_key = self.identity.public_key();
+        if replace
+            && self.current_member().is_some()
+            && !incoming.iter().any(|(_, key)| *key == my_key)
+        {
+            return Err(ConfigError::Other(
+                "the imported roster doesn't include your key; refusing to remove your access"
+                    .to_string(),
+            )
+            .into());
+        }
+
+        let added: Vec<MemberName> = incoming
+            .iter()
+            .filter(|(name, key)| self.config.recipients.get(name) != Some(key))
+            .map(|(name, _)| name.clone())
+            .collect();
+        for name in &removed {
+            self.config.recipients.remove(name);
+            self.config.recipient_expiry.remove(name);
+            self.config.recipient_notes.remove(name);
+            self.config.roles.remove(name);
+        }
+        for name in &added {
+            self.config.recipient_expiry.remove(name);
+        }
+        self.config.recipients.extend(incoming);
+
+        info!(
+            added = added.len(),
+            removed = removed.len(),
+            "imported team roster"
+        );
+        self.config.save_to(self.vault_name.as_deref())?;
+        let names: Vec<&str> = added.iter().chain(&removed).map(String::as_str).collect();
+        self.record("team.import", &names);
+
+        let roster_changed = !added.is_empty() || !removed.is_empty();
+        if roster_changed && !self.config.secrets.is_empty() {
+            self.reencrypt_all()?;
+        }
+
+        Ok((added, removed))
+    }
+
+    /// The note attached to a team member, if any.
+    pub fn recipient_note(&self, name: &str) -> Option<&str> {
+        self.config.recipient_notes.get(name).map(String::as_str)
+    }
+
+    /// Attach a note to a team member; an empty note removes it.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ConfigError::RecipientNotFound` if the member doesn't exist.
+    /// Returns error if the config can't be saved.
+    #[instrument(skip(self, note))]
+    pub fn set_recipient_note(&mut self, name: &str, note: &str) -> Result<()> {
+        if !self.config.recipients.contains_key(name) {
+            return Err(ConfigError::RecipientNotFound(name.to_string()).into());
+        }
+        let note = note.trim();
+        if note.is_empty() {
+            self.config.recipient_notes.remove(name);
+        } else {
+            self.config
+                .recipient_notes
+                .insert(name.to_string(), note.to_string());
+        }
+        self.config.save_to(self.vault_name.as_deref())?;
+        Ok(())
+    }
+
+    /// A team member's role (`member` unless set otherwise).
+    pub fn role_of(&self, name: &str) -> Role {
+        self.config.roles.get(name).copied().unwrap_or_default()
+    }
+
+    /// Set a team member's role.
+    ///
+    /// Roles are metadata only; they don't change who secrets are encrypted for.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ConfigError::RecipientNotFound` if the member doesn't exist.
+    /// Returns error if the config can't be saved.
+    #[instrument(skip(self))]
+    pub fn set_role(&mut self, name: &str, role: Role) -> Result<()> {
+        if !self.config.recipients.contains_key(name) {
+            return Err(ConfigError::RecipientNotFound(name.to_string()).into());
+        }
+        match role {
+            Role::Member => self.config.roles.remove(name),
+            role => self.config.roles.insert(name.to_string(), role),
+        };
+        self.config.save_to(self.vault_name.as_deref())?;
+        self.record("team.role", &[name]);
+        Ok(())
+    }
+
+    /// The team member holding the current identity's public key.
+    pub fn current_member(&self) -> Option<&str> {
+        let public_key = self.identity.public_key();
+        self.config
+            .recipients
+            .iter()
+            .find(|(_, key)| **key == public_key)
+            .map(|(name, _)| name.as_str())
+    }
+
+    /// Whether the current identity may admit new members.
+    ///
+    /// Vaults without any admin (created before roles existed) let everyone admit.
+    pub fn can_admit(&self) -> bool {
+        !self.config.roles.values().any(|role| *role == Role::Admin)
+            || self
+                .current_member()
+                .is_some_and(|name| self.role_of(name) == Role::Admin)
+    }
+
+    /// When a team member's access expires, if it does.
+    pub fn recipient_expiry(&self, name: &str) -> Option<DateTime<Utc>> {
+        self.config.recipient_expiry(name)
+    }
+
+    /// Team members whose expiry has passed.
+    pub fn expired_recipients(&self) -> Vec<MemberName> {
+        self.config
+            .recipients
+            .keys()
+            .filter(|name| self.config.is_expired(name))
+            .cloned()
+            .collect()
+    }
+
+    /// Remove team members whose expiry has passed.
+    ///
+    /// Only the config changes; expired members are already left out of
+    /// encryption, so follow with `sync` to drop them from existing secrets.
+    /// Returns the removed names.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the config can't be saved.
+    #[instrument(skip(self))]
+    pub fn prune_expired(&mut self) -> Result<Vec<MemberName>> {
+        let expired = self.expired_recipients();
+        if expired.is_empty() {
+            return Ok(expired);
+        }
+
+        info!(count = expired.len(), "pruning expired team members");
+        for name in &expired {
+            self.config.recipients.remove(name);
+            self.config.recipient_expiry.remove(name);
+            self.config.recipient_notes.remove(name);
+            self.config.roles.remove(name);
+        }
+        self.config.save_to(self.vault_name.as_deref())?;
+        let names: Vec<&str> = expired.iter().map(String::as_str).collect();
+        self.record("team.prune", &names);
+        Ok(expired)
+    }
+
+    /// List all team members
+    pub fn recipients(&self) -> Vec<Recipient> {
+        list_recipients(&self.config)
+            .into_iter()
+            .filter_map(|(name, key)| Recipient::new(name, key).ok())
+            .collect()
+    }
+
+    /// Migrate legacy request files to per-vault directories.
+    ///
+    /// Moves files from `.dugout/requests/*.pub` to `.dugout/requests/default/*.pub`.
+    /// This is called automatically when listing pending requests.
+    fn migrate_legacy_requests() -> Result<()> {
+        let legacy_dir = std::path::Path::new(".dugout/requests");
+        let new_dir = constants::request_dir(None); // .dugout/requests/default
+
+        if !legacy_dir.exists() {
+            return Ok(());
+        }
+
+        // Check for .pub files directly in .dugout/requests/ (legacy location)
+        let mut has_legacy_files = false;
+        for entry in std::fs::read_dir(legacy_dir)? {
+            let entry = entry?;
+            let path = entry.path();
+            if path.is_file() && path.extension().and_then(|s| s.to_str()) == Some("pub") {
                 has_legacy_files = true;
                 break;
             }
@@ -456,12 +1938,13 @@ impl Vault {
 
     /// List pending access requests
     ///
-    /// Returns a vector of (name, public_key) pairs from `.dugout/requests/` directory.
+    /// Reads each request in `.dugout/requests/`, sorted by name. Files that
+    /// can't be parsed are skipped with a warning; `admit` reports why.
     ///
     /// # Errors
     ///
     /// Returns error if the directory cannot be read.
-    pub fn pending_requests(&self) -> Result<Vec<(String, String)>> {
+    pub fn pending_requests(&self) -> Result<Vec<AccessRequest>> {
         // Migrate legacy requests on first access
         Self::migrate_legacy_requests()?;
 
@@ -483,32 +1966,33 @@ impl Vault {
                     .unwrap_or("unknown")
                     .to_string();
 
-                let pubkey = std::fs::read_to_string(&path)?.trim().to_string();
-
-                requests.push((name, pubkey));
+                match AccessRequest::parse(
+                    self.vault_name.as_deref(),
+                    &name,
+                    &std::fs::read_to_string(&path)?,
+                ) {
+                    Ok(request) => requests.push(request),
+                    Err(e) => warn!(name = %name, error = %e, "skipping malformed request"),
+                }
             }
         }
 
+        requests.sort_by(|a, b| a.name().cmp(b.name()));
         Ok(requests)
     }
 
-    /// Admit a team member from a pending request
-    ///
-    /// Reads the request file, adds the recipient, deletes the request file,
-    /// and re-encrypts all secrets for the new team.
+    /// `name`'s pending request
     ///
     /// # Errors
     ///
-    /// Returns error if the request file doesn't exist or operations fail.
-    #[instrument(skip(self))]
-    pub fn admit(&mut self, name: &str) -> Result<()> {
-        info!(name = %name, "admitting team member from request");
-
+    /// Returns `ConfigError::RecipientNotFound` if there is no such request.
+    /// Returns `ValidationError::InvalidRequest` if the file is malformed.
+    /// Returns error if the name is invalid or the file can't be read.
+    pub fn pending_request(&self, name: &str) -> Result<AccessRequest> {
         validate_member_name(name)?;
 
         let request_path =
             constants::request_dir(self.vault_name.as_deref()).join(format!("{}.pub", name));
-
         if !request_path.exists() {
             return Err(ConfigError::RecipientNotFound(format!(
                 "no pending request from '{}'",
@@ -517,10 +2001,36 @@ impl Vault {
             .into());
         }
 
-        let pubkey = std::fs::read_to_string(&request_path)?.trim().to_string();
+        AccessRequest::parse(
+            self.vault_name.as_deref(),
+            name,
+            &std::fs::read_to_string(&request_path)?,
+        )
+    }
+
+    /// Admit a team member from a pending request
+    ///
+    /// Reads the request file, checks its signature, adds the recipient,
+    /// deletes the request file, and re-encrypts all secrets for the new
+    /// team. A bad signature is always rejected; with `verify`, so is a
+    /// missing one.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ValidationError::InvalidRequest` if the request fails
+    /// verification.
+    /// Returns error if the request file doesn't exist or operations fail.
+    #[instrument(skip(self))]
+    pub fn admit(&mut self, name: &str, verify: bool) -> Result<()> {
+        info!(name = %name, "admitting team member from request");
+
+        let request = self.pending_request(name)?;
+        request.verify(verify)?;
+        let request_path =
+            constants::request_dir(self.vault_name.as_deref()).join(format!("{}.pub", name));
 
         // Add the recipient
-        self.add_recipient(name, &pubkey)?;
+        self.add_recipient(name, request.public_key())?;
 
         // Delete the request file
         std::fs::remove_file(&request_path)?;
@@ -529,27 +2039,204 @@ impl Vault {
         Ok(())
     }
 
+    /// Admit everyone with a pending request, re-encrypting once
+    ///
+    /// All request files are checked (as in [`Vault::admit`]) before the
+    /// team changes; they're deleted only after the re-encryption succeeds.
+    /// Returns the admitted names, sorted.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ValidationError` or `CipherError` if any request has an
+    /// invalid name, key or signature.
+    /// Returns error if re-encryption or file operations fail.
+    #[instrument(skip(self))]
+    pub fn admit_all(&mut self, verify: bool) -> Result<Vec<MemberName>> {
+        let requests = self.pending_requests()?;
+
+        let mut admitted = Vec::with_capacity(requests.len());
+        for request in &requests {
+            validate_member_name(request.name())?;
+            request.verify(verify)?;
+            admitted.push((
+                request.name().to_string(),
+                cipher::parse_recipient(request.public_key())?.to_string(),
+            ));
+        }
+        if admitted.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        info!(count = admitted.len(), "admitting pending requests");
+        for (name, key) in &admitted {
+            self.config.recipients.insert(name.clone(), key.clone());
+            self.config.recipient_expiry.remove(name);
+        }
+        self.config.save_to(self.vault_name.as_deref())?;
+        let names: Vec<&str> = admitted.iter().map(|(name, _)| name.as_str()).collect();
+        self.record("team.add", &names);
+
+        if !self.config.secrets.is_empty() {
+            self.reencrypt_all()?;
+        }
+
+        let request_dir = constants::request_dir(self.vault_name.as_deref());
+        for (name, _) in &admitted {
+            std::fs::remove_file(request_dir.join(format!("{}.pub", name)))?;
+        }
+
+        Ok(admitted.into_iter().map(|(name, _)| name).collect())
+    }
+
     // --- Lifecycle ---
     /// Import secrets from .env file
     ///
-    /// Reads key=value pairs from the file and encrypts them.
-    /// Returns vector of imported secret keys.
+    /// Reads key=value pairs from the file and encrypts them. Keys the vault
+    /// already has are handled by `on_conflict`.
     ///
     /// # Errors
     ///
     /// Returns error if file cannot be read or secrets cannot be encrypted.
+    /// Returns `SecretError::ImportConflict` if a key exists and
+    /// `on_conflict` is `OnConflict::Error`.
     #[instrument(skip(self, path))]
-    pub fn import(&mut self, path: impl AsRef<std::path::Path>) -> Result<Vec<SecretKey>> {
-        let path_str = path.as_ref().display().to_string();
-        info!(path = %path_str, "importing secrets");
+    pub fn import(
+        &mut self,
+        path: impl AsRef<std::path::Path>,
+        on_conflict: OnConflict,
+    ) -> Result<ImportResult> {
+        self.import_with(
+            path,
+            &ImportOptions {
+                on_conflict,
+                ..Default::default()
+            },
+        )
+    }
+
+    /// Import secrets from a .env file, filtering and renaming keys.
+    ///
+    /// With a cloud `opts.format`, the file is parsed as that secret
+    /// manager's JSON output instead (see [`ImportFormat::parse_json`]).
+    /// Entries that don't match `opts.prefix` are skipped. With
+    /// `opts.strip_prefix`, the prefix is removed and the remaining name is
+    /// validated as a key in its own right. With `opts.expand`, variable
+    /// references are resolved against the whole file before filtering.
+    /// A key that appears more than once keeps its last value, with a
+    /// warning, or fails the import with `opts.strict`. Keys the vault
+    /// already has are handled by `opts.on_conflict`; with
+    /// `OnConflict::Error`, nothing is written if any exist.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`Vault::import`].
+    pub fn import_with(
+        &mut self,
+        path: impl AsRef<std::path::Path>,
+        opts: &ImportOptions,
+    ) -> Result<ImportResult> {
+        let path = path.as_ref();
+        let file = std::fs::File::open(path)?;
+        self.import_from_reader(std::io::BufReader::new(file), path, opts)
+    }
+
+    /// Import secrets from a stream, such as stdin, as [`Vault::import_with`]
+    /// would from a file.
+    ///
+    /// `source` names the stream in logs and parsed `Env`s.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`Vault::import`].
+    pub fn import_from_reader(
+        &mut self,
+        mut reader: impl std::io::BufRead,
+        source: &std::path::Path,
+        opts: &ImportOptions,
+    ) -> Result<ImportResult> {
+        info!(path = %source.display(), prefix = ?opts.prefix, "importing secrets");
+
+        let path = source.to_path_buf();
+        let env = match opts.format {
+            ImportFormat::Dotenv if opts.expand => {
+                Env::load_expanded_from_reader(reader, path, opts.allow_undefined)?
+            }
+            ImportFormat::Dotenv => Env::load_from_reader(reader, path)?,
+            ImportFormat::EnvVault => {
+                let uri = std::env::var(constants::DOTENV_KEY_ENV).map_err(|_| {
+                    Error::Other(format!(
+                        "{} is not set\n  → Export the key printed by `dugout secrets export --format env-vault`",
+                        constants::DOTENV_KEY_ENV
+                    ))
+                })?;
+                let key = cipher::env_vault::DotenvKey::parse(&uri)?;
+                let file = Env::load_from_reader(reader, path.clone())?;
+                let dotenv = cipher::env_vault::open(file.entries(), &key)?;
+                Env::from_contents(&dotenv, path)?
+            }
+            format => {
+                let mut content = String::new();
+                reader.read_to_string(&mut content)?;
+                Env::from_pairs(format.parse_json(&content)?, path)
+            }
+        };
+
+        let duplicates: Vec<String> = env
+            .duplicate_keys()
+            .into_iter()
+            .filter(|(key, _)| opts.map_key(key).is_some())
+            .map(|(key, lines)| {
+                let lines: Vec<String> = lines.iter().map(|l| l.to_string()).collect();
+                format!("{} (lines {})", key, lines.join(", "))
+            })
+            .collect();
+        if !duplicates.is_empty() {
+            let listed = duplicates.join("; ");
+            if opts.strict {
+                return Err(ValidationError::DuplicateKeys(listed).into());
+            }
+            warn!(keys = %listed, "duplicate keys, keeping the last value of each");
+        }
+        // Keys the vault had before this import, in file order
+        let mut existing: Vec<&str> = Vec::new();
+        for (key, _) in env.entries() {
+            if let Some(key) = opts.map_key(key) {
+                if self.config.secrets.contains_key(key)
+                    && !self.config.aliases.contains_key(key)
+                    && !existing.contains(&key)
+                {
+                    existing.push(key);
+                }
+            }
+        }
+        if opts.on_conflict == OnConflict::Error && !existing.is_empty() {
+            return Err(SecretError::ImportConflict(existing.join(", ")).into());
+        }
 
-        let env = Env::load(path)?;
-        let mut imported = Vec::new();
+        let mut result = ImportResult::default();
 
         for (key, value) in env.entries() {
+            let Some(key) = opts.map_key(key) else {
+                continue;
+            };
+
+            // Aliases are written out by export/unlock; they follow their target
+            if self.config.aliases.contains_key(key) {
+                continue;
+            }
+
+            let existed = existing.contains(&key);
+            if existed && opts.on_conflict == OnConflict::Skip {
+                if !result.skipped.iter().any(|k| k == key) {
+                    result.skipped.push(key.to_string());
+                }
+                continue;
+            }
+
             // Validate input
             validate_key(key)?;
             validate_value(key, value)?;
+            validate_pattern(&self.config, key, value)?;
 
             let recipients = get_recipients_as_strings(&self.config);
             if recipients.is_empty() {
@@ -557,14 +2244,32 @@ impl Vault {
             }
 
             let encrypted = self.backend.encrypt(value, &recipients)?;
-            self.config.secrets.insert(key.clone(), encrypted);
-            imported.push(key.clone());
+            self.config.secrets.insert(key.to_string(), encrypted);
+            self.touch(key);
+            let written = if existed {
+                &mut result.overwritten
+            } else {
+                &mut result.created
+            };
+            if !written.iter().any(|k| k == key) {
+                written.push(key.to_string());
+            }
         }
 
         self.update_recipients_hash();
         self.config.save_to(self.vault_name.as_deref())?;
-        debug!(count = imported.len(), "import complete");
-        Ok(imported)
+        let keys: Vec<&str> = result
+            .created
+            .iter()
+            .chain(&result.overwritten)
+            .map(String::as_str)
+            .collect();
+        self.record("import", &keys);
+        if !keys.is_empty() {
+            self.run_hook("post_set", &keys);
+        }
+        debug!(count = keys.len(), "import complete");
+        Ok(result)
     }
 
     /// Export all decrypted secrets as .env format
@@ -575,19 +2280,36 @@ impl Vault {
     #[instrument(skip(self))]
     pub fn export(&self) -> Result<Env> {
         info!("exporting secrets as env");
+        Ok(Self::to_env(self.decrypt_all()?))
+    }
 
-        let pairs = self
-            .decrypt_all()?
-            .into_iter()
-            .map(|(k, v)| (k, v.to_string()))
-            .collect();
+    /// Export the secrets whose keys match a glob pattern as .env format
+    ///
+    /// `*` matches any run of characters and `?` a single character, so
+    /// `DB_*` selects a prefix and `*_KEY` a suffix; a pattern without
+    /// wildcards matches one key exactly. Aliases match by their own name.
+    /// Only matching secrets are decrypted, and no match yields an empty `Env`.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if decryption of a matching secret fails.
+    #[instrument(skip(self))]
+    pub fn export_filtered(&self, pattern: &str) -> Result<Env> {
+        info!("exporting matching secrets as env");
+        Ok(Self::to_env(
+            self.decrypt_where(|key| glob::matches(pattern, key))?,
+        ))
+    }
 
-        Ok(Env::from_pairs(pairs, std::path::PathBuf::from(".env")))
+    fn to_env(pairs: Vec<(SecretKey, Zeroizing<String>)>) -> Env {
+        let pairs = pairs.into_iter().map(|(k, v)| (k, v.to_string())).collect();
+        Env::from_pairs(pairs, std::path::PathBuf::from(".env"))
     }
 
     /// Unlock to .env file.
     ///
-    /// Decrypts all secrets and writes them to `.env` in the current directory.
+    /// Decrypts all secrets and writes them to `.env` in the current directory,
+    /// using the vault's `env_line_ending` (LF by default).
     ///
     /// # Returns
     ///
@@ -596,12 +2318,94 @@ impl Vault {
     /// # Errors
     ///
     /// Returns error if decryption or file write fails.
-    #[instrument(skip(self))]
     pub fn unlock(&self) -> Result<Env> {
-        info!("unlocking vault to .env");
+        self.unlock_with(
+            self.config.dugout.env_line_ending.unwrap_or_default(),
+            Quoting::default(),
+            None,
+        )
+    }
+
+    /// Unlock to .env file with an explicit line ending and quoting mode.
+    ///
+    /// With a `pattern`, only matching secrets are written (see
+    /// [`Vault::export_filtered`]).
+    ///
+    /// # Errors
+    ///
+    /// Returns `ValidationError::NeedsQuoting` if `quoting` is `Never` and a
+    /// value needs quotes. Returns error if decryption or file write fails.
+    pub fn unlock_with(
+        &self,
+        line_ending: LineEnding,
+        quoting: Quoting,
+        pattern: Option<&str>,
+    ) -> Result<Env> {
+        self.unlock_to(
+            std::path::Path::new(constants::ENV_FILE),
+            line_ending,
+            quoting,
+            pattern,
+            true,
+            false,
+        )
+    }
+
+    /// Unlock to the .env file at `path`, creating parent directories.
+    ///
+    /// Otherwise as [`Vault::unlock_with`]. An existing file is only
+    /// replaced with `force`, or moved to `<name>.bak` first with `backup`
+    /// (see [`Env::save_with_backup`]). The new file keeps the existing one's
+    /// key order and comments (see [`Env::ordered_like`]); keys it didn't
+    /// have are appended in sorted order.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ConfigError::Other` if `path` exists and neither `force` nor
+    /// `backup` is set.
+    /// Returns error if decryption or file write fails.
+    #[instrument(skip(self))]
+    pub fn unlock_to(
+        &self,
+        path: &std::path::Path,
+        line_ending: LineEnding,
+        quoting: Quoting,
+        pattern: Option<&str>,
+        force: bool,
+        backup: bool,
+    ) -> Result<Env> {
+        info!(path = %path.display(), "unlocking vault");
+
+        if !force && !backup && path.exists() {
+            return Err(ConfigError::Other(format!(
+                "{} already exists\n  → Pass --force to overwrite it",
+                path.display()
+            ))
+            .into());
+        }
 
-        let env = self.export()?;
-        env.save()?;
+        let env = match pattern {
+            Some(pattern) => self.export_filtered(pattern)?,
+            None => self.export()?,
+        };
+        let mut env = env
+            .with_path(path)
+            .with_line_ending(line_ending)
+            .with_quoting(quoting);
+        // Keep the layout of the file being replaced
+        if let Ok(existing) = Env::load(path) {
+            env = env.ordered_like(&existing);
+        }
+        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            std::fs::create_dir_all(parent)?;
+        }
+        if backup {
+            if let Some(moved) = env.save_with_backup()? {
+                debug!(backup = %moved.display(), "backed up existing env file");
+            }
+        } else {
+            env.save()?;
+        }
 
         debug!(count = env.len(), "unlock complete");
         Ok(env)
@@ -639,28 +2443,215 @@ impl Vault {
         Ok(Diff::compute(&vault_pairs, &env_pairs))
     }
 
-    // --- Sync ---
+    /// Compare this vault's secrets with another vault in the same directory.
+    ///
+    /// Both vaults are decrypted. In the result, this vault takes the place
+    /// of the vault side and `other` the place of `.env`: keys only here are
+    /// [`EntryStatus::VaultOnly`](crate::core::domain::EntryStatus::VaultOnly),
+    /// keys only in `other` are `EnvOnly`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ConfigError::NoAccessTo` if you aren't a recipient of `other`,
+    /// `ConfigError::NotInitialized` if it doesn't exist, or `CipherError` if
+    /// a secret in either vault fails to decrypt.
+    #[instrument(skip(self))]
+    pub fn diff_vault(&self, other: &str) -> Result<Diff> {
+        let other_vault = Self::open_other(Some(other))?;
+
+        let pairs = |vault: &Self| -> Result<Vec<(String, String)>> {
+            Ok(vault
+                .decrypt_all()?
+                .into_iter()
+                .map(|(k, v)| (k, v.to_string()))
+                .collect())
+        };
+        Ok(Diff::compute(&pairs(self)?, &pairs(&other_vault)?))
+    }
+
+    /// Move a secret into another vault.
+    ///
+    /// The value is decrypted here and set in `to` (`None` = default vault),
+    /// encrypted for that vault's recipients, before it is removed from this
+    /// one; if the removal fails the secret is left in both rather than lost.
+    /// A plaintext description moves with it.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SecretError::NotFound` if the key doesn't exist here.
+    /// Returns `SecretError::RemoveFailed` if the key is an alias or aliases
+    /// point at it.
+    /// Returns `SecretError::AlreadyExists` if `to` has the key and `force`
+    /// is false.
+    /// Returns `ConfigError::NoAccessTo` if you aren't a recipient of `to`.
+    #[instrument(skip(self))]
+    pub fn move_to(&mut self, key: &str, to: Option<&str>, force: bool) -> Result<()> {
+        let to = to.filter(|name| *name != "default");
+        if to == self.vault_name.as_deref().filter(|name| *name != "default") {
+            return Err(Error::Other(
+                "source and destination are the same vault".to_string(),
+            ));
+        }
+        if let Some(target) = self.config.aliases.get(key) {
+            return Err(SecretError::RemoveFailed(format!(
+                "{} is an alias for {}; move {} instead",
+                key, target, target
+            ))
+            .into());
+        }
+        let dependents = self.aliases_of(key);
+        if !dependents.is_empty() {
+            return Err(SecretError::RemoveFailed(format!(
+                "{} is the target of aliases: {}",
+                key,
+                dependents.join(", ")
+            ))
+            .into());
+        }
+
+        let value = self.get(key)?;
+        let mut dest = Self::open_other(to)?;
+        dest.set(key, &value, force)?;
+        if let Some(description) = self.config.descriptions.get(key).cloned() {
+            dest.set_description(key, &description)?;
+        }
+
+        self.remove(key)
+    }
+
+    /// Open another vault, reporting missing access as `NoAccessTo`.
+    fn open_other(vault: Option<&str>) -> Result<Self> {
+        Self::open_vault(vault).map_err(|e| match e {
+            Error::Config(
+                ConfigError::AccessDenied | ConfigError::NoIdentity | ConfigError::NotRecipient,
+            ) => ConfigError::NoAccessTo(vault.unwrap_or("default").to_string()).into(),
+            e => e,
+        })
+    }
+
+    // --- Sync ---
+
+    /// Compute SHA-256 fingerprint of the current recipient set.
+    ///
+    /// Deterministic: sorts keys before hashing so order doesn't matter.
+    pub fn recipients_fingerprint(&self) -> String {
+        recipients_fingerprint(&self.config)
+    }
+
+    /// Check if secrets need to be re-encrypted for the current recipient set.
+    ///
+    /// Returns `true` if hash is missing (backward compat) or mismatched.
+    /// Returns `false` if there are no secrets (nothing to sync).
+    /// See [`Vault::sync_status`] for the reason.
+    pub fn needs_sync(&self) -> bool {
+        self.sync_status().needs_sync()
+    }
+
+    /// Why secrets do or don't need re-encrypting.
+    ///
+    /// Compares stored `recipients_hash` against the current fingerprint.
+    /// A vault with no secrets is always in sync.
+    pub fn sync_status(&self) -> SyncStatus {
+        if self.config.secrets.is_empty() {
+            return SyncStatus::InSync;
+        }
+        match &self.config.dugout.recipients_hash {
+            Some(stored) if stored == &self.recipients_fingerprint() => SyncStatus::InSync,
+            Some(_) => SyncStatus::RecipientsChanged,
+            None => SyncStatus::NeverSynced,
+        }
+    }
+
+    /// Whether secrets exist but no `recipients_hash` is stored.
+    ///
+    /// Older vaults and hand-edited configs can lack the hash, which makes
+    /// `needs_sync` report true even when every secret is current.
+    pub fn recipients_hash_missing(&self) -> bool {
+        !self.config.secrets.is_empty() && self.config.dugout.recipients_hash.is_none()
+    }
 
-    /// Compute SHA-256 fingerprint of the current recipient set.
+    /// Rewrite `recipients_hash` from the current recipient set.
     ///
-    /// Deterministic: sorts keys before hashing so order doesn't matter.
-    pub fn recipients_fingerprint(&self) -> String {
-        recipients_fingerprint(&self.config)
+    /// Secrets are left untouched, so this only repairs the stored
+    /// fingerprint; use `sync` when they really need re-encrypting.
+    /// Returns `true` if the stored hash changed.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the config can't be saved.
+    #[instrument(skip(self))]
+    pub fn fix_recipients_hash(&mut self) -> Result<bool> {
+        let fingerprint = self.recipients_fingerprint();
+        if self.config.dugout.recipients_hash.as_deref() == Some(fingerprint.as_str()) {
+            debug!("recipients_hash already current");
+            return Ok(false);
+        }
+
+        info!("rewriting recipients_hash");
+        mark_synced(&mut self.config);
+        self.config.save_to(self.vault_name.as_deref())?;
+        self.record("fix-hash", &[]);
+        Ok(true)
     }
 
-    /// Check if secrets need to be re-encrypted for the current recipient set.
+    /// Name of the configured cipher backend (`age`, `hybrid+aws`, ...).
+    pub fn backend_name(&self) -> &'static str {
+        self.backend.name()
+    }
+
+    /// Backend recorded at the last sync, if it differs from the configured one.
     ///
-    /// Compares stored `recipients_hash` against the current fingerprint.
-    /// Returns `true` if hash is missing (backward compat) or mismatched.
-    /// Returns `false` if there are no secrets (nothing to sync).
-    pub fn needs_sync(&self) -> bool {
+    /// Returns `(synced, configured)` backend names. Vaults without secrets or
+    /// without a recorded backend (created before it was tracked) never differ.
+    pub fn backend_mismatch(&self) -> Option<(&str, &'static str)> {
         if self.config.secrets.is_empty() {
-            return false;
+            return None;
         }
-        match &self.config.dugout.recipients_hash {
-            Some(stored) => stored != &self.recipients_fingerprint(),
-            None => true, // no hash stored → assume out of sync
+        let synced = self.config.dugout.last_synced_backend.as_deref()?;
+        let configured = self.backend.name();
+        (synced != configured).then_some((synced, configured))
+    }
+
+    /// What `sync` would do, without doing it.
+    ///
+    /// Lists the secrets that would be re-encrypted (all of them, when a
+    /// sync is needed or `force`d) and the recipients added, removed or
+    /// re-keyed since the snapshot stored at the last sync. Vaults synced
+    /// before snapshots were stored report no recipient changes and set
+    /// `snapshot` to false.
+    pub fn sync_plan(&self, force: bool) -> SyncPlan {
+        let status = self.sync_status();
+        let secrets = if status.needs_sync() || force {
+            self.config.secrets.keys().cloned().collect()
+        } else {
+            Vec::new()
+        };
+
+        let previous = &self.config.dugout.synced_recipients;
+        let snapshot = !previous.is_empty();
+        let current = recipient_snapshot(&self.config);
+        let mut plan = SyncPlan {
+            status,
+            secrets,
+            snapshot,
+            ..SyncPlan::default()
+        };
+        if !snapshot {
+            return plan;
         }
+        for (name, fingerprint) in &current {
+            match previous.get(name) {
+                None => plan.added.push(name.clone()),
+                Some(old) if old != fingerprint => plan.rekeyed.push(name.clone()),
+                Some(_) => {}
+            }
+        }
+        plan.removed = previous
+            .keys()
+            .filter(|name| !current.contains_key(*name))
+            .cloned()
+            .collect();
+        plan
     }
 
     /// Sync all secrets for the current recipient set.
@@ -695,8 +2686,11 @@ impl Vault {
         }
 
         // Update fingerprint (reencrypt_all already saved, but we need the hash)
-        self.config.dugout.recipients_hash = Some(self.recipients_fingerprint());
+        mark_synced(&mut self.config);
+        self.config.dugout.last_synced_backend = Some(self.backend.name().to_string());
         self.config.save_to(self.vault_name.as_deref())?;
+        let keys: Vec<&str> = self.config.secrets.keys().map(String::as_str).collect();
+        self.run_hook("post_sync", &keys);
 
         Ok(SyncResult {
             secrets,
@@ -710,11 +2704,102 @@ impl Vault {
         self.vault_name.as_deref()
     }
 
+    /// Follow aliases from `key` to the secret they point at.
+    fn resolve_alias<'a>(&'a self, key: &'a str) -> Result<&'a str> {
+        let mut chain = vec![key];
+        let mut current = key;
+        while let Some(target) = self.config.aliases.get(current) {
+            let seen = chain.contains(&target.as_str());
+            chain.push(target.as_str());
+            if seen {
+                return Err(SecretError::AliasCycle(chain.join(" -> ")).into());
+            }
+            current = target.as_str();
+        }
+        Ok(current)
+    }
+
+    /// Aliases that point directly at `key`.
+    fn aliases_of(&self, key: &str) -> Vec<String> {
+        self.config
+            .aliases
+            .iter()
+            .filter(|(_, target)| target.as_str() == key)
+            .map(|(alias, _)| alias.clone())
+            .collect()
+    }
+
+    /// Refuse to write a secret under a name that is already an alias.
+    fn reject_alias(&self, key: &str) -> Result<()> {
+        match self.config.aliases.get(key) {
+            Some(target) => Err(SecretError::SetFailed(format!(
+                "{} is an alias for {}; update {} instead",
+                key, target, target
+            ))
+            .into()),
+            None => Ok(()),
+        }
+    }
+
+    /// Append an event to the local operation log.
+    fn record(&self, action: &str, keys: &[&str]) {
+        oplog::record(LogEvent::new(
+            &self.project_id,
+            self.vault_name.as_deref(),
+            action,
+            keys.iter().map(|key| key.to_string()).collect(),
+        ));
+    }
+
+    /// Turn a decryption failure for a member into a clearer error.
+    ///
+    /// Opening the vault already proved the identity is a recipient, so a
+    /// secret whose age header has no stanza for it was written before they
+    /// were added and hasn't been synced since.
+    fn explain_decrypt_failure(&self, key: &str, encrypted: &str, err: Error) -> Error {
+        let age_ct = cipher::Envelope::parse(encrypted)
+            .map(|envelope| envelope.age)
+            .unwrap_or_else(|| encrypted.to_string());
+        if cipher::header_stanzas(&age_ct).is_ok()
+            && !cipher::is_recipient(&age_ct, self.identity.as_age())
+        {
+            return SecretError::NotEncryptedForYou(key.to_string()).into();
+        }
+        err
+    }
+
+    /// Run the configured `[hooks]` command for `event`, if any.
+    ///
+    /// Call this only after the config has been saved.
+    fn run_hook(&self, event: &str, keys: &[&str]) {
+        let Some(hooks) = &self.config.hooks else {
+            return;
+        };
+        let command = match event {
+            "post_set" => &hooks.post_set,
+            "post_sync" => &hooks.post_sync,
+            _ => return,
+        };
+        if let Some(command) = command {
+            hooks::run(command, event, self.vault_name.as_deref(), keys);
+        }
+    }
+
+    /// Record that a secret's plaintext changed just now.
+    ///
+    /// Only call this from operations that write new plaintext; re-encryption
+    /// (`sync`, `rotate`, team changes) must leave timestamps alone.
+    fn touch(&mut self, key: &str) {
+        self.config
+            .updated
+            .insert(key.to_string(), Utc::now().to_rfc3339());
+    }
+
     /// Update the recipients hash in config.
     ///
     /// Call this after any operation that writes secrets.
     fn update_recipients_hash(&mut self) {
-        self.config.dugout.recipients_hash = Some(self.recipients_fingerprint());
+        mark_synced(&mut self.config);
     }
 
     /// Find all vault files in the current directory.
@@ -762,7 +2847,12 @@ impl Vault {
             }
 
             let contents = std::fs::read_to_string(config_path)?;
-            let config: Config = toml::from_str(&contents).map_err(ConfigError::Parse)?;
+            let mut config: Config = toml::from_str(&contents).map_err(ConfigError::Parse)?;
+            if config.dugout.split_secrets {
+                if let Ok(full) = Config::load_from(vault_name.as_deref()) {
+                    config = full;
+                }
+            }
 
             let has_access = identity_pubkey
                 .as_ref()
@@ -785,10 +2875,114 @@ impl Vault {
     pub fn has_multiple_vaults() -> Result<bool> {
         Ok(Self::find_vault_files()?.len() > 1)
     }
+
+    /// Fork this vault into a new named vault.
+    ///
+    /// Copies recipients, secrets and per-key metadata into
+    /// `.dugout.<new_name>.toml`, re-encrypting every secret and encrypted
+    /// description so the fork shares no ciphertext with its source.
+    /// `profiles` and `protected` only apply in the default vault and are
+    /// left out.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ConfigError::AlreadyInitialized` if the target vault exists.
+    /// Returns error if any secret can't be decrypted with the current identity.
+    #[instrument(skip(self))]
+    pub fn clone_to(&self, new_name: &str) -> Result<()> {
+        if Config::exists_for(Some(new_name)) {
+            return Err(ConfigError::AlreadyInitialized.into());
+        }
+
+        let recipients = get_recipients_as_strings(&self.config);
+        let reencrypt = |encrypted: &String| -> Result<String> {
+            let plaintext =
+                Zeroizing::new(self.backend.decrypt(encrypted, self.identity.as_age())?);
+            self.backend.encrypt(&plaintext, &recipients)
+        };
+
+        let mut config = self.config.clone();
+        for encrypted in config.secrets.values_mut() {
+            *encrypted = reencrypt(encrypted)?;
+        }
+        for encrypted in config.encrypted_descriptions.values_mut() {
+            *encrypted = reencrypt(encrypted)?;
+        }
+        config.profiles.clear();
+        config.dugout.protected.clear();
+        mark_synced(&mut config);
+        config.dugout.last_synced_backend = Some(self.backend.name().to_string());
+        config.save_to(Some(new_name))?;
+
+        debug!(count = config.secrets.len(), "cloned vault");
+        Ok(())
+    }
 }
 
 // --- Private helpers ---
 
+/// Three-way merge of `theirs` into the `ours` table of a conflicted vault.
+///
+/// See [`merge_value`]; `base` is `None` when the conflict has no diff3 base.
+fn merge_table<V: Clone + PartialEq>(
+    table: &str,
+    ours: &mut std::collections::BTreeMap<String, V>,
+    theirs: &std::collections::BTreeMap<String, V>,
+    base: Option<&std::collections::BTreeMap<String, V>>,
+) -> Result<()> {
+    let keys: std::collections::BTreeSet<String> =
+        ours.keys().chain(theirs.keys()).cloned().collect();
+    for key in keys {
+        let merged = merge_value(
+            &format!("[{}] {}", table, key),
+            ours.get(&key),
+            theirs.get(&key),
+            base.map(|b| b.get(&key)),
+        )?;
+        match merged {
+            Some(value) => ours.insert(key, value),
+            None => ours.remove(&key),
+        };
+    }
+    Ok(())
+}
+
+/// Three-way merge of one setting, where `None` means "not set".
+///
+/// A side that left the base value alone takes the other side's change,
+/// including a removal. Without a base (`base` is `None`), a setting on one
+/// side only is kept.
+///
+/// # Errors
+///
+/// Returns `ConfigError::Other` if the two sides changed it differently.
+fn merge_value<V: Clone + PartialEq>(
+    what: &str,
+    ours: Option<&V>,
+    theirs: Option<&V>,
+    base: Option<Option<&V>>,
+) -> Result<Option<V>> {
+    if ours == theirs {
+        return Ok(ours.cloned());
+    }
+    match base {
+        Some(base) if base == ours => Ok(theirs.cloned()),
+        Some(base) if base == theirs => Ok(ours.cloned()),
+        None if ours.is_none() || theirs.is_none() => Ok(ours.or(theirs).cloned()),
+        _ => Err(
+            ConfigError::Other(format!("{} differs on each side; resolve it by hand", what)).into(),
+        ),
+    }
+}
+
+/// Copy `map[src]` to `map[dst]`, removing `map[dst]` if `src` has no entry.
+fn copy_entry(map: &mut std::collections::BTreeMap<SecretKey, String>, src: &str, dst: &str) {
+    match map.get(src).cloned() {
+        Some(value) => map.insert(dst.to_string(), value),
+        None => map.remove(dst),
+    };
+}
+
 /// Validate a secret key name
 ///
 /// Secret keys must be valid environment variable names:
@@ -881,20 +3075,111 @@ fn validate_value(key: &str, value: &str) -> Result<()> {
     Ok(())
 }
 
+/// Validate a secret value against its configured `[validate]` pattern, if any
+fn validate_pattern(config: &Config, key: &str, value: &str) -> Result<()> {
+    if let Some(pattern) = config.validations.get(key) {
+        let re = regex::Regex::new(pattern).map_err(|e| ConfigError::InvalidValue {
+            field: "validate",
+            reason: format!("invalid pattern for '{}': {}", key, e),
+        })?;
+        if !re.is_match(value) {
+            return Err(ValidationError::PatternMismatch {
+                key: key.to_string(),
+                pattern: pattern.clone(),
+            }
+            .into());
+        }
+    }
+
+    Ok(())
+}
+
+/// KMS key from the default vault's profile matching `vault`, if any
+fn profile_kms_key(vault: &str) -> Result<Option<String>> {
+    if !Config::exists_for(None) {
+        return Ok(None);
+    }
+    let defaults = Config::load_from(None)?;
+    match defaults.profile_for(vault) {
+        Some((name, profile)) => {
+            debug!(vault, profile = name, "applying vault profile");
+            Ok(profile.kms_key(name)?.map(str::to_string))
+        }
+        None => Ok(None),
+    }
+}
+
+/// Move the project's identity key into its timestamped archive directory.
+///
+/// Uses atomic rename and handles missing files gracefully to avoid TOCTOU races.
+fn archive_key(project_id: &str) -> Result<()> {
+    let key_dir = Identity::project_dir(project_id)?;
+    let archive_dir = key_dir.join("archive");
+    std::fs::create_dir_all(&archive_dir)?;
+
+    let timestamp = Utc::now().format("%Y%m%d_%H%M%S");
+    let archive_file = archive_dir.join(format!("identity.key.{}", timestamp));
+
+    // Try to rename directly - handles TOCTOU by checking error instead of exists()
+    match std::fs::rename(key_dir.join("identity.key"), &archive_file) {
+        Ok(()) => Ok(()),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()), // No key to archive
+        Err(e) => Err(e.into()),
+    }
+}
+
 /// Get all recipient public keys as strings
+///
+/// Expired recipients are skipped with a warning.
 fn get_recipients_as_strings(config: &Config) -> Vec<String> {
-    config.recipients.values().cloned().collect()
+    config
+        .recipients
+        .iter()
+        .filter(|(name, _)| {
+            let expired = config.is_expired(name);
+            if expired {
+                warn!(name = %name, "skipping expired recipient");
+            }
+            !expired
+        })
+        .map(|(_, key)| key.clone())
+        .collect()
 }
 
 /// Compute SHA-256 fingerprint of sorted recipient public keys.
+///
+/// Expired recipients are left out, so an expiry passing makes the vault
+/// need a sync.
 fn recipients_fingerprint(config: &Config) -> String {
-    let mut keys: Vec<&str> = config.recipients.values().map(|k| k.as_str()).collect();
+    let mut keys: Vec<&str> = config
+        .recipients
+        .iter()
+        .filter(|(name, _)| !config.is_expired(name))
+        .map(|(_, k)| k.as_str())
+        .collect();
     keys.sort();
     let joined = keys.join("\n");
     let hash = Sha256::digest(joined.as_bytes());
     format!("{:x}", hash)
 }
 
+/// Record the current recipients as synced: the hash `needs_sync` checks,
+/// plus the per-member snapshot `sync_plan` diffs against.
+fn mark_synced(config: &mut Config) {
+    config.dugout.recipients_hash = Some(recipients_fingerprint(config));
+    config.dugout.synced_recipients = recipient_snapshot(config);
+}
+
+/// Key fingerprint of each recipient that secrets are encrypted for.
+fn recipient_snapshot(config: &Config) -> std::collections::BTreeMap<MemberName, String> {
+    config
+        .recipients
+        .iter()
+        .filter(|(name, _)| !config.is_expired(name))
+        .map(|(name, key)| (name.clone(), key_fingerprint(key)))
+        .collect()
+}
+
 /// List all team members as (name, public_key) pairs
 fn list_recipients(config: &Config) -> Vec<(MemberName, PublicKey)> {
     config
@@ -904,6 +3189,23 @@ fn list_recipients(config: &Config) -> Vec<(MemberName, PublicKey)> {
         .collect()
 }
 
+/// Explain why no identity could open the vault.
+///
+/// No key at all is `NoIdentity`. If a key was found but the recipient named
+/// after the current user holds a different one, the identity was most
+/// likely regenerated after they were added, which is worth saying instead
+/// of a bare `AccessDenied`.
+fn access_denied(config: &Config, loaded_any: bool) -> ConfigError {
+    let name = whoami::username();
+    if !loaded_any {
+        ConfigError::NoIdentity
+    } else if config.recipients.contains_key(&name) {
+        ConfigError::StaleRecipient(name)
+    } else {
+        ConfigError::AccessDenied
+    }
+}
+
 fn identity_has_access(config: &Config, identity: &Identity) -> bool {
     let identity_pubkey = identity.public_key();
     config
@@ -927,77 +3229,359 @@ mod tests {
         _cwd_guard: MutexGuard<'static, ()>,
     }
 
-    static FORCE_FILESYSTEM_BACKEND: Once = Once::new();
-    static CWD_LOCK: Mutex<()> = Mutex::new(());
+    static FORCE_FILESYSTEM_BACKEND: Once = Once::new();
+    static CWD_LOCK: Mutex<()> = Mutex::new(());
+
+    impl Drop for TestContext {
+        fn drop(&mut self) {
+            // Restore original directory before tempdir is cleaned up
+            let _ = std::env::set_current_dir(&self._original_dir);
+        }
+    }
+
+    fn setup_test_vault() -> (TestContext, Vault) {
+        FORCE_FILESYSTEM_BACKEND.call_once(|| {
+            std::env::set_var("DUGOUT_NO_KEYCHAIN", "1");
+        });
+        let cwd_guard = CWD_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        let tmp = TempDir::new().unwrap();
+        let original_dir =
+            std::env::current_dir().unwrap_or_else(|_| std::path::PathBuf::from("/"));
+        std::env::set_current_dir(tmp.path()).unwrap();
+        let vault = Vault::init("alice", None).unwrap();
+        let ctx = TestContext {
+            _tmp: tmp,
+            _original_dir: original_dir,
+            _cwd_guard: cwd_guard,
+        };
+        (ctx, vault)
+    }
+
+    // --- Secrets tests ---
+
+    #[test]
+    fn test_vault_set_and_get() {
+        let (_ctx, mut vault) = setup_test_vault();
+
+        vault.set("API_KEY", "secret123", false).unwrap();
+        let value = vault.get("API_KEY").unwrap();
+
+        assert_eq!(value.as_str(), "secret123");
+    }
+
+    #[test]
+    fn test_vault_remove() {
+        let (_ctx, mut vault) = setup_test_vault();
+
+        vault.set("TEMP_SECRET", "value", false).unwrap();
+        vault.remove("TEMP_SECRET").unwrap();
+
+        // After removal, get should fail
+        assert!(vault.get("TEMP_SECRET").is_err());
+
+        // Verify it's not in the list
+        let secrets = vault.list();
+        let keys: Vec<String> = secrets.iter().map(|s| s.key().to_string()).collect();
+        assert!(!keys.contains(&"TEMP_SECRET".to_string()));
+    }
+
+    #[test]
+    fn test_vault_list() {
+        let (_ctx, mut vault) = setup_test_vault();
+
+        vault.set("KEY_ONE", "value1", false).unwrap();
+        vault.set("KEY_TWO", "value2", false).unwrap();
+        vault.set("KEY_THREE", "value3", false).unwrap();
+
+        let secrets = vault.list();
+        assert_eq!(secrets.len(), 3);
+
+        let keys: Vec<String> = secrets.iter().map(|s| s.key().to_string()).collect();
+        assert!(keys.contains(&"KEY_ONE".to_string()));
+        assert!(keys.contains(&"KEY_TWO".to_string()));
+        assert!(keys.contains(&"KEY_THREE".to_string()));
+    }
+
+    #[test]
+    fn test_vault_description_roundtrip() {
+        let (_ctx, mut vault) = setup_test_vault();
+
+        vault.set("API_KEY", "secret123", false).unwrap();
+        assert_eq!(vault.description("API_KEY"), None);
+
+        vault.set_description("API_KEY", "Stripe live key").unwrap();
+        assert_eq!(vault.description("API_KEY"), Some("Stripe live key"));
+
+        let secrets = vault.list();
+        assert_eq!(secrets[0].description(), Some("Stripe live key"));
+
+        // Survives re-encryption
+        vault.reencrypt_all().unwrap();
+        assert_eq!(vault.description("API_KEY"), Some("Stripe live key"));
+
+        // Removed with the secret
+        vault.remove("API_KEY").unwrap();
+        assert_eq!(vault.description("API_KEY"), None);
+    }
+
+    #[test]
+    fn test_vault_updated_at_tracks_plaintext_changes() {
+        let (_ctx, mut vault) = setup_test_vault();
+
+        assert!(vault.updated_at("API_KEY").is_none());
+
+        vault.set("API_KEY", "first", false).unwrap();
+        let first = vault.updated_at("API_KEY").unwrap();
+
+        // Re-encryption leaves the timestamp alone
+        vault.reencrypt_all().unwrap();
+        assert_eq!(vault.updated_at("API_KEY"), Some(first));
+
+        vault.set("API_KEY", "second", true).unwrap();
+        assert!(vault.updated_at("API_KEY").unwrap() >= first);
+
+        vault.remove("API_KEY").unwrap();
+        assert!(vault.updated_at("API_KEY").is_none());
+    }
+
+    #[test]
+    fn test_vault_encrypted_description() {
+        let (_ctx, mut vault) = setup_test_vault();
+        vault.config.dugout.encrypt_descriptions = true;
+        vault.set("API_KEY", "sk_live", false).unwrap();
+        vault.set_description("API_KEY", "Stripe live key").unwrap();
+
+        assert_eq!(vault.description("API_KEY"), None);
+        assert!(vault.has_encrypted_description("API_KEY"));
+        let raw = std::fs::read_to_string(constants::CONFIG_FILE).unwrap();
+        assert!(raw.contains("[encrypted_descriptions]"));
+        assert!(!raw.contains("Stripe live key"));
+
+        // Survives re-encryption for a new recipient set
+        vault.reencrypt_all().unwrap();
+        let desc = vault.decrypt_description("API_KEY").unwrap().unwrap();
+        assert_eq!(desc.as_str(), "Stripe live key");
+
+        vault.set_description("API_KEY", "").unwrap();
+        assert!(!vault.has_encrypted_description("API_KEY"));
+    }
+
+    #[test]
+    fn test_vault_set_description_missing_key() {
+        let (_ctx, mut vault) = setup_test_vault();
+
+        assert!(vault.set_description("MISSING", "note").is_err());
+    }
+
+    #[test]
+    fn test_vault_set_many() {
+        let (_ctx, mut vault) = setup_test_vault();
+        let pairs = vec![
+            ("A".to_string(), "1".to_string()),
+            ("B".to_string(), "2".to_string()),
+        ];
+
+        let secrets = vault.set_many(&pairs, false).unwrap();
+        assert_eq!(secrets.len(), 2);
+        assert_eq!(vault.get("A").unwrap().as_str(), "1");
+        assert_eq!(vault.get("B").unwrap().as_str(), "2");
+    }
+
+    #[test]
+    fn test_vault_set_many_is_atomic() {
+        let (_ctx, mut vault) = setup_test_vault();
+        vault.set("EXISTING", "old", false).unwrap();
+
+        let pairs = vec![
+            ("NEW".to_string(), "1".to_string()),
+            ("1BAD".to_string(), "2".to_string()),
+        ];
+        assert!(vault.set_many(&pairs, false).is_err());
+        assert!(vault.get("NEW").is_err());
+
+        let pairs = vec![
+            ("NEW".to_string(), "1".to_string()),
+            ("EXISTING".to_string(), "new".to_string()),
+        ];
+        assert!(vault.set_many(&pairs, false).is_err());
+        assert!(vault.get("NEW").is_err());
+        assert_eq!(vault.get("EXISTING").unwrap().as_str(), "old");
+    }
+
+    #[test]
+    fn test_vault_alias_returns_target_value() {
+        let (_ctx, mut vault) = setup_test_vault();
+        vault.set("DB_PASSWORD", "hunter2", false).unwrap();
+
+        vault.alias("APP_DB_PASSWORD", "DB_PASSWORD").unwrap();
+        assert_eq!(vault.get("APP_DB_PASSWORD").unwrap().as_str(), "hunter2");
+
+        vault.set("DB_PASSWORD", "hunter3", true).unwrap();
+        assert_eq!(vault.get("APP_DB_PASSWORD").unwrap().as_str(), "hunter3");
 
-    impl Drop for TestContext {
-        fn drop(&mut self) {
-            // Restore original directory before tempdir is cleaned up
-            let _ = std::env::set_current_dir(&self._original_dir);
-        }
+        let pairs = vault.decrypt_all().unwrap();
+        let keys: Vec<&str> = pairs.iter().map(|(k, _)| k.as_str()).collect();
+        assert_eq!(keys, vec!["APP_DB_PASSWORD", "DB_PASSWORD"]);
+        assert_eq!(pairs[0].1.as_str(), "hunter3");
     }
 
-    fn setup_test_vault() -> (TestContext, Vault) {
-        FORCE_FILESYSTEM_BACKEND.call_once(|| {
-            std::env::set_var("DUGOUT_NO_KEYCHAIN", "1");
-        });
-        let cwd_guard = CWD_LOCK.lock().unwrap_or_else(|e| e.into_inner());
-        let tmp = TempDir::new().unwrap();
-        let original_dir =
-            std::env::current_dir().unwrap_or_else(|_| std::path::PathBuf::from("/"));
-        std::env::set_current_dir(tmp.path()).unwrap();
-        let vault = Vault::init("alice", None).unwrap();
-        let ctx = TestContext {
-            _tmp: tmp,
-            _original_dir: original_dir,
-            _cwd_guard: cwd_guard,
-        };
-        (ctx, vault)
+    #[test]
+    fn test_vault_alias_cycle_rejected() {
+        let (_ctx, mut vault) = setup_test_vault();
+        vault.set("TARGET", "value", false).unwrap();
+        vault.alias("A", "TARGET").unwrap();
+        vault.alias("B", "A").unwrap();
+
+        let err = vault.alias("A", "B").unwrap_err();
+        assert!(err.to_string().contains("cycle"));
+        // The failed alias leaves the original in place
+        assert_eq!(vault.get("A").unwrap().as_str(), "value");
     }
 
-    // --- Secrets tests ---
+    #[test]
+    fn test_vault_alias_guards() {
+        let (_ctx, mut vault) = setup_test_vault();
+        vault.set("TARGET", "value", false).unwrap();
+
+        assert!(vault.alias("TARGET", "TARGET").is_err());
+        assert!(vault.alias("NEW", "MISSING").is_err());
+
+        vault.alias("NEW", "TARGET").unwrap();
+        assert!(vault.set("NEW", "x", true).is_err());
+        assert!(vault.remove("TARGET").is_err());
+
+        vault.remove("NEW").unwrap();
+        vault.remove("TARGET").unwrap();
+    }
 
     #[test]
-    fn test_vault_set_and_get() {
+    fn test_vault_remove_alias_in_chain_rejected() {
         let (_ctx, mut vault) = setup_test_vault();
+        vault.set("SECRET", "value", false).unwrap();
+        vault.alias("B", "SECRET").unwrap();
+        vault.alias("A", "B").unwrap();
+
+        let err = vault.remove("B").unwrap_err();
+        assert!(err.to_string().contains("target of aliases: A"));
+        assert_eq!(vault.get("A").unwrap().as_str(), "value");
+        assert_eq!(vault.decrypt_all().unwrap().len(), 3);
+
+        vault.remove("A").unwrap();
+        vault.remove("B").unwrap();
+        vault.remove("SECRET").unwrap();
+    }
 
-        vault.set("API_KEY", "secret123", false).unwrap();
-        let value = vault.get("API_KEY").unwrap();
+    #[test]
+    fn test_vault_get_many() {
+        let (_ctx, mut vault) = setup_test_vault();
+        vault.set("A", "1", false).unwrap();
+        vault.set("B", "2", false).unwrap();
+        vault.set("C", "3", false).unwrap();
+
+        let pairs = vault.get_many(&["C", "A"]).unwrap();
+        assert_eq!(pairs.len(), 2);
+        assert_eq!(pairs[0].0, "C");
+        assert_eq!(pairs[0].1.as_str(), "3");
+        assert_eq!(pairs[1].0, "A");
+        assert_eq!(pairs[1].1.as_str(), "1");
+
+        let err = vault.get_many(&["A", "MISSING"]).unwrap_err();
+        assert!(err.to_string().contains("MISSING"));
+    }
 
-        assert_eq!(value.as_str(), "secret123");
+    #[test]
+    fn test_vault_rename_moves_ciphertext() {
+        let (_ctx, mut vault) = setup_test_vault();
+        vault.set("OLD_KEY", "value", false).unwrap();
+        vault.set_description("OLD_KEY", "note").unwrap();
+        let ciphertext = vault.config.secrets["OLD_KEY"].clone();
+        let hash = vault.config.dugout.recipients_hash.clone();
+
+        vault.rename("OLD_KEY", "NEW_KEY", false).unwrap();
+
+        assert!(!vault.config.secrets.contains_key("OLD_KEY"));
+        assert_eq!(vault.config.secrets["NEW_KEY"], ciphertext);
+        assert_eq!(vault.description("NEW_KEY"), Some("note"));
+        assert_eq!(vault.config.dugout.recipients_hash, hash);
+        assert_eq!(vault.get("NEW_KEY").unwrap().as_str(), "value");
     }
 
     #[test]
-    fn test_vault_remove() {
+    fn test_vault_rename_errors() {
         let (_ctx, mut vault) = setup_test_vault();
+        vault.set("A", "1", false).unwrap();
+        vault.set("B", "2", false).unwrap();
 
-        vault.set("TEMP_SECRET", "value", false).unwrap();
-        vault.remove("TEMP_SECRET").unwrap();
+        assert!(vault.rename("MISSING", "C", false).is_err());
+        assert!(vault.rename("A", "B", false).is_err());
+        assert!(vault.rename("A", "1BAD", false).is_err());
 
-        // After removal, get should fail
-        assert!(vault.get("TEMP_SECRET").is_err());
+        vault.rename("A", "B", true).unwrap();
+        assert_eq!(vault.get("B").unwrap().as_str(), "1");
+    }
 
-        // Verify it's not in the list
-        let secrets = vault.list();
-        let keys: Vec<String> = secrets.iter().map(|s| s.key().to_string()).collect();
-        assert!(!keys.contains(&"TEMP_SECRET".to_string()));
+    #[test]
+    fn test_vault_copy_keeps_ciphertext() {
+        let (_ctx, mut vault) = setup_test_vault();
+        vault
+            .set("DATABASE_URL", "postgres://primary", false)
+            .unwrap();
+        vault.set_description("DATABASE_URL", "main db").unwrap();
+        let ciphertext = vault.config.secrets["DATABASE_URL"].clone();
+        let hash = vault.config.dugout.recipients_hash.clone();
+
+        vault
+            .copy("DATABASE_URL", "DATABASE_URL_REPLICA", false)
+            .unwrap();
+
+        assert_eq!(vault.config.secrets["DATABASE_URL"], ciphertext);
+        assert_eq!(vault.config.secrets["DATABASE_URL_REPLICA"], ciphertext);
+        assert_eq!(vault.description("DATABASE_URL_REPLICA"), Some("main db"));
+        assert_eq!(vault.config.dugout.recipients_hash, hash);
+        assert_eq!(
+            vault.get("DATABASE_URL_REPLICA").unwrap().as_str(),
+            "postgres://primary"
+        );
+
+        assert!(vault.copy("MISSING", "OTHER", false).is_err());
+        assert!(vault
+            .copy("DATABASE_URL", "DATABASE_URL_REPLICA", false)
+            .is_err());
+        vault.set("OTHER", "x", false).unwrap();
+        vault.copy("OTHER", "DATABASE_URL_REPLICA", true).unwrap();
+        assert_eq!(vault.get("DATABASE_URL_REPLICA").unwrap().as_str(), "x");
+        assert_eq!(vault.description("DATABASE_URL_REPLICA"), None);
     }
 
     #[test]
-    fn test_vault_list() {
+    fn test_vault_set_enforces_validation_pattern() {
         let (_ctx, mut vault) = setup_test_vault();
+        vault
+            .config
+            .validations
+            .insert("PORT".to_string(), "^[0-9]+$".to_string());
 
-        vault.set("KEY_ONE", "value1", false).unwrap();
-        vault.set("KEY_TWO", "value2", false).unwrap();
-        vault.set("KEY_THREE", "value3", false).unwrap();
+        let err = vault.set("PORT", "80a", false).unwrap_err();
+        assert!(err.to_string().contains("^[0-9]+$"));
+        assert!(vault.get("PORT").is_err());
 
-        let secrets = vault.list();
-        assert_eq!(secrets.len(), 3);
+        vault.set("PORT", "8080", false).unwrap();
+        assert_eq!(vault.get("PORT").unwrap().as_str(), "8080");
 
-        let keys: Vec<String> = secrets.iter().map(|s| s.key().to_string()).collect();
-        assert!(keys.contains(&"KEY_ONE".to_string()));
-        assert!(keys.contains(&"KEY_TWO".to_string()));
-        assert!(keys.contains(&"KEY_THREE".to_string()));
+        // Keys without a pattern are unaffected
+        vault.set("API_KEY", "anything goes", false).unwrap();
+    }
+
+    #[test]
+    fn test_vault_import_enforces_validation_pattern() {
+        let (_ctx, mut vault) = setup_test_vault();
+        vault
+            .config
+            .validations
+            .insert("PORT".to_string(), "^[0-9]+$".to_string());
+
+        fs::write(".env.test", "PORT=not-a-port\n").unwrap();
+        assert!(vault.import(".env.test", OnConflict::Error).is_err());
     }
 
     // --- Team tests ---
@@ -1045,6 +3629,82 @@ mod tests {
         assert!(recipients.iter().all(|r| r.name() != "bob"));
     }
 
+    #[test]
+    fn test_vault_expired_recipient_is_skipped_and_pruned() {
+        let (_ctx, mut vault) = setup_test_vault();
+        vault.set("SHARED_SECRET", "value", false).unwrap();
+
+        let bob = age::x25519::Identity::generate().to_public().to_string();
+        let past = Utc::now() - chrono::Duration::days(1);
+        assert!(vault.add_recipient_with_expiry("bob", &bob, past).is_err());
+
+        let future = Utc::now() + chrono::Duration::days(30);
+        vault
+            .add_recipient_with_expiry("bob", &bob, future)
+            .unwrap();
+        assert!(vault.recipient_expiry("bob").is_some());
+        assert_eq!(get_recipients_as_strings(&vault.config).len(), 2);
+        assert!(!vault.needs_sync());
+
+        // Backdate the expiry: bob drops out of encryption and the vault needs a sync
+        vault
+            .config
+            .recipient_expiry
+            .insert("bob".to_string(), past.to_rfc3339());
+        assert_eq!(vault.expired_recipients(), vec!["bob".to_string()]);
+        assert!(!get_recipients_as_strings(&vault.config).contains(&bob));
+        assert!(vault.needs_sync());
+        assert_eq!(vault.sync_status(), SyncStatus::RecipientsChanged);
+
+        vault.sync(false).unwrap();
+        let stanzas = cipher::header_stanzas(&vault.config.secrets["SHARED_SECRET"]).unwrap();
+        assert_eq!(stanzas.len(), 1);
+
+        assert_eq!(vault.prune_expired().unwrap(), vec!["bob".to_string()]);
+        assert!(vault.config.recipient_expiry.is_empty());
+        assert_eq!(vault.recipients().len(), 1);
+        assert!(!vault.needs_sync());
+    }
+
+    #[test]
+    fn test_vault_recipient_note_follows_member() {
+        let (_ctx, mut vault) = setup_test_vault();
+        vault.set("SHARED_SECRET", "value", false).unwrap();
+        let ci = age::x25519::Identity::generate().to_public().to_string();
+        vault.add_recipient("ci", &ci).unwrap();
+        assert!(vault.set_recipient_note("nobody", "x").is_err());
+
+        vault.set_recipient_note("ci", " CI deploy key ").unwrap();
+        vault.reencrypt_all().unwrap();
+        assert_eq!(vault.recipient_note("ci"), Some("CI deploy key"));
+
+        vault.remove_recipient("ci").unwrap();
+        assert_eq!(vault.recipient_note("ci"), None);
+        assert!(vault.config.recipient_notes.is_empty());
+    }
+
+    #[test]
+    fn test_vault_roles_gate_admit() {
+        let (_ctx, mut vault) = setup_test_vault();
+        assert_eq!(vault.current_member(), Some("alice"));
+        assert_eq!(vault.role_of("alice"), Role::Admin);
+        assert!(vault.can_admit());
+
+        let bob = age::x25519::Identity::generate().to_public().to_string();
+        vault.add_recipient("bob", &bob).unwrap();
+        assert_eq!(vault.role_of("bob"), Role::Member);
+        assert!(vault.set_role("carol", Role::Admin).is_err());
+
+        vault.set_role("bob", Role::Admin).unwrap();
+        vault.set_role("alice", Role::Member).unwrap();
+        assert!(!vault.can_admit());
+
+        // Without any admin, roles aren't enforced
+        vault.remove_recipient("bob").unwrap();
+        assert!(vault.config.roles.is_empty());
+        assert!(vault.can_admit());
+    }
+
     #[test]
     fn test_vault_reencrypt_after_team_change() {
         let (_ctx, mut vault) = setup_test_vault();
@@ -1068,6 +3728,174 @@ mod tests {
         assert_eq!(all_secrets[0].1.as_str(), "original");
     }
 
+    #[test]
+    fn test_vault_set_outcome() {
+        let (_ctx, mut vault) = setup_test_vault();
+
+        assert_eq!(
+            vault.set("TOKEN", "one", false).unwrap(),
+            SetOutcome::Created
+        );
+        let stored = vault.config.secrets["TOKEN"].clone();
+        let updated = vault.config.updated["TOKEN"].clone();
+
+        // Same value: no re-encryption, no write
+        assert_eq!(
+            vault.set("TOKEN", "one", true).unwrap(),
+            SetOutcome::Unchanged
+        );
+        assert_eq!(vault.config.secrets["TOKEN"], stored);
+        assert_eq!(vault.config.updated["TOKEN"], updated);
+
+        assert_eq!(
+            vault.set("TOKEN", "two", true).unwrap(),
+            SetOutcome::Updated
+        );
+        assert_ne!(vault.config.secrets["TOKEN"], stored);
+        assert_eq!(vault.get("TOKEN").unwrap().as_str(), "two");
+    }
+
+    #[test]
+    fn test_vault_dedup_case() {
+        let (_ctx, mut vault) = setup_test_vault();
+        vault.set("API_KEY", "same", false).unwrap();
+        vault.set("Api_Key", "same", false).unwrap();
+        vault.set("TOKEN", "upper", false).unwrap();
+        vault.set("token", "lower", false).unwrap();
+        vault.set("DB_URL", "postgres://", false).unwrap();
+        vault.alias("OLD_TOKEN", "token").unwrap();
+        assert_eq!(vault.case_duplicates().len(), 2);
+
+        let mut seen = Vec::new();
+        let decisions = vault
+            .dedup_case(|group, identical| {
+                seen.push((group.to_vec(), identical));
+                Ok(group[0].clone())
+            })
+            .unwrap();
+        assert_eq!(
+            seen,
+            vec![
+                (vec!["API_KEY".to_string(), "Api_Key".to_string()], true),
+                (vec!["TOKEN".to_string(), "token".to_string()], false),
+            ]
+        );
+        assert_eq!(
+            decisions[1],
+            ("TOKEN".to_string(), vec!["token".to_string()])
+        );
+
+        let reopened = Vault::open().unwrap();
+        assert!(reopened.case_duplicates().is_empty());
+        assert_eq!(reopened.list().len(), 3);
+        assert_eq!(reopened.get("OLD_TOKEN").unwrap().as_str(), "upper");
+
+        let mut vault = reopened;
+        assert!(vault.dedup_case(|_, _| unreachable!()).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_vault_sync_plan_diffs_recipients() {
+        let (_ctx, mut vault) = setup_test_vault();
+        vault.set("SECRET", "value", false).unwrap();
+        let plan = vault.sync_plan(false);
+        assert_eq!(plan.status, SyncStatus::InSync);
+        assert!(plan.secrets.is_empty());
+
+        // Recipient changes that arrive without a sync, e.g. through git
+        let bob = age::x25519::Identity::generate().to_public().to_string();
+        let alice = age::x25519::Identity::generate().to_public().to_string();
+        vault.config.recipients.insert("bob".to_string(), bob);
+        vault.config.recipients.insert("alice".to_string(), alice);
+        let before = vault.config.clone();
+
+        let plan = vault.sync_plan(false);
+        assert_eq!(plan.status, SyncStatus::RecipientsChanged);
+        assert_eq!(plan.secrets, vec!["SECRET".to_string()]);
+        assert_eq!(plan.added, vec!["bob".to_string()]);
+        assert_eq!(plan.rekeyed, vec!["alice".to_string()]);
+        assert!(plan.removed.is_empty());
+        assert_eq!(
+            vault.config.dugout.recipients_hash,
+            before.dugout.recipients_hash
+        );
+
+        // alice missing from a snapshot that still records bob counts as added
+        let bob_fingerprint = recipient_snapshot(&vault.config)["bob"].clone();
+        vault
+            .config
+            .dugout
+            .synced_recipients
+            .insert("bob".to_string(), bob_fingerprint);
+        vault.config.dugout.synced_recipients.remove("alice");
+        let plan = vault.sync_plan(false);
+        assert!(plan.added.contains(&"alice".to_string()));
+        assert!(!plan.added.contains(&"bob".to_string()));
+
+        // Vaults synced before snapshots were stored can't say who changed
+        vault.config.dugout.synced_recipients.clear();
+        let plan = vault.sync_plan(false);
+        assert!(!plan.snapshot);
+        assert!(plan.added.is_empty());
+        assert_eq!(plan.secrets, vec!["SECRET".to_string()]);
+    }
+
+    #[test]
+    fn test_vault_fix_recipients_hash() {
+        let (_ctx, mut vault) = setup_test_vault();
+        vault.set("SECRET", "unchanged", false).unwrap();
+        let encrypted = vault.config.secrets["SECRET"].clone();
+
+        vault.config.dugout.recipients_hash = None;
+        vault.config.save_to(None).unwrap();
+        let mut vault = Vault::open().unwrap();
+        assert!(vault.recipients_hash_missing());
+        assert!(vault.needs_sync());
+        assert_eq!(vault.sync_status(), SyncStatus::NeverSynced);
+
+        assert!(vault.fix_recipients_hash().unwrap());
+        assert!(!vault.fix_recipients_hash().unwrap());
+
+        let reopened = Vault::open().unwrap();
+        assert!(!reopened.recipients_hash_missing());
+        assert_eq!(reopened.sync_status(), SyncStatus::InSync);
+        assert_eq!(reopened.config.secrets["SECRET"], encrypted);
+    }
+
+    #[test]
+    fn test_vault_rotate_keypair() {
+        let (_ctx, mut vault) = setup_test_vault();
+        vault.set("SECRET", "unchanged", false).unwrap();
+        let old_key = vault.config.recipients["alice"].clone();
+
+        vault.rotate_keypair().unwrap();
+
+        let new_key = vault.config.recipients["alice"].clone();
+        assert_ne!(old_key, new_key);
+        assert_eq!(vault.identity().public_key(), new_key);
+        assert!(!vault.needs_sync());
+
+        let reopened = Vault::open().unwrap();
+        assert_eq!(reopened.get("SECRET").unwrap().as_str(), "unchanged");
+    }
+
+    #[test]
+    fn test_vault_rotate_keypair_requires_recipient() {
+        let (_ctx, mut vault) = setup_test_vault();
+        let outsider = age::x25519::Identity::generate();
+        vault.config.recipients.clear();
+        vault
+            .config
+            .recipients
+            .insert("bob".to_string(), outsider.to_public().to_string());
+
+        let err = vault.rotate_keypair().unwrap_err();
+        assert!(matches!(
+            err,
+            crate::error::Error::Config(ConfigError::NotRecipient)
+        ));
+    }
+
     #[test]
     fn test_vault_open_denies_non_member_identity() {
         let (_ctx, _vault) = setup_test_vault();
@@ -1087,6 +3915,59 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_vault_open_detects_stale_recipient_key() {
+        let (_ctx, _vault) = setup_test_vault();
+
+        // The current user is still listed, but under a key from before regeneration
+        let old_key = age::x25519::Identity::generate().to_public().to_string();
+        let name = whoami::username();
+
+        let mut cfg = Config::load().unwrap();
+        cfg.recipients.clear();
+        cfg.recipients.insert(name.clone(), old_key);
+        cfg.save().unwrap();
+
+        let err = Vault::open().unwrap_err();
+        assert!(matches!(
+            &err,
+            crate::error::Error::Config(ConfigError::StaleRecipient(n)) if n == &name
+        ));
+        assert!(err.to_string().contains("dugout knock"));
+    }
+
+    #[test]
+    fn test_verify_recipients_reports_partial_sync() {
+        let (_ctx, mut vault) = setup_test_vault();
+        vault.set("API_KEY", "secret", false).unwrap();
+        vault.set("DB_URL", "postgres://", false).unwrap();
+        assert!(vault.verify_recipients().unwrap().is_empty());
+
+        // Bob is admitted, but the vault is saved without re-encrypting API_KEY
+        let api_key = vault.config.secrets["API_KEY"].clone();
+        let bob = age::x25519::Identity::generate().to_public().to_string();
+        vault.add_recipient("bob", &bob).unwrap();
+        vault.config.secrets.insert("API_KEY".to_string(), api_key);
+
+        let missing = vault.verify_recipients().unwrap();
+        assert_eq!(
+            missing,
+            vec![("bob".to_string(), vec!["API_KEY".to_string()])]
+        );
+
+        let coverage = vault.recipient_coverage().unwrap();
+        assert_eq!(
+            coverage,
+            vec![
+                ("API_KEY".to_string(), vec!["alice".to_string()]),
+                (
+                    "DB_URL".to_string(),
+                    vec!["alice".to_string(), "bob".to_string()]
+                ),
+            ]
+        );
+    }
+
     #[test]
     fn test_validate_member_name_rejects_path_separators() {
         let result = validate_member_name("../bob");
@@ -1095,6 +3976,267 @@ mod tests {
 
     // --- Lifecycle tests ---
 
+    #[test]
+    fn test_vault_clone_to_reencrypts_for_new_vault() {
+        let (_ctx, mut vault) = setup_test_vault();
+        vault.set("API_KEY", "secret123", false).unwrap();
+        vault.set_description("API_KEY", "payments").unwrap();
+
+        vault.clone_to("staging").unwrap();
+        let staging = Vault::open_vault(Some("staging")).unwrap();
+
+        assert_eq!(staging.get("API_KEY").unwrap().as_str(), "secret123");
+        assert_eq!(staging.config.recipients, vault.config.recipients);
+        assert_eq!(staging.description("API_KEY"), Some("payments"));
+        assert_ne!(
+            staging.config.secrets["API_KEY"],
+            vault.config.secrets["API_KEY"]
+        );
+        assert!(!staging.needs_sync());
+
+        let err = vault.clone_to("staging").unwrap_err();
+        assert!(matches!(
+            err,
+            Error::Config(ConfigError::AlreadyInitialized)
+        ));
+    }
+
+    #[test]
+    fn test_vault_open_reuses_backend_for_same_kms() {
+        let (_ctx, _vault) = setup_test_vault();
+        let kms = "arn:aws:kms:us-east-1:123:key/open-cache".to_string();
+
+        let inits = cipher::CipherBackend::client_inits();
+        Vault::init_vault(Some("one"), "alice", Some(kms.clone())).unwrap();
+        Vault::init_vault(Some("two"), "alice", Some(kms)).unwrap();
+        let mut one = Vault::open_vault(Some("one")).unwrap();
+        let mut two = Vault::open_vault(Some("two")).unwrap();
+        one.set("ONE", "1", false).unwrap();
+        two.set("TWO", "2", false).unwrap();
+
+        assert_eq!(cipher::CipherBackend::client_inits(), inits + 1);
+        assert!(std::sync::Arc::ptr_eq(&one.backend, &two.backend));
+    }
+
+    #[test]
+    fn test_vault_init_applies_profile() {
+        let (_ctx, _vault) = setup_test_vault();
+
+        let mut defaults = Config::load().unwrap();
+        defaults.profiles.insert(
+            "prod*".to_string(),
+            crate::core::config::Profile {
+                cipher: Some("hybrid".to_string()),
+                kms: Some(crate::core::config::KmsConfig {
+                    key: "arn:aws:kms:us-east-1:123:key/abc".to_string(),
+                }),
+            },
+        );
+        defaults.save().unwrap();
+
+        let prod = Vault::init_vault(Some("prod"), "alice", None).unwrap();
+        assert_eq!(
+            prod.config().kms_key(),
+            Some("arn:aws:kms:us-east-1:123:key/abc")
+        );
+
+        let dev = Vault::init_vault(Some("dev"), "alice", None).unwrap();
+        assert!(!dev.config().has_kms());
+    }
+
+    #[test]
+    fn test_vault_diff_vault() {
+        use crate::core::domain::EntryStatus;
+
+        let (_ctx, mut dev) = setup_test_vault();
+        dev.set("SAME", "shared", false).unwrap();
+        dev.set("CHANGED", "dev-value", false).unwrap();
+        dev.set("DEV_ONLY", "x", false).unwrap();
+
+        let mut prod = Vault::init_vault(Some("prod"), "alice", None).unwrap();
+        prod.set("SAME", "shared", false).unwrap();
+        prod.set("CHANGED", "prod-value", false).unwrap();
+        prod.set("PROD_ONLY", "y", false).unwrap();
+
+        let diff = dev.diff_vault("prod").unwrap();
+        let statuses: Vec<_> = diff
+            .entries()
+            .iter()
+            .map(|e| (e.key(), e.status().clone()))
+            .collect();
+        assert_eq!(
+            statuses,
+            vec![
+                ("CHANGED", EntryStatus::Modified),
+                ("DEV_ONLY", EntryStatus::VaultOnly),
+                ("PROD_ONLY", EntryStatus::EnvOnly),
+                ("SAME", EntryStatus::Synced),
+            ]
+        );
+
+        // Not a recipient of the other vault
+        let outsider = age::x25519::Identity::generate();
+        prod.config.recipients.clear();
+        prod.config
+            .recipients
+            .insert("bob".to_string(), outsider.to_public().to_string());
+        prod.config.save_to(Some("prod")).unwrap();
+        let err = dev.diff_vault("prod").unwrap_err();
+        assert!(matches!(
+            err,
+            Error::Config(ConfigError::NoAccessTo(ref name)) if name == "prod"
+        ));
+
+        assert!(dev.diff_vault("missing").is_err());
+    }
+
+    #[test]
+    fn test_vault_reencrypt_all_parallel() {
+        let (_ctx, mut vault) = setup_test_vault();
+
+        let pairs: Vec<(String, String)> = (0..PARALLEL_REENCRYPT_MIN * 3)
+            .map(|i| (format!("KEY_{:03}", i), format!("value-{}", i)))
+            .collect();
+        vault.set_many(&pairs, false).unwrap();
+
+        let bob = age::x25519::Identity::generate();
+        vault
+            .add_recipient("bob", &bob.to_public().to_string())
+            .unwrap();
+
+        let keys: Vec<&String> = vault.config.secrets.keys().collect();
+        let expected: Vec<&String> = pairs.iter().map(|(k, _)| k).collect();
+        assert_eq!(keys, expected);
+        for (key, value) in &pairs {
+            let ciphertext = vault.config.secrets.get(key).unwrap();
+            let plaintext = cipher::CipherBackend::Age
+                .decrypt(ciphertext, &bob)
+                .unwrap();
+            assert_eq!(&plaintext, value);
+        }
+    }
+
+    #[test]
+    fn test_vault_reencrypt_all_reports_progress() {
+        let (_ctx, mut vault) = setup_test_vault();
+        vault.set("A", "one", false).unwrap();
+
+        let mut seen = Vec::new();
+        vault
+            .reencrypt_all_with_progress(|done, total| seen.push((done, total)))
+            .unwrap();
+        assert_eq!(seen, vec![(1, 1)]);
+
+        // Parallel workers still report every secret, in order, on this thread
+        let pairs: Vec<(String, String)> = (0..PARALLEL_REENCRYPT_MIN * 3)
+            .map(|i| (format!("KEY_{:03}", i), format!("value-{}", i)))
+            .collect();
+        vault.set_many(&pairs, false).unwrap();
+        let total = vault.config.secrets.len();
+
+        let calls = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        let recorded = calls.clone();
+        vault.set_progress(move |done, total| recorded.lock().unwrap().push((done, total)));
+        let bob = age::x25519::Identity::generate().to_public().to_string();
+        vault.add_recipient("bob", &bob).unwrap();
+
+        let calls = calls.lock().unwrap();
+        let expected: Vec<(usize, usize)> = (1..=total).map(|done| (done, total)).collect();
+        assert_eq!(*calls, expected);
+    }
+
+    #[test]
+    fn test_vault_reencrypt_all_parallel_aborts_on_error() {
+        let (_ctx, mut vault) = setup_test_vault();
+
+        let pairs: Vec<(String, String)> = (0..PARALLEL_REENCRYPT_MIN * 3)
+            .map(|i| (format!("KEY_{:03}", i), format!("value-{}", i)))
+            .collect();
+        vault.set_many(&pairs, false).unwrap();
+        vault
+            .config
+            .secrets
+            .insert("KEY_020".to_string(), "corrupt".to_string());
+        let before = vault.config.secrets.clone();
+
+        assert!(vault.reencrypt_all().is_err());
+        assert_eq!(vault.config.secrets, before);
+    }
+
+    #[test]
+    fn test_vault_add_recipient_hybrid_skips_kms() {
+        use crate::core::cipher::envelope::StubKms;
+
+        let (_ctx, _vault) = setup_test_vault();
+        let kms = "arn:aws:kms:us-east-1:123:key/abc".to_string();
+        let mut vault = Vault::init_vault(Some("hybrid"), "alice", Some(kms)).unwrap();
+        vault.set("A", "one", false).unwrap();
+        vault.set("B", "two", false).unwrap();
+
+        let bob = age::x25519::Identity::generate();
+        let calls = StubKms::encrypt_calls();
+        vault
+            .add_recipient("bob", &bob.to_public().to_string())
+            .unwrap();
+        assert_eq!(StubKms::encrypt_calls(), calls);
+
+        let ciphertext = vault.config.secrets.get("A").unwrap();
+        let plaintext = cipher::CipherBackend::Age
+            .decrypt(ciphertext, &bob)
+            .unwrap();
+        assert_eq!(plaintext, "one");
+
+        // Switching KMS keys must go back to KMS for every secret
+        vault.config.kms = Some(crate::core::config::KmsConfig {
+            key: "arn:aws:kms:us-east-1:123:key/new".to_string(),
+        });
+        vault.backend = cipher::CipherBackend::shared(&vault.config).unwrap();
+        let calls = StubKms::encrypt_calls();
+        vault.reencrypt_all().unwrap();
+        assert_eq!(StubKms::encrypt_calls(), calls + 2);
+    }
+
+    #[test]
+    fn test_vault_get_cached_skips_kms_within_ttl() {
+        use crate::core::cipher::envelope::{Envelope, KmsBackend, KmsProvider, StubKms};
+        use crate::core::cipher::Cipher;
+        use std::time::Duration;
+
+        let (_ctx, _vault) = setup_test_vault();
+        let kms = "arn:aws:kms:us-east-1:123:key/abc".to_string();
+        let mut vault = Vault::init_vault(Some("cached"), "alice", Some(kms)).unwrap();
+        vault.set("TOKEN", "placeholder", false).unwrap();
+
+        // Only the KMS half decrypts, so every uncached read goes to KMS
+        let outsider = age::x25519::Identity::generate().to_public();
+        let age = cipher::Age
+            .encrypt("from-kms", &[cipher::AgeRecipient::X25519(outsider)])
+            .unwrap();
+        let envelope = Envelope::new(
+            age,
+            Some(StubKms.encrypt("from-kms").unwrap()),
+            Some(&KmsProvider::Aws),
+        );
+        vault
+            .config
+            .secrets
+            .insert("TOKEN".to_string(), envelope.seal().unwrap());
+
+        let calls = StubKms::decrypt_calls();
+        let ttl = Duration::from_millis(300);
+        assert_eq!(vault.get_cached("TOKEN", ttl).unwrap().as_str(), "from-kms");
+        assert_eq!(vault.get_cached("TOKEN", ttl).unwrap().as_str(), "from-kms");
+        assert_eq!(StubKms::decrypt_calls(), calls + 1);
+
+        std::thread::sleep(Duration::from_millis(400));
+        assert_eq!(vault.get_cached("TOKEN", ttl).unwrap().as_str(), "from-kms");
+        assert_eq!(StubKms::decrypt_calls(), calls + 2);
+
+        // A changed value is a miss, not a stale read
+        vault.set("TOKEN", "rotated", true).unwrap();
+        assert_eq!(vault.get_cached("TOKEN", ttl).unwrap().as_str(), "rotated");
+    }
+
     #[test]
     fn test_vault_import() {
         let (_ctx, mut vault) = setup_test_vault();
@@ -1102,13 +4244,115 @@ mod tests {
         let env_content = "IMPORT_ONE=value1\nIMPORT_TWO=value2\n";
         fs::write(".env.test", env_content).unwrap();
 
-        let imported = vault.import(".env.test").unwrap();
-        assert_eq!(imported.len(), 2);
+        let imported = vault.import(".env.test", OnConflict::Error).unwrap();
+        assert_eq!(imported.created.len(), 2);
 
         assert_eq!(vault.get("IMPORT_ONE").unwrap().as_str(), "value1");
         assert_eq!(vault.get("IMPORT_TWO").unwrap().as_str(), "value2");
     }
 
+    #[test]
+    fn test_vault_import_conflict_policy() {
+        let (_ctx, mut vault) = setup_test_vault();
+        vault.set("KEEP", "old", false).unwrap();
+
+        fs::write(".env.test", "KEEP=new\nFRESH=1\n").unwrap();
+
+        // Error refuses before writing anything
+        let err = vault.import(".env.test", OnConflict::Error).unwrap_err();
+        assert!(err.to_string().contains("KEEP"));
+        assert!(vault.get("FRESH").is_err());
+
+        let result = vault.import(".env.test", OnConflict::Skip).unwrap();
+        assert_eq!(result.created, vec!["FRESH".to_string()]);
+        assert_eq!(result.skipped, vec!["KEEP".to_string()]);
+        assert_eq!(vault.get("KEEP").unwrap().as_str(), "old");
+
+        let result = vault.import(".env.test", OnConflict::Overwrite).unwrap();
+        assert!(result.created.is_empty());
+        assert_eq!(
+            result.overwritten,
+            vec!["KEEP".to_string(), "FRESH".to_string()]
+        );
+        assert_eq!(result.written(), 2);
+        assert_eq!(vault.get("KEEP").unwrap().as_str(), "new");
+    }
+
+    #[test]
+    fn test_vault_import_with_prefix_strip() {
+        let (_ctx, mut vault) = setup_test_vault();
+
+        fs::write(".env.test", "API_TOKEN=a\nAPI_URL=b\nWEB_TOKEN=c\n").unwrap();
+
+        let opts = ImportOptions {
+            prefix: Some("API_".to_string()),
+            strip_prefix: true,
+            ..Default::default()
+        };
+        let imported = vault.import_with(".env.test", &opts).unwrap();
+        assert_eq!(
+            imported.created,
+            vec!["TOKEN".to_string(), "URL".to_string()]
+        );
+        assert_eq!(vault.get("TOKEN").unwrap().as_str(), "a");
+        assert!(vault.get("WEB_TOKEN").is_err());
+        assert!(vault.get("API_TOKEN").is_err());
+    }
+
+    #[test]
+    fn test_vault_import_duplicate_keys() {
+        let (_ctx, mut vault) = setup_test_vault();
+
+        fs::write(".env.test", "KEY=a\nOTHER=x\nKEY=b\n").unwrap();
+
+        let strict = ImportOptions {
+            strict: true,
+            ..Default::default()
+        };
+        let err = vault.import_with(".env.test", &strict).unwrap_err();
+        assert!(err.to_string().contains("KEY (lines 1, 3)"));
+        assert!(vault.list().is_empty());
+
+        vault
+            .import_with(".env.test", &ImportOptions::default())
+            .unwrap();
+        assert_eq!(vault.get("KEY").unwrap().as_str(), "b");
+    }
+
+    #[test]
+    fn test_vault_import_strip_validates_stripped_name() {
+        let (_ctx, mut vault) = setup_test_vault();
+
+        fs::write(".env.test", "SVC_1ST=a\n").unwrap();
+
+        let opts = ImportOptions {
+            prefix: Some("SVC_".to_string()),
+            strip_prefix: true,
+            ..Default::default()
+        };
+        assert!(vault.import_with(".env.test", &opts).is_err());
+        assert!(vault.list().is_empty());
+    }
+
+    #[test]
+    fn test_vault_verify_all_reports_failures() {
+        let (_ctx, mut vault) = setup_test_vault();
+
+        vault.set("GOOD", "fine", false).unwrap();
+        vault.set("BAD", "broken", false).unwrap();
+        vault
+            .config
+            .secrets
+            .insert("BAD".to_string(), "not ciphertext".to_string());
+
+        let results = vault.verify_all().unwrap();
+        assert_eq!(results.len(), 2);
+        let bad = results.iter().find(|(k, _)| k == "BAD").unwrap();
+        assert!(bad.1.is_some());
+        let good = results.iter().find(|(k, _)| k == "GOOD").unwrap();
+        assert!(good.1.is_none());
+    }
+
     #[test]
     fn test_vault_export_roundtrip() {
         let (_ctx, mut vault) = setup_test_vault();
@@ -1122,4 +4366,44 @@ mod tests {
         assert!(exported.contains("EXPORT_KEY=export_value"));
         assert!(exported.contains("ANOTHER_KEY=another_value"));
     }
+
+    #[test]
+    fn test_vault_backend_mismatch_after_cipher_change() {
+        let (_ctx, mut vault) = setup_test_vault();
+        assert_eq!(vault.backend_mismatch(), None);
+
+        vault.set("KEY", "value", false).unwrap();
+        vault.config.dugout.cipher = Some("vault-transit".to_string());
+        vault.config.vault = Some(crate::core::config::TransitConfig {
+            address: "https://vault.example.com".to_string(),
+            key: "dugout".to_string(),
+            mount: "transit".to_string(),
+            token_env: "VAULT_TOKEN".to_string(),
+        });
+        vault.config.save_to(None).unwrap();
+
+        let reopened = Vault::open().unwrap();
+        assert_eq!(reopened.backend_mismatch(), Some(("age", "hybrid+vault")));
+    }
+
+    #[test]
+    fn test_vault_export_filtered() {
+        let (_ctx, mut vault) = setup_test_vault();
+
+        vault.set("DB_HOST", "localhost", false).unwrap();
+        vault.set("DB_PASSWORD", "hunter2", false).unwrap();
+        vault.set("STRIPE_KEY", "sk_test", false).unwrap();
+
+        let env = vault.export_filtered("DB_*").unwrap();
+        let keys: Vec<&str> = env.entries().iter().map(|(k, _)| k.as_str()).collect();
+        assert_eq!(keys, ["DB_HOST", "DB_PASSWORD"]);
+
+        let env = vault.export_filtered("*_KEY").unwrap();
+        assert_eq!(
+            env.entries(),
+            [("STRIPE_KEY".to_string(), "sk_test".to_string())]
+        );
+
+        assert!(vault.export_filtered("REDIS_*").unwrap().is_empty());
+    }
 }
@@ -1,6 +1,23 @@
 //! Error types for Dugout
 //!
 //! Domain-specific error types following best practices.
+//!
+//! # Exit codes
+//!
+//! The CLI exits with [`Error::exit_code`]. These are stable; scripts and CI
+//! can branch on them.
+//!
+//! | Code | Meaning |
+//! |------|---------|
+//! | 1 | Any other failure |
+//! | 2 | Invalid command-line usage (reported by clap) |
+//! | 3 | Access denied: no identity, not a recipient, not an admin |
+//! | 4 | Vault not initialized |
+//! | 5 | Secret, recipient or access request not found |
+//! | 6 | Invalid input ([`ValidationError`]) |
+//! | 7 | Encryption or decryption failed ([`CipherError`]) |
+//! | 8 | Vault file can't be read, parsed, or has a merge conflict |
+//! | 9 | Already exists: vault, secret, or a `--parent` vault |
 
 use thiserror::Error;
 
@@ -13,18 +30,47 @@ pub enum ConfigError {
     #[error("already initialized: .dugout.toml exists")]
     AlreadyInitialized,
 
+    #[error("a parent directory already has a vault: {0}\n  → Run dugout from that project, or pass --here to create a nested vault")]
+    ParentVault(String),
+
     #[error("no recipients configured")]
     NoRecipients,
 
     #[error("recipient not found: {0}")]
     RecipientNotFound(String),
 
-    #[error("identity does not have access to this vault")]
+    #[error("found your identity, but it is not a recipient of this vault")]
     AccessDenied,
 
+    #[error("no identity found for this vault\n  → Run `dugout knock` to create one and request access, or set DUGOUT_IDENTITY")]
+    NoIdentity,
+
+    #[error("your identity is not a recipient of this vault")]
+    NotRecipient,
+
+    #[error("you don't have access to vault '{0}'\n  → Ask one of its members to run `dugout --vault {0} team add <name> <your key>`")]
+    NoAccessTo(String),
+
+    #[error("your key no longer matches recipient '{0}' in this vault\n  → Your identity was regenerated after you were added. Run `dugout knock`, or ask a member to run `dugout team add {0} <your new key>`")]
+    StaleRecipient(String),
+
+    #[error("vault '{0}' is protected\n  → Pass --yes to confirm changes non-interactively")]
+    Protected(String),
+
+    #[error("{0} is not an admin of this vault\n  → Ask an admin to run this, or have one run `dugout team role {0} admin`")]
+    NotAdmin(String),
+
+    #[error(
+        "admit not confirmed\n  → Check the key fingerprint with the requester, then pass --yes"
+    )]
+    AdmitUnconfirmed,
+
     #[error("multiple vaults found\n\n{vaults}\n\nspecify which vault to use:\n\n  dugout --vault <name> <command>\n\nor set DUGOUT_VAULT environment variable:\n\n  export DUGOUT_VAULT=<name>")]
     MultipleVaults { vaults: String },
 
+    #[error("{0} KMS support is not compiled into this build\n  → Rebuild with: cargo install dugout --features {0}")]
+    BackendNotCompiled(&'static str),
+
     #[error("missing required field: {field}")]
     MissingField { field: &'static str },
 
@@ -34,6 +80,9 @@ pub enum ConfigError {
     #[error("failed to read config file: {0}")]
     ReadFile(#[source] std::io::Error),
 
+    #[error("{path} has unresolved merge conflict markers (line {line})")]
+    MergeConflict { path: String, line: usize },
+
     #[error("config file is malformed: {0}")]
     Parse(#[source] toml::de::Error),
 
@@ -44,6 +93,36 @@ pub enum ConfigError {
     Other(String),
 }
 
+impl ConfigError {
+    /// Stable snake_case name of the variant, for machine-readable output
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::NotInitialized => "not_initialized",
+            Self::AlreadyInitialized => "already_initialized",
+            Self::ParentVault(_) => "parent_vault",
+            Self::NoRecipients => "no_recipients",
+            Self::RecipientNotFound(_) => "recipient_not_found",
+            Self::AccessDenied => "access_denied",
+            Self::NoIdentity => "no_identity",
+            Self::NotRecipient => "not_recipient",
+            Self::NoAccessTo(_) => "no_access_to",
+            Self::StaleRecipient(_) => "stale_recipient",
+            Self::Protected(_) => "protected",
+            Self::NotAdmin(_) => "not_admin",
+            Self::AdmitUnconfirmed => "admit_unconfirmed",
+            Self::MultipleVaults { .. } => "multiple_vaults",
+            Self::BackendNotCompiled(_) => "backend_not_compiled",
+            Self::MissingField { .. } => "missing_field",
+            Self::InvalidValue { .. } => "invalid_value",
+            Self::ReadFile(_) => "read_file",
+            Self::MergeConflict { .. } => "merge_conflict",
+            Self::Parse(_) => "parse",
+            Self::Serialize(_) => "serialize",
+            Self::Other(_) => "other",
+        }
+    }
+}
+
 /// Cryptographic operation errors
 #[derive(Error, Debug)]
 pub enum CipherError {
@@ -66,6 +145,20 @@ pub enum CipherError {
     Io(#[source] std::io::Error),
 }
 
+impl CipherError {
+    /// Stable snake_case name of the variant, for machine-readable output
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::EncryptionFailed(_) => "encryption_failed",
+            Self::DecryptionFailed(_) => "decryption_failed",
+            Self::InvalidPublicKey(_) => "invalid_public_key",
+            Self::InvalidSecretKey(_) => "invalid_secret_key",
+            Self::ArmorFailed(_) => "armor_failed",
+            Self::Io(_) => "io",
+        }
+    }
+}
+
 /// Key storage and management errors
 #[derive(Error, Debug)]
 pub enum StoreError {
@@ -84,6 +177,12 @@ pub enum StoreError {
     #[error("invalid key format: {0}")]
     InvalidFormat(String),
 
+    #[error("{0} is passphrase-protected\n  → Run from a terminal, or set DUGOUT_PASSPHRASE")]
+    PassphraseRequired(String),
+
+    #[error("failed to unlock identity: {0}")]
+    PassphraseFailed(String),
+
     #[cfg(target_os = "macos")]
     #[error("Keychain error: {0}")]
     KeychainError(String),
@@ -92,10 +191,44 @@ pub enum StoreError {
     #[error("Keychain access denied (user cancelled or locked)")]
     KeychainAccessDenied,
 
+    #[cfg(target_os = "windows")]
+    #[error("Credential Manager error: {0}")]
+    CredentialManagerError(String),
+
+    #[cfg(feature = "secret-service")]
+    #[error(
+        "Secret Service error: {0}\n  → Set DUGOUT_NO_KEYCHAIN=1 to store keys on disk instead"
+    )]
+    SecretServiceError(String),
+
     #[error("Migration failed: {0}")]
     MigrationFailed(String),
 }
 
+impl StoreError {
+    /// Stable snake_case name of the variant, for machine-readable output
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::NoPrivateKey(_) => "no_private_key",
+            Self::GenerationFailed(_) => "generation_failed",
+            Self::ReadFailed(_) => "read_failed",
+            Self::WriteFailed(_) => "write_failed",
+            Self::InvalidFormat(_) => "invalid_format",
+            Self::PassphraseRequired(_) => "passphrase_required",
+            Self::PassphraseFailed(_) => "passphrase_failed",
+            #[cfg(target_os = "macos")]
+            Self::KeychainError(_) => "keychain_error",
+            #[cfg(target_os = "macos")]
+            Self::KeychainAccessDenied => "keychain_access_denied",
+            #[cfg(target_os = "windows")]
+            Self::CredentialManagerError(_) => "credential_manager_error",
+            #[cfg(feature = "secret-service")]
+            Self::SecretServiceError(_) => "secret_service_error",
+            Self::MigrationFailed(_) => "migration_failed",
+        }
+    }
+}
+
 /// Secret operation errors
 #[derive(Error, Debug)]
 pub enum SecretError {
@@ -105,17 +238,40 @@ pub enum SecretError {
     #[error("secret already exists: {0} (use --force to overwrite)")]
     AlreadyExists(String),
 
+    #[error("already in the vault: {0}\n  → Pass --on-conflict skip or --on-conflict overwrite")]
+    ImportConflict(String),
+
+    #[error("alias cycle: {0}")]
+    AliasCycle(String),
+
     #[error("failed to set secret: {0}")]
     SetFailed(String),
 
     #[error("failed to get secret: {0}")]
     GetFailed(String),
 
+    #[error("{0} hasn't been encrypted for you yet\n  → You're a recipient, but it was last written before you were added. Ask a member to run `dugout sync`")]
+    NotEncryptedForYou(String),
+
     #[error("failed to remove secret: {0}")]
     RemoveFailed(String),
 }
 
 impl SecretError {
+    /// Stable snake_case name of the variant, for machine-readable output
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::NotFound { .. } => "not_found",
+            Self::AlreadyExists(_) => "already_exists",
+            Self::ImportConflict(_) => "import_conflict",
+            Self::AliasCycle(_) => "alias_cycle",
+            Self::SetFailed(_) => "set_failed",
+            Self::GetFailed(_) => "get_failed",
+            Self::NotEncryptedForYou(_) => "not_encrypted_for_you",
+            Self::RemoveFailed(_) => "remove_failed",
+        }
+    }
+
     /// Create a NotFound error with suggestions based on available keys
     pub fn not_found_with_suggestions(key: String, available_keys: &[String]) -> Self {
         let suggestion = if available_keys.is_empty() {
@@ -144,9 +300,33 @@ pub enum ValidationError {
     #[error("empty value is not allowed for key '{0}'")]
     EmptyValue(String),
 
+    #[error("value for '{key}' does not match pattern '{pattern}'")]
+    PatternMismatch { key: String, pattern: String },
+
+    #[error("value for '{0}' needs quotes\n  → Use --quote minimal or --quote always")]
+    NeedsQuoting(String),
+
+    #[error("can't import {format} JSON: {reason}\n  → Pass the output of `{command}`")]
+    UnrecognizedImport {
+        format: &'static str,
+        command: &'static str,
+        reason: String,
+    },
+
+    #[error("'{key}' references undefined variable '{var}'")]
+    UndefinedVariable { key: String, var: String },
+
+    #[error("duplicate keys: {0}\n  → Keep one of each, or import without --strict")]
+    DuplicateKeys(String),
+
     #[error("invalid member name '{name}': {reason}")]
     InvalidMemberName { name: String, reason: String },
 
+    #[error(
+        "invalid access request from '{name}': {reason}\n  → Ask them to run `dugout knock` again"
+    )]
+    InvalidRequest { name: String, reason: String },
+
     #[error("invalid vault name '{name}': {reason}")]
     InvalidVaultName { name: String, reason: String },
 
@@ -158,6 +338,26 @@ pub enum ValidationError {
     },
 }
 
+impl ValidationError {
+    /// Stable snake_case name of the variant, for machine-readable output
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::InvalidKey { .. } => "invalid_key",
+            Self::EmptyKey => "empty_key",
+            Self::EmptyValue(_) => "empty_value",
+            Self::PatternMismatch { .. } => "pattern_mismatch",
+            Self::NeedsQuoting(_) => "needs_quoting",
+            Self::UnrecognizedImport { .. } => "unrecognized_import",
+            Self::UndefinedVariable { .. } => "undefined_variable",
+            Self::DuplicateKeys(_) => "duplicate_keys",
+            Self::InvalidMemberName { .. } => "invalid_member_name",
+            Self::InvalidRequest { .. } => "invalid_request",
+            Self::InvalidVaultName { .. } => "invalid_vault_name",
+            Self::InvalidPermissions { .. } => "invalid_permissions",
+        }
+    }
+}
+
 /// Top-level error type for all dugout operations
 #[derive(Error, Debug)]
 pub enum Error {
@@ -189,6 +389,60 @@ pub enum Error {
     Other(String),
 }
 
+impl Error {
+    /// Stable `category.variant` name, e.g. `config.not_initialized`
+    ///
+    /// Used by `--error-format json`; tooling can match on it, so existing
+    /// names must not change.
+    pub fn kind(&self) -> String {
+        let (category, variant) = match self {
+            Error::Config(e) => ("config", e.kind()),
+            Error::Cipher(e) => ("cipher", e.kind()),
+            Error::Store(e) => ("store", e.kind()),
+            Error::Secret(e) => ("secret", e.kind()),
+            Error::Validation(e) => ("validation", e.kind()),
+            Error::Io(_) => return "io".to_string(),
+            Error::Json(_) => return "json".to_string(),
+            Error::Dialog(_) => return "dialog".to_string(),
+            Error::Other(_) => return "other".to_string(),
+        };
+        format!("{}.{}", category, variant)
+    }
+
+    /// Process exit code for this error (see the table in the module docs)
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            Error::Config(e) => match e {
+                ConfigError::AccessDenied
+                | ConfigError::NoIdentity
+                | ConfigError::NotRecipient
+                | ConfigError::NoAccessTo(_)
+                | ConfigError::StaleRecipient(_)
+                | ConfigError::NotAdmin(_) => 3,
+                ConfigError::NotInitialized => 4,
+                ConfigError::RecipientNotFound(_) => 5,
+                ConfigError::ReadFile(_)
+                | ConfigError::Parse(_)
+                | ConfigError::MissingField { .. }
+                | ConfigError::InvalidValue { .. }
+                | ConfigError::MergeConflict { .. } => 8,
+                ConfigError::AlreadyInitialized | ConfigError::ParentVault(_) => 9,
+                _ => 1,
+            },
+            Error::Secret(e) => match e {
+                SecretError::NotEncryptedForYou(_) => 3,
+                SecretError::NotFound { .. } => 5,
+                SecretError::AlreadyExists(_) | SecretError::ImportConflict(_) => 9,
+                _ => 1,
+            },
+            Error::Store(StoreError::NoPrivateKey(_)) => 3,
+            Error::Validation(_) => 6,
+            Error::Cipher(_) => 7,
+            _ => 1,
+        }
+    }
+}
+
 // Manual From impl for dialoguer::Error since it doesn't implement std::error::Error
 impl From<dialoguer::Error> for Error {
     fn from(err: dialoguer::Error) -> Self {
@@ -198,3 +452,45 @@ impl From<dialoguer::Error> for Error {
 
 /// Result type alias for all dugout operations
 pub type Result<T> = std::result::Result<T, Error>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_error_kind_is_category_and_variant() {
+        assert_eq!(
+            Error::from(ConfigError::NotInitialized).kind(),
+            "config.not_initialized"
+        );
+        assert_eq!(
+            Error::from(SecretError::not_found_with_suggestions(
+                "X".to_string(),
+                &[]
+            ))
+            .kind(),
+            "secret.not_found"
+        );
+        assert_eq!(Error::Other("x".to_string()).kind(), "other");
+    }
+
+    #[test]
+    fn test_exit_codes_by_category() {
+        assert_eq!(Error::from(ConfigError::AccessDenied).exit_code(), 3);
+        assert_eq!(Error::from(ConfigError::NotInitialized).exit_code(), 4);
+        assert_eq!(
+            Error::from(SecretError::not_found_with_suggestions(
+                "X".to_string(),
+                &[]
+            ))
+            .exit_code(),
+            5
+        );
+        assert_eq!(Error::from(ValidationError::EmptyKey).exit_code(), 6);
+        assert_eq!(
+            Error::from(CipherError::DecryptionFailed("x".to_string())).exit_code(),
+            7
+        );
+        assert_eq!(Error::Other("x".to_string()).exit_code(), 1);
+    }
+}
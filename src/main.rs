@@ -4,7 +4,7 @@ use clap::Parser;
 use tracing_subscriber::{fmt, prelude::*, EnvFilter};
 
 use dugout::cli::output;
-use dugout::cli::{execute, Cli};
+use dugout::cli::{execute, Cli, ErrorFormat};
 
 fn main() {
     let cli = Cli::parse();
@@ -20,10 +20,26 @@ fn main() {
 
     tracing_subscriber::registry()
         .with(filter)
-        .with(fmt::layer().with_target(false).without_time())
+        .with(
+            // Logs go to stderr so they never mix into `get`, `export` or JSON output
+            fmt::layer()
+                .with_target(false)
+                .without_time()
+                .with_writer(std::io::stderr),
+        )
         .init();
 
-    if let Err(e) = execute(cli.command, cli.vault) {
+    output::set_quiet(cli.quiet);
+
+    if let Err(e) = execute(cli.command, cli.vault, cli.yes) {
+        if cli.error_format == ErrorFormat::Json {
+            let error = serde_json::json!({
+                "error": { "kind": e.kind(), "message": e.to_string() }
+            });
+            eprintln!("{}", error);
+            std::process::exit(e.exit_code());
+        }
+
         // Format error with suggestion if available
         let error_msg = e.to_string();
         let suggestion = match &e {
@@ -36,6 +52,12 @@ fn main() {
             dugout::error::Error::Config(dugout::error::ConfigError::AccessDenied) => {
                 Some("run: dugout knock")
             }
+            dugout::error::Error::Config(dugout::error::ConfigError::MergeConflict { .. }) => {
+                Some("run: dugout resolve (or fix the conflict by hand)")
+            }
+            dugout::error::Error::Validation(
+                dugout::error::ValidationError::UndefinedVariable { .. },
+            ) => Some("define it earlier in the file, or pass --allow-undefined"),
             _ => None,
         };
 
@@ -43,6 +65,6 @@ fn main() {
         if let Some(hint) = suggestion {
             output::hint(hint);
         }
-        std::process::exit(1);
+        std::process::exit(e.exit_code());
     }
 }
@@ -6,16 +6,30 @@ mod support;
 mod add;
 #[path = "cli/check.rs"]
 mod check;
+#[path = "cli/crypt.rs"]
+mod crypt;
 #[path = "cli/dot.rs"]
 mod dot;
 #[path = "cli/errors.rs"]
 mod errors;
+#[path = "cli/hook.rs"]
+mod hook;
 #[path = "cli/init.rs"]
 mod init;
 #[path = "cli/knock.rs"]
 mod knock;
+#[path = "cli/log.rs"]
+mod log;
+#[path = "cli/protect.rs"]
+mod protect;
+#[path = "cli/render.rs"]
+mod render;
+#[path = "cli/resolve.rs"]
+mod resolve;
 #[path = "cli/run.rs"]
 mod run;
+#[path = "cli/schema.rs"]
+mod schema;
 #[path = "cli/secrets.rs"]
 mod secrets;
 #[path = "cli/setup.rs"]
@@ -0,0 +1,138 @@
+//! Tests for `dugout encrypt` and `dugout decrypt`.
+
+use crate::support::*;
+
+#[test]
+fn test_encrypt_to_recipient_and_decrypt_from_stdin() {
+    let t = Test::new();
+    let (public_key, private_key) = generate_age_keypair();
+
+    let output = t
+        .cmd()
+        .args(["encrypt", "--recipient", &public_key, "hand-off-value"])
+        .output()
+        .unwrap();
+    assert_success(&output);
+    let ciphertext = stdout(&output);
+    assert!(ciphertext.contains("BEGIN AGE ENCRYPTED FILE"));
+    assert!(!ciphertext.contains("hand-off-value"));
+
+    let output = t
+        .cmd()
+        .env("DUGOUT_IDENTITY", &private_key)
+        .args(["decrypt", "-"])
+        .write_stdin(ciphertext)
+        .output()
+        .unwrap();
+    assert_success(&output);
+    assert_eq!(stdout(&output), "hand-off-value");
+}
+
+#[test]
+fn test_decrypt_with_wrong_identity_fails() {
+    let t = Test::new();
+    let (public_key, _) = generate_age_keypair();
+    let (_, other_key) = generate_age_keypair();
+
+    let output = t
+        .cmd()
+        .args(["encrypt", "-r", &public_key])
+        .write_stdin("value")
+        .output()
+        .unwrap();
+    assert_success(&output);
+
+    let output = t
+        .cmd()
+        .env("DUGOUT_IDENTITY", &other_key)
+        .arg("decrypt")
+        .write_stdin(stdout(&output))
+        .output()
+        .unwrap();
+    assert_failure(&output);
+}
+
+#[test]
+fn test_encrypt_rejects_invalid_recipient() {
+    let t = Test::new();
+
+    let output = t
+        .cmd()
+        .args(["encrypt", "--recipient", "not-a-key", "value"])
+        .output()
+        .unwrap();
+    assert_failure(&output);
+}
+
+#[test]
+fn test_decrypt_reports_invalid_identity_env() {
+    let t = Test::new();
+
+    let output = t
+        .cmd()
+        .env("DUGOUT_IDENTITY", "not-a-key")
+        .arg("decrypt")
+        .write_stdin("ciphertext")
+        .output()
+        .unwrap();
+    assert_failure(&output);
+    assert_stderr_contains(&output, "invalid value for DUGOUT_IDENTITY");
+
+    let output = t
+        .cmd()
+        .env("DUGOUT_IDENTITY_FILE", t.dir.path().join("missing.key"))
+        .arg("decrypt")
+        .write_stdin("ciphertext")
+        .output()
+        .unwrap();
+    assert_failure(&output);
+    assert_stderr_contains(&output, "missing.key does not exist");
+}
+
+#[test]
+fn test_decrypt_passes_through_identity_unlock_errors() {
+    let t = Test::new();
+
+    let output = t
+        .cmd()
+        .args(["setup", "--passphrase"])
+        .env("DUGOUT_PASSPHRASE", "correct horse")
+        .output()
+        .unwrap();
+    assert_success(&output);
+
+    // Ciphertext on stdin leaves no terminal for the passphrase prompt
+    let output = t
+        .cmd()
+        .arg("decrypt")
+        .write_stdin("ciphertext")
+        .output()
+        .unwrap();
+    assert_failure(&output);
+    assert_stderr_contains(&output, "passphrase-protected");
+    assert!(!stderr(&output).contains("no identity found"));
+
+    let output = t
+        .cmd()
+        .arg("decrypt")
+        .env("DUGOUT_PASSPHRASE", "wrong horse")
+        .write_stdin("ciphertext")
+        .output()
+        .unwrap();
+    assert_failure(&output);
+    assert_stderr_contains(&output, "failed to unlock identity");
+}
+
+#[test]
+fn test_decrypt_without_identity_suggests_setup() {
+    let t = Test::new();
+
+    let output = t
+        .cmd()
+        .arg("decrypt")
+        .write_stdin("ciphertext")
+        .output()
+        .unwrap();
+    assert_failure(&output);
+    assert_stderr_contains(&output, "no identity found");
+}
@@ -98,3 +98,47 @@ fn test_completions_powershell() {
         "powershell completion should contain PowerShell-specific syntax"
     );
 }
+
+#[test]
+fn test_get_explains_missing_access() {
+    let t = Test::with_secrets("alice", &[("API_KEY", "sk-123")]);
+    let path = t.dir.path().join(".dugout.toml");
+    let original = std::fs::read_to_string(&path).unwrap();
+    let (bob_public, bob_private) = generate_age_keypair();
+
+    // Only Bob's key (from the environment) is available from here on
+    std::fs::remove_dir_all(t.home.path().join(".dugout")).unwrap();
+
+    // (c) A recipient added without re-encrypting can't read older secrets yet
+    let with_bob = original.replacen(
+        "[recipients]\n",
+        &format!("[recipients]\nbob = \"{}\"\n", bob_public),
+        1,
+    );
+    std::fs::write(&path, with_bob).unwrap();
+    let output = t
+        .cmd()
+        .env("DUGOUT_IDENTITY", &bob_private)
+        .args(["get", "API_KEY"])
+        .output()
+        .unwrap();
+    assert_failure(&output);
+    assert_stderr_contains(&output, "API_KEY hasn't been encrypted for you yet");
+    assert_stderr_contains(&output, "dugout sync");
+
+    // (b) An identity that isn't a recipient at all
+    std::fs::write(&path, &original).unwrap();
+    let output = t
+        .cmd()
+        .env("DUGOUT_IDENTITY", &bob_private)
+        .args(["get", "API_KEY"])
+        .output()
+        .unwrap();
+    assert_failure(&output);
+    assert_stderr_contains(&output, "found your identity, but it is not a recipient");
+
+    // (a) No identity anywhere
+    let output = t.get("API_KEY");
+    assert_failure(&output);
+    assert_stderr_contains(&output, "no identity found for this vault");
+}
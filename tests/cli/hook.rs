@@ -0,0 +1,42 @@
+//! Tests for `dugout shell-hook` and `dugout env --export`.
+
+use crate::support::*;
+
+#[test]
+fn test_shell_hook_references_export_and_marker() {
+    let t = Test::new();
+
+    for shell in ["bash", "zsh", "fish"] {
+        let output = t.cmd().args(["shell-hook", shell]).output().unwrap();
+        assert_success(&output);
+        assert_stdout_contains(&output, &format!("dugout env --export --shell {}", shell));
+        assert_stdout_contains(&output, ".dugout/autoload");
+        assert_stdout_contains(&output, ".dugout.toml");
+    }
+}
+
+#[test]
+fn test_env_export_quotes_values() {
+    let t = Test::with_secrets("test-user", &[("API_KEY", "it's secret")]);
+
+    let output = t.cmd().args(["env", "--export"]).output().unwrap();
+    assert_success(&output);
+    assert_stdout_contains(&output, r"export API_KEY='it'\''s secret'");
+    assert_stdout_contains(&output, "_DUGOUT_KEYS='API_KEY'");
+
+    let output = t
+        .cmd()
+        .args(["env", "--export", "--shell", "fish"])
+        .output()
+        .unwrap();
+    assert_success(&output);
+    assert_stdout_contains(&output, r"set -gx API_KEY 'it\'s secret'");
+}
+
+#[test]
+fn test_env_shell_requires_export() {
+    let t = Test::init("test-user");
+
+    let output = t.cmd().args(["env", "--shell", "fish"]).output().unwrap();
+    assert_failure(&output);
+}
@@ -138,3 +138,56 @@ fn test_init_vault_name_too_long() {
     assert_failure(&output);
     assert_stderr_contains(&output, "64 characters");
 }
+
+#[cfg(not(any(feature = "aws", feature = "test-kms")))]
+#[test]
+fn test_init_kms_without_feature_writes_nothing() {
+    let t = Test::new();
+
+    let output = t
+        .cmd()
+        .args([
+            "init",
+            "--no-banner",
+            "--name",
+            "alice",
+            "--kms",
+            "arn:aws:kms:us-east-1:123:key/abc",
+        ])
+        .output()
+        .unwrap();
+    assert_failure(&output);
+    assert_stderr_contains(&output, "--features aws");
+
+    // No half-configured vault: nothing in the project or key directory
+    assert_eq!(fs::read_dir(t.dir.path()).unwrap().count(), 0);
+    assert!(!t.home.path().join(".dugout/keys").exists());
+}
+
+#[test]
+fn test_init_in_subdir_of_initialized_project() {
+    let t = Test::init("alice");
+    let sub = t.dir.path().join("services").join("api");
+    fs::create_dir_all(&sub).unwrap();
+
+    let output = t
+        .cmd()
+        .current_dir(&sub)
+        .args(["init", "--no-banner", "--name", "alice"])
+        .output()
+        .unwrap();
+    assert_failure(&output);
+    assert_stderr_contains(&output, "a parent directory already has a vault");
+    assert_stderr_contains(&output, "--here");
+    assert!(!sub.join(".dugout.toml").exists());
+
+    let output = t
+        .cmd()
+        .current_dir(&sub)
+        .args(["init", "--no-banner", "--name", "alice", "--here"])
+        .output()
+        .unwrap();
+    assert_success(&output);
+    assert_stdout_contains(&output, "creating a nested vault");
+    assert!(sub.join(".dugout.toml").exists());
+}
@@ -120,7 +120,7 @@ fn test_admit_approves_request() {
     assert_success(&knock_output);
 
     // Admit bob
-    let output = t.cmd().args(["admit", "bob"]).output().unwrap();
+    let output = t.cmd().args(["admit", "bob", "--yes"]).output().unwrap();
     assert_success(&output);
     assert_stdout_contains(&output, "admitted");
 
@@ -134,6 +134,36 @@ fn test_admit_approves_request() {
     assert_stdout_contains(&team_output, "bob");
 }
 
+#[test]
+fn test_admit_shows_fingerprint_and_needs_confirmation() {
+    let t = Test::init("alice");
+    let (bob_key, _) = generate_age_keypair();
+    let request_dir = t.dir.path().join(".dugout/requests/default");
+    fs::create_dir_all(&request_dir).unwrap();
+    fs::write(request_dir.join("bob.pub"), format!("{}\n", bob_key)).unwrap();
+
+    let fingerprint = {
+        use sha2::{Digest, Sha256};
+        let hash = Sha256::digest(bob_key.as_bytes());
+        let hex: String = hash[..8].iter().map(|b| format!("{:02x}", b)).collect();
+        format!("SHA256:{}", hex)
+    };
+
+    let output = t.cmd().arg("pending").output().unwrap();
+    assert_stdout_contains(&output, &fingerprint);
+
+    // No terminal and no --yes: nothing changes
+    let output = t.cmd().args(["admit", "bob"]).output().unwrap();
+    assert_failure(&output);
+    assert_stdout_contains(&output, &format!("bob: {}", fingerprint));
+    assert_stderr_contains(&output, "admit not confirmed");
+    assert!(request_dir.join("bob.pub").exists());
+
+    let output = t.cmd().args(["admit", "bob", "--yes"]).output().unwrap();
+    assert_success(&output);
+    assert_stdout_contains(&output, "admitted bob");
+}
+
 #[test]
 fn test_admit_nonexistent_request_fails() {
     let t = Test::init("alice");
@@ -161,7 +191,7 @@ fn test_knock_pending_admit_workflow() {
     assert_stdout_contains(&pending_output, "bob");
 
     // Admit bob
-    let admit_output = t.cmd().args(["admit", "bob"]).output().unwrap();
+    let admit_output = t.cmd().args(["admit", "bob", "--yes"]).output().unwrap();
     assert_success(&admit_output);
 
     // Check pending again - should be empty
@@ -267,15 +297,16 @@ fn test_full_onboarding_with_separate_identities() {
     // Verify the request file contains Bob's public key
     let request_path = t.dir.path().join(".dugout/requests/default/bob.pub");
     assert!(request_path.exists());
-    let request_key = fs::read_to_string(&request_path).unwrap();
-    assert_eq!(request_key.trim(), bob_pubkey);
+    let request = fs::read_to_string(&request_path).unwrap();
+    assert_eq!(request.lines().next(), Some(bob_pubkey.as_str()));
+    assert!(request.contains("signature: "));
 
     // --- Alice admits Bob ---
     let output = t
         .cmd()
         .env("HOME", alice_home.path())
         .env("USERPROFILE", alice_home.path())
-        .args(["admit", "bob"])
+        .args(["admit", "bob", "--yes"])
         .output()
         .unwrap();
     assert_success(&output);
@@ -330,10 +361,9 @@ fn test_knock_uses_global_identity_key() {
     let output = t.cmd().args(["knock", "bob"]).output().unwrap();
     assert_success(&output);
 
-    let request_key = fs::read_to_string(t.dir.path().join(".dugout/requests/default/bob.pub"))
-        .unwrap()
-        .trim()
-        .to_string();
+    let request =
+        fs::read_to_string(t.dir.path().join(".dugout/requests/default/bob.pub")).unwrap();
+    let request_key = request.lines().next().unwrap_or_default().to_string();
 
     assert_eq!(
         request_key, global_pubkey,
@@ -457,3 +487,172 @@ fn test_admit_corrupted_request_file() {
     // Should mention invalid key format
     assert_stderr_contains(&output, "invalid");
 }
+
+#[test]
+fn test_admit_by_non_admin_warns_or_refuses() {
+    let t = Test::init("alice");
+    assert_success(&t.team_add("bob", BOB_PUBLIC_KEY));
+
+    // The initializer starts as admin
+    assert_stdout_contains(&t.team_list(), "admin");
+
+    let output = t
+        .cmd()
+        .args(["team", "role", "bob", "admin"])
+        .output()
+        .unwrap();
+    assert_success(&output);
+    let output = t
+        .cmd()
+        .args(["team", "role", "alice", "member"])
+        .output()
+        .unwrap();
+    assert_success(&output);
+
+    let (carol_key, _) = generate_age_keypair();
+    let request_dir = t.dir.path().join(".dugout/requests/default");
+    fs::create_dir_all(&request_dir).unwrap();
+    fs::write(request_dir.join("carol.pub"), format!("{}\n", carol_key)).unwrap();
+
+    let output = t
+        .cmd()
+        .args(["admit", "carol", "--strict"])
+        .output()
+        .unwrap();
+    assert_failure(&output);
+    assert_stderr_contains(&output, "alice is not an admin of this vault");
+    assert!(request_dir.join("carol.pub").exists());
+
+    let output = t.cmd().args(["admit", "carol", "--yes"]).output().unwrap();
+    assert_success(&output);
+    assert_stdout_contains(
+        &output,
+        "alice is not an admin of this vault; admitting anyway",
+    );
+    assert_stdout_contains(&t.team_list(), "carol");
+}
+
+#[test]
+fn test_admit_all_reencrypts_once() {
+    let t = Test::with_secrets("alice", &[("API_KEY", "v1")]);
+    let request_dir = t.dir.path().join(".dugout/requests/default");
+    fs::create_dir_all(&request_dir).unwrap();
+    for name in ["bob", "carol"] {
+        let (key, _) = generate_age_keypair();
+        fs::write(
+            request_dir.join(format!("{}.pub", name)),
+            format!("{}\n", key),
+        )
+        .unwrap();
+    }
+
+    let output = t.cmd().args(["admit", "--all", "--yes"]).output().unwrap();
+    assert_success(&output);
+    assert_stdout_contains(&output, "admitted 2 (bob, carol)");
+    assert!(!request_dir.join("bob.pub").exists());
+    assert!(!request_dir.join("carol.pub").exists());
+
+    let team = t.team_list();
+    assert_stdout_contains(&team, "bob");
+    assert_stdout_contains(&team, "carol");
+    assert_stdout_contains(&t.get("API_KEY"), "v1");
+
+    let output = t.cmd().args(["log", "export"]).output().unwrap();
+    let reencrypts = stdout(&output)
+        .lines()
+        .filter(|line| line.contains(r#""action":"reencrypt""#))
+        .count();
+    assert_eq!(reencrypts, 1);
+
+    let output = t.cmd().args(["admit", "--all"]).output().unwrap();
+    assert_success(&output);
+    assert_stdout_contains(&output, "no pending requests");
+}
+
+#[test]
+fn test_knock_signs_request_and_admit_verifies_it() {
+    let t = Test::init("alice");
+    assert_success(&t.cmd().arg("setup").output().unwrap());
+    assert_success(&t.cmd().args(["knock", "bob"]).output().unwrap());
+
+    let request_dir = t.dir.path().join(".dugout/requests/default");
+    let contents = fs::read_to_string(request_dir.join("bob.pub")).unwrap();
+    assert!(contents.lines().nth(1).unwrap().starts_with("signature: "));
+
+    let output = t.cmd().arg("pending").output().unwrap();
+    assert_stdout_contains(&output, "signed");
+
+    // Swapping in another key invalidates the signature, with or without --verify
+    let (other_key, _) = generate_age_keypair();
+    let signature = contents.lines().nth(1).unwrap();
+    fs::write(
+        request_dir.join("bob.pub"),
+        format!("{}\n{}\n", other_key, signature),
+    )
+    .unwrap();
+    let output = t.cmd().args(["admit", "bob", "--yes"]).output().unwrap();
+    assert_failure(&output);
+    assert_stderr_contains(&output, "signature does not match");
+
+    fs::write(request_dir.join("bob.pub"), &contents).unwrap();
+    let output = t
+        .cmd()
+        .args(["admit", "bob", "--verify", "--yes"])
+        .output()
+        .unwrap();
+    assert_success(&output);
+    assert_stdout_contains(&output, "(signed)");
+    assert_stdout_contains(&t.team_list(), "bob");
+}
+
+#[test]
+fn test_admit_rejects_request_copied_from_another_vault() {
+    let t = Test::init("alice");
+    t.init_vault("alice", "prod");
+    assert_success(&t.cmd().arg("setup").output().unwrap());
+    let output = t
+        .cmd()
+        .args(["--vault", "default", "knock", "bob"])
+        .output()
+        .unwrap();
+    assert_success(&output);
+
+    let requests = t.dir.path().join(".dugout/requests");
+    fs::create_dir_all(requests.join("prod")).unwrap();
+    fs::copy(
+        requests.join("default/bob.pub"),
+        requests.join("prod/bob.pub"),
+    )
+    .unwrap();
+
+    let output = t
+        .cmd()
+        .args(["--vault", "prod", "admit", "bob", "--verify", "--yes"])
+        .output()
+        .unwrap();
+    assert_failure(&output);
+    assert_stderr_contains(&output, "signature does not match");
+    assert!(requests.join("prod/bob.pub").exists());
+}
+
+#[test]
+fn test_admit_verify_rejects_unsigned_request() {
+    let t = Test::init("alice");
+    let (carol_key, _) = generate_age_keypair();
+    let request_dir = t.dir.path().join(".dugout/requests/default");
+    fs::create_dir_all(&request_dir).unwrap();
+    fs::write(request_dir.join("carol.pub"), format!("{}\n", carol_key)).unwrap();
+
+    let output = t
+        .cmd()
+        .args(["admit", "--all", "--verify", "--yes"])
+        .output()
+        .unwrap();
+    assert_failure(&output);
+    assert_stderr_contains(&output, "invalid access request from 'carol'");
+    assert!(request_dir.join("carol.pub").exists());
+
+    let output = t.cmd().args(["admit", "carol", "--yes"]).output().unwrap();
+    assert_success(&output);
+    assert_stdout_contains(&output, "(unsigned)");
+}
@@ -0,0 +1,128 @@
+//! Tests for `dugout log export`.
+
+use crate::support::*;
+
+const SCHEMA_FIELDS: &[&str] = &[
+    "version",
+    "timestamp",
+    "actor",
+    "project",
+    "vault",
+    "action",
+    "keys",
+];
+
+fn events(output: &std::process::Output) -> Vec<serde_json::Value> {
+    stdout(output)
+        .lines()
+        .map(|line| serde_json::from_str(line).expect("each line is a JSON object"))
+        .collect()
+}
+
+#[test]
+fn test_log_export_ndjson_matches_recorded_events() {
+    let t = Test::init("test-user");
+    assert_success(&t.set("API_KEY", "super-secret-value"));
+    assert_success(&t.get("API_KEY"));
+    assert_success(&t.rm("API_KEY"));
+
+    let output = t.cmd().args(["log", "export"]).output().unwrap();
+    assert_success(&output);
+    assert_stdout_excludes(&output, "super-secret-value");
+
+    let events = events(&output);
+    let actions: Vec<&str> = events
+        .iter()
+        .map(|e| e["action"].as_str().unwrap())
+        .collect();
+    assert_eq!(actions, vec!["set", "read", "rm"]);
+
+    for event in &events {
+        for field in SCHEMA_FIELDS {
+            assert!(event.get(field).is_some(), "missing {} in {}", field, event);
+        }
+        assert_eq!(event["version"], 1);
+        assert_eq!(event["vault"], "default");
+        assert_eq!(event["keys"], serde_json::json!(["API_KEY"]));
+    }
+}
+
+#[test]
+fn test_log_export_json_and_since() {
+    let t = Test::init("test-user");
+    assert_success(&t.set("API_KEY", "value"));
+
+    let output = t
+        .cmd()
+        .args(["log", "export", "--format", "json"])
+        .output()
+        .unwrap();
+    assert_success(&output);
+    let events: Vec<serde_json::Value> = serde_json::from_str(&stdout(&output)).unwrap();
+    assert_eq!(events.len(), 1);
+
+    let output = t
+        .cmd()
+        .args(["log", "export", "--since", "2999-01-01"])
+        .output()
+        .unwrap();
+    assert_success(&output);
+    assert!(stdout(&output).trim().is_empty());
+
+    let output = t
+        .cmd()
+        .args(["log", "export", "--since", "yesterday"])
+        .output()
+        .unwrap();
+    assert_failure(&output);
+}
+
+#[test]
+fn test_log_opt_out_env() {
+    let t = Test::init("test-user");
+    assert_success(&t.set("API_KEY", "value"));
+
+    let output = t
+        .cmd()
+        .env("DUGOUT_NO_OPLOG", "1")
+        .args(["get", "API_KEY"])
+        .output()
+        .unwrap();
+    assert_success(&output);
+
+    let output = t.cmd().args(["log", "export"]).output().unwrap();
+    let actions: Vec<String> = events(&output)
+        .iter()
+        .map(|e| e["action"].as_str().unwrap().to_string())
+        .collect();
+    assert_eq!(actions, vec!["set"]);
+}
+
+#[test]
+fn test_log_rotates_when_full() {
+    let t = Test::init("test-user");
+    assert_success(&t.set("API_KEY", "value"));
+
+    let log_dir = t.home.path().join(".dugout/log");
+    let log = std::fs::read_dir(&log_dir)
+        .unwrap()
+        .next()
+        .unwrap()
+        .unwrap()
+        .path();
+    let line = std::fs::read_to_string(&log).unwrap();
+    std::fs::write(&log, line.repeat(1024 * 1024 / line.len() + 1)).unwrap();
+    let full = std::fs::metadata(&log).unwrap().len();
+
+    assert_success(&t.get("API_KEY"));
+
+    let rotated = log.with_extension("ndjson.1");
+    assert_eq!(std::fs::metadata(&rotated).unwrap().len(), full);
+    assert_eq!(std::fs::read_to_string(&log).unwrap().lines().count(), 1);
+
+    // Export still covers both generations, oldest first
+    let output = t.cmd().args(["log", "export"]).output().unwrap();
+    let events = events(&output);
+    assert!(events.len() > 1);
+    assert_eq!(events.last().unwrap()["action"], "read");
+}
@@ -0,0 +1,91 @@
+//! Tests for confirmation of changes to protected vaults.
+
+use crate::support::*;
+
+/// Insert a line into the `[dugout]` table of the default vault file.
+fn set_meta(t: &Test, line: &str) {
+    let path = t.dir.path().join(".dugout.toml");
+    let config = std::fs::read_to_string(&path).unwrap().replacen(
+        "[dugout]\n",
+        &format!("[dugout]\n{}\n", line),
+        1,
+    );
+    std::fs::write(&path, config).unwrap();
+}
+
+#[test]
+fn test_protected_pattern_refuses_mutation_without_yes() {
+    let t = Test::new();
+    t.init_cmd("alice");
+    t.init_vault("alice", "prod");
+    set_meta(&t, "protected = [\"prod*\"]");
+
+    let output = t.set_vault("prod", "API_KEY", "value");
+    assert_failure(&output);
+    assert_stderr_contains(&output, "vault 'prod' is protected");
+    assert_failure(&t.get_vault("prod", "API_KEY"));
+
+    // Unprotected vaults are unaffected
+    assert_success(&t.set_vault("default", "API_KEY", "value"));
+
+    let output = t
+        .cmd()
+        .args(["--vault", "prod", "--yes", "set", "API_KEY", "value"])
+        .output()
+        .unwrap();
+    assert_success(&output);
+    assert_stdout_contains(&t.get_vault("prod", "API_KEY"), "value");
+}
+
+#[test]
+fn test_confirm_mutations_flag_protects_vault() {
+    let t = Test::with_secrets("alice", &[("API_KEY", "value")]);
+    set_meta(&t, "confirm_mutations = true");
+
+    let output = t.rm("API_KEY");
+    assert_failure(&output);
+    assert_stderr_contains(&output, "vault 'default' is protected");
+
+    // Reads don't need confirmation
+    assert_success(&t.get("API_KEY"));
+
+    let output = t.cmd().args(["rm", "API_KEY", "-y"]).output().unwrap();
+    assert_success(&output);
+    assert_failure(&t.get("API_KEY"));
+}
+
+#[test]
+fn test_vault_rm_and_clone_respect_protection() {
+    let t = Test::new();
+    t.init_cmd("alice");
+    t.init_vault("alice", "prod");
+    set_meta(&t, "protected = [\"prod*\"]");
+    let prod = t.dir.path().join(".dugout.prod.toml");
+
+    // --force skips the delete prompt, not the protection guard
+    let output = t
+        .cmd()
+        .args(["vault", "rm", "prod", "--force"])
+        .output()
+        .unwrap();
+    assert_failure(&output);
+    assert_stderr_contains(&output, "vault 'prod' is protected");
+    assert!(prod.exists());
+
+    let output = t
+        .cmd()
+        .args(["--vault", "default", "vault", "clone", "prod-copy"])
+        .output()
+        .unwrap();
+    assert_failure(&output);
+    assert_stderr_contains(&output, "vault 'prod-copy' is protected");
+    assert!(!t.dir.path().join(".dugout.prod-copy.toml").exists());
+
+    let output = t
+        .cmd()
+        .args(["--yes", "vault", "rm", "prod", "--force"])
+        .output()
+        .unwrap();
+    assert_success(&output);
+    assert!(!prod.exists());
+}
@@ -0,0 +1,54 @@
+//! Tests for `dugout render`.
+
+use crate::support::*;
+use std::fs;
+
+#[test]
+fn test_render_to_output_file() {
+    let t = Test::with_secrets("test-user", &[("DB_URL", "postgres://db"), ("UNUSED", "x")]);
+    fs::write(
+        t.dir.path().join("config.tmpl"),
+        "database: {{ DB_URL }}\nhelm: {{ .Values.name }}\n",
+    )
+    .unwrap();
+
+    let output = t
+        .cmd()
+        .args(["render", "config.tmpl", "--output", "config.yml"])
+        .output()
+        .unwrap();
+    assert_success(&output);
+    assert_stdout_contains(&output, "rendered config.tmpl to config.yml (1 secrets)");
+
+    let path = t.dir.path().join("config.yml");
+    assert_eq!(
+        fs::read_to_string(&path).unwrap(),
+        "database: postgres://db\nhelm: {{ .Values.name }}\n"
+    );
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mode = fs::metadata(&path).unwrap().permissions().mode() & 0o777;
+        assert_eq!(mode, 0o600);
+    }
+}
+
+#[test]
+fn test_render_missing_placeholder() {
+    let t = Test::with_secrets("test-user", &[("KNOWN", "yes")]);
+    fs::write(t.dir.path().join("app.tmpl"), "{{KNOWN}} {{MISSING}}\n").unwrap();
+
+    let output = t.cmd().args(["render", "app.tmpl"]).output().unwrap();
+    assert_failure(&output);
+    assert_eq!(output.status.code(), Some(5));
+    assert_stderr_contains(&output, "MISSING");
+
+    let output = t
+        .cmd()
+        .args(["render", "app.tmpl", "--allow-missing"])
+        .output()
+        .unwrap();
+    assert_success(&output);
+    assert_eq!(stdout(&output), "yes {{MISSING}}\n");
+    assert_stderr_contains(&output, "left {{MISSING}} unrendered");
+}
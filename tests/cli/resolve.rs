@@ -0,0 +1,287 @@
+//! Tests for `dugout resolve`.
+
+use crate::support::*;
+
+/// Build a conflicted `.dugout.toml` from two divergent edits of the same vault.
+fn conflict(t: &Test, ours: &[(&str, &str)], theirs: &[(&str, &str)]) {
+    let path = t.dir.path().join(".dugout.toml");
+    let base = std::fs::read_to_string(&path).unwrap();
+
+    for (key, value) in ours {
+        assert_success(&t.set_force(key, value));
+    }
+    let ours = std::fs::read_to_string(&path).unwrap();
+
+    std::fs::write(&path, &base).unwrap();
+    for (key, value) in theirs {
+        assert_success(&t.set_force(key, value));
+    }
+    let theirs = std::fs::read_to_string(&path).unwrap();
+
+    std::fs::write(
+        &path,
+        format!("<<<<<<< HEAD\n{}=======\n{}>>>>>>> feature\n", ours, theirs),
+    )
+    .unwrap();
+}
+
+#[test]
+fn test_conflicted_vault_reports_merge_conflict() {
+    let t = Test::with_secrets("test-user", &[("SHARED", "base")]);
+    conflict(&t, &[("OURS_KEY", "a")], &[("THEIRS_KEY", "b")]);
+
+    let output = t.get("SHARED");
+    assert_failure(&output);
+    assert_stderr_contains(&output, "merge conflict");
+}
+
+#[test]
+fn test_resolve_merges_keys_added_on_each_side() {
+    let t = Test::with_secrets("test-user", &[("SHARED", "base")]);
+    conflict(&t, &[("OURS_KEY", "a")], &[("THEIRS_KEY", "b")]);
+
+    let output = t.cmd().arg("resolve").output().unwrap();
+    assert_success(&output);
+
+    assert_stdout_contains(&t.get("SHARED"), "base");
+    assert_stdout_contains(&t.get("OURS_KEY"), "a");
+    assert_stdout_contains(&t.get("THEIRS_KEY"), "b");
+}
+
+#[test]
+fn test_resolve_same_plaintext_needs_no_choice() {
+    let t = Test::with_secrets("test-user", &[("SHARED", "base")]);
+    conflict(&t, &[("SHARED", "same")], &[("SHARED", "same")]);
+
+    let output = t.cmd().arg("resolve").output().unwrap();
+    assert_success(&output);
+    assert_stdout_contains(&t.get("SHARED"), "same");
+}
+
+#[test]
+fn test_resolve_conflicting_values_requires_choice() {
+    let t = Test::with_secrets("test-user", &[("SHARED", "base")]);
+    conflict(&t, &[("SHARED", "ours")], &[("SHARED", "theirs")]);
+
+    let output = t.cmd().arg("resolve").output().unwrap();
+    assert_failure(&output);
+    assert_stderr_contains(&output, "--ours or --theirs");
+
+    let output = t.cmd().args(["resolve", "--theirs"]).output().unwrap();
+    assert_success(&output);
+    assert_stdout_contains(&output, "SHARED");
+
+    let output = t.get("SHARED");
+    assert_success(&output);
+    assert_eq!(stdout(&output), "theirs\n");
+}
+
+/// Build a conflicted `.dugout.toml` where their side removed bob, with the
+/// merge base in a diff3 section when `diff3` is set.
+fn conflict_removing_bob(t: &Test, diff3: bool) {
+    let path = t.dir.path().join(".dugout.toml");
+    assert_success(&t.team_add("bob", BOB_PUBLIC_KEY));
+    let base = std::fs::read_to_string(&path).unwrap();
+
+    assert_success(&t.set_force("OURS_KEY", "a"));
+    let ours = std::fs::read_to_string(&path).unwrap();
+
+    std::fs::write(&path, &base).unwrap();
+    assert_success(&t.team_rm("bob"));
+    let theirs = std::fs::read_to_string(&path).unwrap();
+
+    let base = if diff3 {
+        format!("||||||| base\n{}", base)
+    } else {
+        String::new()
+    };
+    std::fs::write(
+        &path,
+        format!(
+            "<<<<<<< HEAD\n{}{}=======\n{}>>>>>>> feature\n",
+            ours, base, theirs
+        ),
+    )
+    .unwrap();
+}
+
+#[test]
+fn test_resolve_keeps_recipient_removed_on_one_side_removed() {
+    let t = Test::with_secrets("test-user", &[("SHARED", "base")]);
+    conflict_removing_bob(&t, true);
+
+    let output = t.cmd().arg("resolve").output().unwrap();
+    assert_success(&output);
+    assert_stdout_contains(&output, "bob (recipient removed on one side)");
+
+    assert_stdout_excludes(&t.team_list(), "bob");
+    assert_stdout_contains(&t.get("OURS_KEY"), "a");
+}
+
+#[test]
+fn test_resolve_one_sided_recipient_without_base_fails() {
+    let t = Test::with_secrets("test-user", &[("SHARED", "base")]);
+    conflict_removing_bob(&t, false);
+
+    let output = t.cmd().arg("resolve").output().unwrap();
+    assert_failure(&output);
+    assert_stderr_contains(&output, "recipient 'bob' is only on one side");
+    assert_stderr_contains(&output, "merge.conflictStyle diff3");
+}
+
+/// Build a conflicted `.dugout.toml` with a diff3 base from two divergent
+/// edits of the same vault.
+fn diff3_conflict(t: &Test, ours: impl Fn(&Test), theirs: impl Fn(&Test)) {
+    let path = t.dir.path().join(".dugout.toml");
+    let base = std::fs::read_to_string(&path).unwrap();
+
+    ours(t);
+    let ours = std::fs::read_to_string(&path).unwrap();
+
+    std::fs::write(&path, &base).unwrap();
+    theirs(t);
+    let theirs = std::fs::read_to_string(&path).unwrap();
+
+    std::fs::write(
+        &path,
+        format!(
+            "<<<<<<< HEAD\n{}||||||| base\n{}=======\n{}>>>>>>> feature\n",
+            ours, base, theirs
+        ),
+    )
+    .unwrap();
+}
+
+#[test]
+fn test_resolve_keeps_secret_deleted_on_one_side_deleted() {
+    let t = Test::with_secrets("test-user", &[("SHARED", "base"), ("OLD", "gone")]);
+    diff3_conflict(
+        &t,
+        |t| assert_success(&t.set_force("OURS_KEY", "a")),
+        |t| assert_success(&t.rm("OLD")),
+    );
+
+    let output = t.cmd().arg("resolve").output().unwrap();
+    assert_success(&output);
+    assert_stdout_contains(&output, "OLD (removed on one side)");
+
+    assert_failure(&t.get("OLD"));
+    assert_stdout_contains(&t.get("OURS_KEY"), "a");
+    assert_stdout_contains(&t.get("SHARED"), "base");
+}
+
+#[test]
+fn test_resolve_secret_changed_and_deleted_requires_choice() {
+    let t = Test::with_secrets("test-user", &[("OLD", "base")]);
+    diff3_conflict(
+        &t,
+        |t| assert_success(&t.set_force("OLD", "changed")),
+        |t| assert_success(&t.rm("OLD")),
+    );
+
+    let output = t.cmd().arg("resolve").output().unwrap();
+    assert_failure(&output);
+    assert_stderr_contains(&output, "--ours or --theirs");
+
+    let output = t.cmd().args(["resolve", "--theirs"]).output().unwrap();
+    assert_success(&output);
+    assert_failure(&t.get("OLD"));
+}
+
+#[test]
+fn test_resolve_without_base_warns_about_one_sided_keys() {
+    let t = Test::with_secrets("test-user", &[("SHARED", "base")]);
+    conflict(&t, &[("OURS_KEY", "a")], &[("THEIRS_KEY", "b")]);
+
+    let output = t.cmd().arg("resolve").output().unwrap();
+    assert_success(&output);
+    assert_stdout_contains(&output, "OURS_KEY, THEIRS_KEY");
+    assert_stdout_contains(&output, "merge.conflictStyle diff3");
+}
+
+#[test]
+fn test_resolve_merges_aliases_and_hooks_from_their_side() {
+    let t = Test::with_secrets("test-user", &[("SHARED", "base")]);
+    let path = t.dir.path().join(".dugout.toml");
+    diff3_conflict(
+        &t,
+        |t| assert_success(&t.set_force("OURS_KEY", "a")),
+        |t| {
+            let output = t.cmd().args(["alias", "APP_SHARED", "SHARED"]).output();
+            assert_success(&output.unwrap());
+            let mut config = std::fs::read_to_string(&path).unwrap();
+            config.push_str("\n[hooks]\npost_set = \"true\"\n");
+            std::fs::write(&path, config).unwrap();
+        },
+    );
+
+    let output = t.cmd().arg("resolve").output().unwrap();
+    assert_success(&output);
+
+    assert_eq!(stdout(&t.get("APP_SHARED")), "base\n");
+    let config = std::fs::read_to_string(&path).unwrap();
+    assert!(config.contains("post_set = \"true\""));
+}
+
+#[test]
+fn test_resolve_refuses_settings_changed_differently_on_each_side() {
+    let t = Test::with_secrets("test-user", &[("SHARED", "base")]);
+    let path = t.dir.path().join(".dugout.toml");
+    let add_hook = |command: &'static str| {
+        let path = path.clone();
+        move |_: &Test| {
+            let mut config = std::fs::read_to_string(&path).unwrap();
+            config.push_str(&format!("\n[hooks]\npost_set = \"{}\"\n", command));
+            std::fs::write(&path, config).unwrap();
+        }
+    };
+    diff3_conflict(&t, add_hook("./ours.sh"), add_hook("./theirs.sh"));
+
+    let output = t.cmd().arg("resolve").output().unwrap();
+    assert_failure(&output);
+    assert_stderr_contains(&output, "[hooks] differs on each side");
+}
+
+#[test]
+fn test_resolve_merges_conflict_in_secrets_sidecar() {
+    let t = Test::with_secrets("test-user", &[("SHARED", "base")]);
+    let main = t.dir.path().join(".dugout.toml");
+    let sidecar = t.dir.path().join(".dugout-secrets.toml");
+    let config = std::fs::read_to_string(&main).unwrap();
+    let config = config.replacen("[dugout]\n", "[dugout]\nsplit_secrets = true\n", 1);
+    std::fs::write(&main, config).unwrap();
+    assert_success(&t.set_force("SHARED", "moved"));
+
+    let base_main = std::fs::read_to_string(&main).unwrap();
+    let base = std::fs::read_to_string(&sidecar).unwrap();
+    assert_success(&t.set_force("OURS_KEY", "a"));
+    let ours_main = std::fs::read_to_string(&main).unwrap();
+    let ours = std::fs::read_to_string(&sidecar).unwrap();
+
+    std::fs::write(&main, &base_main).unwrap();
+    std::fs::write(&sidecar, &base).unwrap();
+    assert_success(&t.set_force("THEIRS_KEY", "b"));
+    let theirs = std::fs::read_to_string(&sidecar).unwrap();
+
+    // Only the sidecar is left conflicted
+    std::fs::write(&main, ours_main).unwrap();
+    std::fs::write(
+        &sidecar,
+        format!(
+            "<<<<<<< HEAD\n{}||||||| base\n{}=======\n{}>>>>>>> feature\n",
+            ours, base, theirs
+        ),
+    )
+    .unwrap();
+
+    let output = t.cmd().arg("resolve").output().unwrap();
+    assert_success(&output);
+    assert_stdout_contains(&output, ".dugout-secrets.toml");
+    assert!(!std::fs::read_to_string(&sidecar)
+        .unwrap()
+        .contains("<<<<<<<"));
+
+    assert_stdout_contains(&t.get("SHARED"), "moved");
+    assert_stdout_contains(&t.get("OURS_KEY"), "a");
+    assert_stdout_contains(&t.get("THEIRS_KEY"), "b");
+}
@@ -95,3 +95,69 @@ fn test_env_with_init_does_not_crash() {
     // but we can verify the command at least recognizes it's initialized
     let _ = t;
 }
+
+#[test]
+#[cfg(unix)]
+fn test_run_only_injects_selected_keys() {
+    let t = Test::with_secrets("test-user", &[("KEEP", "kept"), ("DROP", "dropped")]);
+
+    let output = t
+        .cmd()
+        .args([
+            "run",
+            "--only",
+            "KEEP",
+            "--",
+            "sh",
+            "-c",
+            "echo ${KEEP:-none} ${DROP:-none}",
+        ])
+        .output()
+        .unwrap();
+    assert_success(&output);
+    assert_stdout_contains(&output, "kept none");
+}
+
+#[test]
+#[cfg(unix)]
+fn test_run_except_skips_selected_keys() {
+    let t = Test::with_secrets("test-user", &[("KEEP", "kept"), ("DROP", "dropped")]);
+
+    let output = t
+        .cmd()
+        .args([
+            "run",
+            "--except",
+            "DROP",
+            "--",
+            "sh",
+            "-c",
+            "echo ${KEEP:-none} ${DROP:-none}",
+        ])
+        .output()
+        .unwrap();
+    assert_success(&output);
+    assert_stdout_contains(&output, "kept none");
+}
+
+#[test]
+fn test_run_filters_reject_unknown_or_combined_keys() {
+    let t = Test::with_secrets("test-user", &[("KEEP", "kept")]);
+
+    let output = t
+        .cmd()
+        .args(["run", "--only", "MISSING", "--", "echo", "hi"])
+        .output()
+        .unwrap();
+    assert_failure(&output);
+    assert_stderr_contains(&output, "MISSING");
+
+    let output = t
+        .cmd()
+        .args([
+            "run", "--only", "KEEP", "--except", "KEEP", "--", "echo", "hi",
+        ])
+        .output()
+        .unwrap();
+    assert_failure(&output);
+}
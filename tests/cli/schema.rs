@@ -0,0 +1,125 @@
+//! Tests for `dugout schema` and the stability of `--json` output.
+
+use crate::support::schema::validate;
+use crate::support::*;
+use std::process::Output;
+
+fn schema_for(t: &Test, command: &str) -> serde_json::Value {
+    let output = t.cmd().args(["schema", command]).output().unwrap();
+    assert_success(&output);
+    serde_json::from_str(&stdout(&output)).expect("schema should be valid JSON")
+}
+
+fn assert_matches_schema(schema: &serde_json::Value, output: &Output) {
+    assert_success(output);
+    let value: serde_json::Value = serde_json::from_str(&stdout(output)).unwrap();
+    if let Err(err) = validate(schema, &value) {
+        panic!("output does not match schema: {}\n{}", err, value);
+    }
+}
+
+#[test]
+fn test_list_json_matches_schema() {
+    let t = Test::with_secrets("test-user", &[("KEY1", "value1"), ("KEY2", "value2")]);
+
+    let schema = schema_for(&t, "list");
+    assert_matches_schema(&schema, &t.list_json());
+}
+
+#[test]
+fn test_list_group_json_matches_schema() {
+    let t = Test::with_secrets("test-user", &[("DB_HOST", "h"), ("PORT", "1")]);
+
+    let schema = schema_for(&t, "list");
+    let output = t
+        .cmd()
+        .args(["list", "--json", "--group"])
+        .output()
+        .unwrap();
+    assert_matches_schema(&schema, &output);
+}
+
+#[test]
+fn test_get_json_matches_schema() {
+    let t = Test::with_secrets("test-user", &[("KEY1", "value1")]);
+
+    let schema = schema_for(&t, "get");
+    let output = t.cmd().args(["get", "KEY1", "--json"]).output().unwrap();
+    assert_matches_schema(&schema, &output);
+}
+
+#[test]
+fn test_team_list_json_matches_schema() {
+    let t = Test::init("test-user");
+    assert_success(&t.team_add("bob", BOB_PUBLIC_KEY));
+
+    let schema = schema_for(&t, "team-list");
+    assert_matches_schema(&schema, &t.team_list_json());
+}
+
+#[test]
+fn test_vault_list_json_matches_schema() {
+    let t = Test::init("test-user");
+
+    let schema = schema_for(&t, "vault-list");
+    assert_matches_schema(&schema, &t.vault_list_json());
+}
+
+#[test]
+fn test_status_json_matches_schema() {
+    let t = Test::with_secrets("test-user", &[("KEY1", "value1")]);
+
+    let schema = schema_for(&t, "status");
+    let output = t
+        .cmd()
+        .args(["check", "status", "--json"])
+        .output()
+        .unwrap();
+    assert_matches_schema(&schema, &output);
+}
+
+#[test]
+fn test_diff_json_matches_schema() {
+    let t = Test::with_secrets("test-user", &[("SAME", "same"), ("CHANGED", "vault")]);
+    std::fs::write(
+        t.dir.path().join(".env"),
+        "SAME=same\nCHANGED=env\nEXTRA=x\n",
+    )
+    .unwrap();
+
+    let schema = schema_for(&t, "diff");
+    let diff = |extra: &[&str]| {
+        t.cmd()
+            .args(["secrets", "diff", "--json"])
+            .args(extra)
+            .output()
+            .unwrap()
+    };
+    assert_matches_schema(&schema, &diff(&[]));
+    assert_matches_schema(&schema, &diff(&["--show-values"]));
+}
+
+#[test]
+fn test_schema_version_is_pinned() {
+    let t = Test::with_secrets("test-user", &[("KEY1", "value1")]);
+
+    let schema = schema_for(&t, "list");
+    let list: serde_json::Value = serde_json::from_str(&stdout(&t.list_json())).unwrap();
+    assert_eq!(
+        list["schema_version"],
+        schema["properties"]["schema_version"]["const"]
+    );
+
+    // A payload from a different version must be rejected
+    let mut stale = list.clone();
+    stale["schema_version"] = serde_json::json!(0);
+    assert!(validate(&schema, &stale).is_err());
+}
+
+#[test]
+fn test_schema_rejects_unknown_command() {
+    let t = Test::new();
+
+    let output = t.cmd().args(["schema", "nope"]).output().unwrap();
+    assert_failure(&output);
+}
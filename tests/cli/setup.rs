@@ -94,6 +94,32 @@ fn test_whoami_prints_public_key() {
     assert_eq!(output_str.trim(), expected_pubkey.trim());
 }
 
+#[test]
+fn test_whoami_all_lists_vault_access() {
+    let t = Test::new();
+    assert_success(&t.cmd().arg("setup").output().unwrap());
+    assert_success(&t.init_cmd("alice"));
+    fs::write(
+        t.dir.path().join(".dugout.prod.toml"),
+        format!(
+            "[dugout]\nversion = \"0.1.0\"\n\n[recipients]\nbob = \"{}\"\n",
+            BOB_PUBLIC_KEY
+        ),
+    )
+    .unwrap();
+
+    // Plain whoami stays a single line for scripts
+    let output = t.cmd().arg("whoami").output().unwrap();
+    assert_eq!(stdout(&output).lines().count(), 1);
+
+    let output = t.cmd().args(["whoami", "--all"]).output().unwrap();
+    assert_success(&output);
+    let lines: Vec<String> = stdout(&output).lines().map(String::from).collect();
+    assert!(lines[0].starts_with("age1"));
+    assert!(lines.iter().any(|l| l.ends_with("✓ default")));
+    assert!(lines.iter().any(|l| l.ends_with("✗ prod")));
+}
+
 #[test]
 fn test_whoami_without_setup_fails() {
     let t = Test::new();
@@ -239,3 +265,74 @@ fn test_open_falls_back_to_global_identity_when_project_key_stale() {
     assert_success(&output);
     assert_eq!(stdout(&output).trim(), "fallback_value");
 }
+
+#[test]
+fn test_setup_passphrase_protects_global_identity() {
+    let t = Test::new();
+
+    let output = t
+        .cmd()
+        .args(["setup", "--passphrase"])
+        .env("DUGOUT_PASSPHRASE", "correct horse")
+        .output()
+        .unwrap();
+    assert_success(&output);
+
+    // The identity on disk is armored, never the plaintext key
+    let key = fs::read_to_string(t.home.path().join(".dugout/identity.key")).unwrap();
+    assert!(key.contains("BEGIN AGE ENCRYPTED FILE"));
+    assert!(!key.contains("AGE-SECRET-KEY"));
+
+    let output = t
+        .cmd()
+        .arg("init")
+        .env("DUGOUT_PASSPHRASE", "correct horse")
+        .output()
+        .unwrap();
+    assert_success(&output);
+
+    let output = t
+        .cmd()
+        .args(["set", "API_KEY", "locked"])
+        .env("DUGOUT_PASSPHRASE", "correct horse")
+        .output()
+        .unwrap();
+    assert_success(&output);
+
+    let output = t
+        .cmd()
+        .args(["get", "API_KEY"])
+        .env("DUGOUT_PASSPHRASE", "correct horse")
+        .output()
+        .unwrap();
+    assert_success(&output);
+    assert_eq!(stdout(&output).trim(), "locked");
+
+    // Without a terminal or the env var the key can't be unlocked
+    let output = t.cmd().args(["get", "API_KEY"]).output().unwrap();
+    assert_failure(&output);
+    assert_stderr_contains(&output, "passphrase-protected");
+}
+
+#[test]
+fn test_regenerated_identity_reports_stale_recipient() {
+    let t = Test::new();
+
+    let output = t.cmd().arg("setup").output().unwrap();
+    assert_success(&output);
+    let output = t.cmd().arg("init").output().unwrap();
+    assert_success(&output);
+    let output = t.cmd().args(["set", "API_KEY", "value"]).output().unwrap();
+    assert_success(&output);
+
+    // Regenerate the identity on a fresh machine (no copied project keys);
+    // the vault still lists the old key under our name
+    fs::remove_dir_all(t.home.path().join(".dugout/keys")).unwrap();
+    let output = t.cmd().args(["setup", "--force"]).output().unwrap();
+    assert_success(&output);
+
+    let output = t.cmd().args(["get", "API_KEY"]).output().unwrap();
+    assert_failure(&output);
+    assert_stderr_contains(&output, "no longer matches recipient");
+    assert_stderr_contains(&output, "dugout knock");
+}
@@ -1,4 +1,4 @@
-//! Tests for `dugout team add/list/rm` commands.
+//! Tests for `dugout team add/list/export/rm` commands.
 
 use crate::support::*;
 
@@ -94,6 +94,174 @@ fn test_team_add_reencrypts_secrets() {
     assert_stdout_contains(&output, "team_value");
 }
 
+#[test]
+fn test_team_add_with_expiry_and_prune() {
+    let t = Test::with_secrets("alice", &[("API_KEY", "v1")]);
+
+    let output = t
+        .cmd()
+        .args([
+            "team",
+            "add",
+            "bob",
+            BOB_PUBLIC_KEY,
+            "--expires",
+            "2000-01-01",
+        ])
+        .output()
+        .unwrap();
+    assert_failure(&output);
+
+    let output = t
+        .cmd()
+        .args([
+            "team",
+            "add",
+            "bob",
+            BOB_PUBLIC_KEY,
+            "--expires",
+            "2099-01-01",
+        ])
+        .output()
+        .unwrap();
+    assert_success(&output);
+    assert_stdout_contains(&t.team_list(), "expires 2099-01-01");
+
+    // Backdate the expiry as if it had passed
+    let path = t.dir.path().join(".dugout.toml");
+    let config = std::fs::read_to_string(&path).unwrap();
+    std::fs::write(&path, config.replace("2099-01-01", "2001-01-01")).unwrap();
+
+    let output = t.team_list();
+    assert_stdout_contains(&output, "expired 2001-01-01");
+
+    let output = t.cmd().args(["sync", "--prune-expired"]).output().unwrap();
+    assert_success(&output);
+    assert_stdout_contains(&output, "removed expired: bob");
+    assert_stdout_contains(&output, "synced");
+
+    let config = std::fs::read_to_string(&path).unwrap();
+    assert!(!config.contains(BOB_PUBLIC_KEY));
+    assert!(!config.contains("recipient_expiry"));
+    assert_stdout_contains(&t.get("API_KEY"), "v1");
+}
+
+#[test]
+fn test_team_add_with_note() {
+    let t = Test::init("alice");
+
+    let output = t
+        .cmd()
+        .args([
+            "team",
+            "add",
+            "ci",
+            BOB_PUBLIC_KEY,
+            "--note",
+            "CI deploy key",
+        ])
+        .output()
+        .unwrap();
+    assert_success(&output);
+    assert_stdout_contains(&t.team_list(), "# CI deploy key");
+
+    let parsed: serde_json::Value = serde_json::from_str(&stdout(&t.team_list_json())).unwrap();
+    let members = parsed["members"].as_array().unwrap();
+    let ci = members.iter().find(|m| m["name"] == "ci").unwrap();
+    assert_eq!(ci["note"], "CI deploy key");
+    let alice = members.iter().find(|m| m["name"] == "alice").unwrap();
+    assert!(alice["note"].is_null());
+
+    // Removing the member drops the note
+    assert_success(&t.cmd().args(["team", "rm", "ci"]).output().unwrap());
+    let config = std::fs::read_to_string(t.dir.path().join(".dugout.toml")).unwrap();
+    assert!(!config.contains("CI deploy key"));
+}
+
+#[test]
+fn test_team_export_import_roster() {
+    let source = Test::init("alice");
+    assert_success(&source.team_add("bob", BOB_PUBLIC_KEY));
+    let output = source
+        .cmd()
+        .args(["team", "export", "--format", "json"])
+        .output()
+        .unwrap();
+    assert_success(&output);
+    let roster = stdout(&output);
+    let parsed: serde_json::Value = serde_json::from_str(&roster).unwrap();
+    assert_eq!(parsed["recipients"]["bob"], BOB_PUBLIC_KEY);
+
+    let t = Test::with_secrets("alice", &[("API_KEY", "v1")]);
+    let (carol_key, _) = generate_age_keypair();
+    assert_success(&t.team_add("carol", &carol_key));
+
+    // Every key is checked before anything changes
+    let bad = t.dir.path().join("bad.json");
+    std::fs::write(
+        &bad,
+        format!(
+            r#"{{"recipients": {{"bob": "{}", "dave": "not-a-key"}}}}"#,
+            BOB_PUBLIC_KEY
+        ),
+    )
+    .unwrap();
+    let output = t
+        .cmd()
+        .args(["team", "import", bad.to_str().unwrap()])
+        .output()
+        .unwrap();
+    assert_failure(&output);
+    assert!(!stdout(&t.team_list()).contains("bob"));
+
+    // Merge keeps carol; the roster's alice has another key, so rewrite it to ours
+    let ours: toml::Value = std::fs::read_to_string(t.dir.path().join(".dugout.toml"))
+        .unwrap()
+        .parse()
+        .unwrap();
+    let alice_key = ours["recipients"]["alice"].as_str().unwrap().to_string();
+    let file = t.dir.path().join("team.toml");
+    std::fs::write(
+        &file,
+        format!(
+            "[recipients]\nalice = \"{}\"\nbob = \"{}\"\n",
+            alice_key, BOB_PUBLIC_KEY
+        ),
+    )
+    .unwrap();
+    let output = t
+        .cmd()
+        .args(["team", "import", file.to_str().unwrap()])
+        .output()
+        .unwrap();
+    assert_success(&output);
+    assert_stdout_contains(&output, "added bob");
+    let list = stdout(&t.team_list());
+    assert!(list.contains("bob") && list.contains("carol"));
+
+    let output = t
+        .cmd()
+        .args(["team", "import", file.to_str().unwrap(), "--replace"])
+        .output()
+        .unwrap();
+    assert_success(&output);
+    assert_stdout_contains(&output, "removed carol");
+    assert!(!stdout(&t.team_list()).contains("carol"));
+    assert_stdout_contains(&t.get("API_KEY"), "v1");
+
+    // The source repo's roster has another alice key, so it can't replace ours
+    let theirs = t.dir.path().join("theirs.json");
+    std::fs::write(&theirs, &roster).unwrap();
+    let output = t
+        .cmd()
+        .args(["team", "import", theirs.to_str().unwrap(), "--replace"])
+        .output()
+        .unwrap();
+    assert_failure(&output);
+    assert_stderr_contains(&output, "doesn't include your key");
+    assert_stdout_contains(&t.get("API_KEY"), "v1");
+}
+
 #[test]
 fn test_team_add_rejects_invalid_member_name() {
     let t = Test::init("alice");
@@ -102,3 +270,75 @@ fn test_team_add_rejects_invalid_member_name() {
     assert_failure(&output);
     assert_stderr_contains(&output, "invalid member name");
 }
+
+#[test]
+fn test_team_add_ssh_key_can_decrypt() {
+    let t = Test::init("alice");
+
+    // Unquoted, as pasted from id_ed25519.pub
+    let mut args = vec!["team", "add", "bob"];
+    args.extend(SSH_PUBLIC_KEY.split(' '));
+    let output = t.cmd().args(&args).output().unwrap();
+    assert_success(&output);
+
+    let output = t.set("API_KEY", "over-ssh");
+    assert_success(&output);
+
+    // The comment is not stored
+    let config = std::fs::read_to_string(t.dir.path().join(".dugout.toml")).unwrap();
+    assert!(config.contains("ssh-ed25519 AAAAC3NzaC1lZDI1NTE5"));
+    assert!(!config.contains("test@dugout"));
+
+    // Bob has only his SSH key: no dugout identity on this machine
+    std::fs::remove_dir_all(t.home.path().join(".dugout")).unwrap();
+    let ssh_dir = t.home.path().join(".ssh");
+    std::fs::create_dir_all(&ssh_dir).unwrap();
+    std::fs::write(ssh_dir.join("id_ed25519"), SSH_PRIVATE_KEY).unwrap();
+    std::fs::write(ssh_dir.join("id_ed25519.pub"), SSH_PUBLIC_KEY).unwrap();
+
+    let output = t.get("API_KEY");
+    assert_success(&output);
+    assert_eq!(stdout(&output).trim(), "over-ssh");
+}
+
+#[test]
+fn test_team_export_age_recipients() {
+    let t = Test::init("alice");
+    assert_success(&t.team_add("bob", BOB_PUBLIC_KEY));
+    let mut args = vec!["team", "add", "carol"];
+    args.extend(SSH_PUBLIC_KEY.split(' '));
+    assert_success(&t.cmd().args(&args).output().unwrap());
+
+    let output = t
+        .cmd()
+        .args(["team", "export", "--format", "age-recipients"])
+        .output()
+        .unwrap();
+    assert_success(&output);
+    let out = stdout(&output);
+    assert!(out.contains("# bob\n"));
+    assert!(out.contains(&format!("{}\n", BOB_PUBLIC_KEY)));
+    assert!(out.contains("# carol\nssh-ed25519 AAAAC3NzaC1lZDI1NTE5"));
+
+    // Every non-comment line is a recipient `age -R` accepts
+    let keys: Vec<&str> = out
+        .lines()
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .collect();
+    assert_eq!(keys.len(), 3);
+    for key in keys {
+        let parsed = key.parse::<age::x25519::Recipient>().is_ok()
+            || key.parse::<age::ssh::Recipient>().is_ok();
+        assert!(parsed, "not an age recipient: {}", key);
+    }
+
+    let output = t
+        .cmd()
+        .args(["team", "export", "--no-comments"])
+        .output()
+        .unwrap();
+    assert_success(&output);
+    let out = stdout(&output);
+    assert!(!out.contains('#'));
+    assert_eq!(out.lines().count(), 3);
+}
@@ -169,3 +169,36 @@ version = "0.1.0"
         "Expected error with no recipients in config"
     );
 }
+
+#[test]
+fn test_load_config_with_merge_conflict_markers() {
+    let _env = setup();
+
+    let conflicted = r#"[dugout]
+version = "0.1.0"
+
+[recipients]
+alice = "age1ql3z7hjy54pw3hyww5ayyfg7zqgvc7w3j2elw8zmrj2kg5sfn9aqmcac8p"
+
+[secrets]
+<<<<<<< HEAD
+API_KEY = "ours"
+=======
+DB_URL = "theirs"
+>>>>>>> feature
+"#;
+    fs::write(".dugout.toml", conflicted).unwrap();
+
+    let Err(err) = Vault::open() else {
+        panic!("expected merge conflict error");
+    };
+    assert!(
+        matches!(
+            err,
+            dugout::error::Error::Config(dugout::error::ConfigError::MergeConflict { line: 8, .. })
+        ),
+        "expected MergeConflict, got: {}",
+        err
+    );
+    assert!(err.to_string().contains("merge conflict"));
+}
@@ -165,7 +165,7 @@ fn test_check_audit_clean() {
     let out_str = stdout(&output);
     let err_str = stderr(&output);
     assert!(
-        out_str.len() > 0 || err_str.len() > 0,
+        !out_str.is_empty() || !err_str.is_empty(),
         "Audit should produce output"
     );
 }
@@ -190,7 +190,7 @@ fn test_check_audit_detects_issues() {
     let stdout = stdout(&output);
     let stderr = stderr(&output);
     let combined = format!("{}{}", stdout, stderr);
-    assert!(combined.len() > 0, "Audit should produce output");
+    assert!(!combined.is_empty(), "Audit should produce output");
 }
 
 #[test]
@@ -290,7 +290,10 @@ fn test_secrets_diff_with_changes() {
 
     let stdout = stdout(&output);
     // Should show some difference indication
-    assert!(stdout.len() > 0, "Diff with changes should produce output");
+    assert!(
+        !stdout.is_empty(),
+        "Diff with changes should produce output"
+    );
 }
 
 #[test]
@@ -400,7 +400,11 @@ fn test_secrets_unlock_roundtrip_unicode() {
     let output = t.secrets_lock();
     assert_success(&output);
 
-    let output = t.secrets_import(".env");
+    let output = t
+        .cmd()
+        .args(["secrets", "import", ".env", "--on-conflict", "overwrite"])
+        .output()
+        .unwrap();
     assert_success(&output);
 
     for (key, value) in &test_cases {
@@ -427,7 +431,11 @@ fn test_secrets_unlock_roundtrip_special_chars() {
     let output = t.secrets_lock();
     assert_success(&output);
 
-    let output = t.secrets_import(".env");
+    let output = t
+        .cmd()
+        .args(["secrets", "import", ".env", "--on-conflict", "overwrite"])
+        .output()
+        .unwrap();
     assert_success(&output);
 
     let output = t.get("QUOTE");
@@ -456,7 +464,11 @@ fn test_secrets_unlock_roundtrip_multiline() {
     let output = t.secrets_lock();
     assert_success(&output);
 
-    let output = t.secrets_import(".env");
+    let output = t
+        .cmd()
+        .args(["secrets", "import", ".env", "--on-conflict", "overwrite"])
+        .output()
+        .unwrap();
     assert_success(&output);
 
     let output = t.get("MULTILINE");
@@ -533,7 +545,17 @@ fn test_secrets_import_export_roundtrip() {
     let output = t.secrets_lock();
     assert_success(&output);
 
-    let output = t.secrets_import("exported.env");
+    let output = t
+        .cmd()
+        .args([
+            "secrets",
+            "import",
+            "exported.env",
+            "--on-conflict",
+            "overwrite",
+        ])
+        .output()
+        .unwrap();
     assert_success(&output);
 
     let output = t.get("UNICODE");
@@ -572,7 +594,7 @@ fn test_null_byte_handling() {
     let t = Test::init("test-user");
 
     let value = "before\\x00after";
-    let output = t.set("ESCAPED_NULL", &value);
+    let output = t.set("ESCAPED_NULL", value);
     assert_success(&output);
 
     let output = t.get("ESCAPED_NULL");
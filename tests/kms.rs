@@ -1,7 +1,9 @@
 //! KMS hybrid mode integration tests.
 //!
 //! Tests the full CLI flow with hybrid encryption.
-//! Uses the mock KMS backend (test builds only).
+//! Uses the mock KMS backend: run with `--features test-kms`.
+
+#![cfg(feature = "test-kms")]
 
 mod support;
 use support::*;
@@ -84,6 +86,97 @@ fn test_hybrid_secrets_are_envelopes() {
     );
 }
 
+#[test]
+fn test_hybrid_status_counts_envelopes() {
+    let t = Test::new();
+
+    t.cmd()
+        .args([
+            "init",
+            "--no-banner",
+            "--name",
+            "alice",
+            "--kms",
+            "arn:aws:kms:us-east-1:123:key/abc",
+        ])
+        .output()
+        .unwrap();
+
+    t.set("KEY_A", "val_a");
+    t.set("KEY_B", "val_b");
+
+    let output = t.check_status();
+    assert_success(&output);
+    assert_stdout_contains(&output, "envelopes");
+    assert_stdout_contains(&output, "2 (aws: 2)");
+}
+
+#[test]
+fn test_mixed_formats_warn_until_synced() {
+    let t = Test::init("alice");
+    assert_success(&t.set("OLD_KEY", "age-only"));
+
+    // Switch the project to hybrid mid-way
+    let path = t.dir.path().join(".dugout.toml");
+    let mut config = std::fs::read_to_string(&path).unwrap();
+    config.push_str("\n[kms]\nkey = \"arn:aws:kms:us-east-1:123:key/abc\"\n");
+    std::fs::write(&path, config).unwrap();
+    assert_success(&t.set("NEW_KEY", "hybrid"));
+
+    let output = t.check_status();
+    assert_success(&output);
+    assert_stdout_contains(&output, "mixed ciphertext formats (1 age, 1 envelope)");
+    assert_stdout_contains(&output, "dugout sync --force");
+
+    let output = t.cmd().args(["check", "audit"]).output().unwrap();
+    assert_success(&output);
+    assert_stdout_contains(&output, "mixed ciphertext formats");
+
+    assert_success(&t.cmd().args(["sync", "--force"]).output().unwrap());
+
+    let output = t.check_status();
+    assert_success(&output);
+    assert_stdout_contains(&output, "2 (aws: 2)");
+    assert_stdout_excludes(&output, "mixed ciphertext formats");
+}
+
+#[test]
+fn test_cipher_change_reported_until_forced_sync() {
+    let t = Test::init("alice");
+    assert_success(&t.set("KEY", "value"));
+
+    let path = t.dir.path().join(".dugout.toml");
+    let config = std::fs::read_to_string(&path).unwrap();
+    assert!(config.contains("last_synced_backend = \"age\""));
+    std::fs::write(
+        &path,
+        format!(
+            "{}\n[kms]\nkey = \"arn:aws:kms:us-east-1:123:key/abc\"\n",
+            config
+        ),
+    )
+    .unwrap();
+
+    let output = t.check_status();
+    assert_success(&output);
+    assert_stdout_contains(&output, "cipher changed from age to hybrid+aws");
+    assert_stdout_contains(&output, "dugout sync --force");
+    assert_stderr_contains(&output, "last synced with the 'age' cipher");
+
+    // The warning must not leak into values printed for scripts
+    let output = t.get("KEY");
+    assert_success(&output);
+    assert_eq!(stdout(&output), "value\n");
+
+    assert_success(&t.cmd().args(["sync", "--force"]).output().unwrap());
+
+    let output = t.check_status();
+    assert_success(&output);
+    assert_stdout_excludes(&output, "cipher changed");
+    let config = std::fs::read_to_string(&path).unwrap();
+    assert!(config.contains("last_synced_backend = \"hybrid+aws\""));
+}
+
 #[test]
 fn test_hybrid_list_works() {
     let t = Test::new();
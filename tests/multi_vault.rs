@@ -47,6 +47,29 @@ fn test_vault_isolation() {
     assert!(!cross_get.status.success());
 }
 
+#[test]
+fn test_status_json_reports_selected_vault() {
+    let t = Test::new();
+    t.init_cmd("alice");
+    t.init_vault("alice", "dev");
+    t.set_vault("dev", "DEV_SECRET", "dev_value");
+    t.set_vault("dev", "OTHER", "x");
+
+    let output = t
+        .cmd()
+        .args(["--vault", "dev", "check", "status", "--json"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let status: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(status["vault"], "dev");
+    assert_eq!(status["cipher"], "age");
+    assert_eq!(status["secret_count"], 2);
+    assert_eq!(status["recipient_count"], 1);
+    assert_eq!(status["needs_sync"], false);
+    assert_eq!(status["has_env"], false);
+}
+
 #[test]
 fn test_vault_list_shows_all_vaults() {
     let t = Test::new();
@@ -70,6 +93,70 @@ fn test_vault_list_shows_all_vaults() {
     assert!(stdout.contains("prod"));
 }
 
+#[test]
+fn test_vault_clone_forks_vault() {
+    let t = Test::new();
+    t.init_vault("alice", "dev");
+    t.set("API_KEY", "dev_value");
+
+    let output = t
+        .cmd()
+        .args(["vault", "clone", "staging"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert!(String::from_utf8_lossy(&output.stdout).contains("cloned vault 'dev' to 'staging'"));
+    assert!(t.dir.path().join(".dugout.staging.toml").exists());
+
+    let get = t.get_vault("staging", "API_KEY");
+    assert!(get.status.success());
+    assert!(String::from_utf8_lossy(&get.stdout).contains("dev_value"));
+
+    // Target must not already exist
+    let output = t
+        .cmd()
+        .args(["--vault", "dev", "vault", "clone", "staging"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+}
+
+#[test]
+fn test_vault_rm_deletes_vault() {
+    let t = Test::new();
+    t.init_cmd("alice");
+    t.init_vault("alice", "dev");
+    let requests = t.dir.path().join(".dugout/requests/dev");
+    std::fs::create_dir_all(&requests).unwrap();
+    std::fs::write(requests.join("bob.pub"), "age1xyz\n").unwrap();
+
+    // Non-interactive without --force is refused
+    let output = t.cmd().args(["vault", "rm", "dev"]).output().unwrap();
+    assert!(!output.status.success());
+    assert!(t.dir.path().join(".dugout.dev.toml").exists());
+
+    let output = t
+        .cmd()
+        .args(["vault", "rm", "dev", "--force"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert!(String::from_utf8_lossy(&output.stdout).contains("removed vault 'dev'"));
+    assert!(!t.dir.path().join(".dugout.dev.toml").exists());
+    assert!(!requests.exists());
+    assert!(t.dir.path().join(".dugout.toml").exists());
+
+    // Files that aren't dugout vaults are left alone
+    std::fs::write(t.dir.path().join(".dugout.notes.toml"), "title = 1\n").unwrap();
+    let output = t
+        .cmd()
+        .args(["vault", "rm", "notes", "--force"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    assert!(t.dir.path().join(".dugout.notes.toml").exists());
+}
+
 #[test]
 fn test_single_vault_no_flag_needed() {
     let t = Test::new();
@@ -216,3 +303,78 @@ fn test_legacy_request_migration() {
         "Legacy request file should be removed after migration"
     );
 }
+
+#[cfg(any(feature = "aws", feature = "test-kms"))]
+#[test]
+fn test_init_applies_vault_profile() {
+    let t = Test::new();
+    t.init_cmd("alice");
+
+    let path = t.dir.path().join(".dugout.toml");
+    let mut config = std::fs::read_to_string(&path).unwrap();
+    config.push_str(
+        "\n[profiles.prod]\ncipher = \"hybrid\"\nkms.key = \"arn:aws:kms:us-east-1:123:key/abc\"\n\n[profiles.dev]\ncipher = \"age\"\n",
+    );
+    std::fs::write(&path, config).unwrap();
+
+    let output = t.init_vault("alice", "prod");
+    assert!(output.status.success());
+    assert!(String::from_utf8_lossy(&output.stdout).contains("hybrid"));
+    let prod = std::fs::read_to_string(t.dir.path().join(".dugout.prod.toml")).unwrap();
+    assert!(prod.contains("[kms]"));
+    assert!(prod.contains("arn:aws:kms:us-east-1:123:key/abc"));
+
+    let output = t.init_vault("alice", "dev");
+    assert!(output.status.success());
+    let dev = std::fs::read_to_string(t.dir.path().join(".dugout.dev.toml")).unwrap();
+    assert!(!dev.contains("[kms]"));
+}
+
+/// Run git in the test directory, switching HEAD to `branch`.
+fn checkout_branch(t: &Test, branch: &str) {
+    if !t.dir.path().join(".git").exists() {
+        let status = std::process::Command::new("git")
+            .args(["init", "-q"])
+            .current_dir(t.dir.path())
+            .status()
+            .expect("failed to run git init");
+        assert!(status.success());
+    }
+    let status = std::process::Command::new("git")
+        .args(["symbolic-ref", "HEAD", &format!("refs/heads/{}", branch)])
+        .current_dir(t.dir.path())
+        .status()
+        .expect("failed to run git symbolic-ref");
+    assert!(status.success());
+}
+
+#[test]
+fn test_vault_inferred_from_git_branch() {
+    let t = Test::new();
+    t.init_cmd("alice");
+    t.init_vault("alice", "prod");
+    t.set_vault("default", "DEFAULT_ONLY", "dev_value");
+    t.set_vault("prod", "PROD_ONLY", "prod_value");
+
+    let path = t.dir.path().join(".dugout.toml");
+    let mut config = std::fs::read_to_string(&path).unwrap();
+    config.push_str("\n[vault_from_branch]\nmain = \"prod\"\n");
+    std::fs::write(&path, config).unwrap();
+
+    checkout_branch(&t, "main");
+    let output = t.get("PROD_ONLY");
+    assert!(output.status.success());
+    assert_eq!(support::stdout(&output).trim(), "prod_value");
+    support::assert_stderr_contains(&output, "vault: prod (from branch main)");
+
+    // An explicit --vault still wins over the branch mapping
+    let output = t.get_vault("default", "DEFAULT_ONLY");
+    assert!(output.status.success());
+
+    checkout_branch(&t, "feature/login");
+    let output = t.get("DEFAULT_ONLY");
+    assert!(output.status.success());
+    assert_eq!(support::stdout(&output).trim(), "dev_value");
+    support::assert_stderr_contains(&output, "no mapping for branch feature/login");
+    assert!(!t.get("PROD_ONLY").status.success());
+}
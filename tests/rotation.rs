@@ -11,19 +11,11 @@ use std::fs;
 
 /// Extract a recipient's public key from config TOML content.
 fn recipient_key(config: &str, name: &str) -> String {
-    for line in config.lines() {
-        let trimmed = line.trim();
-        if trimmed.starts_with(name) && trimmed.contains('=') {
-            return trimmed
-                .split('=')
-                .nth(1)
-                .unwrap()
-                .trim()
-                .trim_matches('"')
-                .to_string();
-        }
-    }
-    panic!("recipient '{}' not found in config", name);
+    let config: toml::Value = config.parse().expect("config should be valid TOML");
+    config["recipients"][name]
+        .as_str()
+        .unwrap_or_else(|| panic!("recipient '{}' not found in config", name))
+        .to_string()
 }
 
 fn read_config(t: &Test) -> String {
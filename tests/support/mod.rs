@@ -7,6 +7,7 @@
 pub mod assertions;
 pub mod commands;
 pub mod fixtures;
+pub mod schema;
 pub mod skip;
 
 #[allow(unused_imports)]
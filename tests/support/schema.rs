@@ -0,0 +1,83 @@
+//! Minimal JSON Schema validator for checking `--json` output.
+//!
+//! Covers only the keywords `dugout schema` emits: `type`, `const`,
+//! `required`, `properties`, `additionalProperties`, `items`, `minimum`.
+
+use serde_json::Value;
+
+/// Validate `value` against `schema`, returning the first violation.
+pub fn validate(schema: &Value, value: &Value) -> Result<(), String> {
+    validate_at("$", schema, value)
+}
+
+fn validate_at(path: &str, schema: &Value, value: &Value) -> Result<(), String> {
+    if let Some(expected) = schema.get("const") {
+        if expected != value {
+            return Err(format!("{}: expected {}, got {}", path, expected, value));
+        }
+    }
+
+    if let Some(ty) = schema.get("type").and_then(Value::as_str) {
+        let ok = match ty {
+            "object" => value.is_object(),
+            "array" => value.is_array(),
+            "string" => value.is_string(),
+            "integer" => value.is_i64() || value.is_u64(),
+            "number" => value.is_number(),
+            "boolean" => value.is_boolean(),
+            "null" => value.is_null(),
+            other => return Err(format!("{}: unsupported type {}", path, other)),
+        };
+        if !ok {
+            return Err(format!("{}: expected {}, got {}", path, ty, value));
+        }
+    }
+
+    if let (Some(min), Some(n)) = (
+        schema.get("minimum").and_then(Value::as_f64),
+        value.as_f64(),
+    ) {
+        if n < min {
+            return Err(format!("{}: {} is below minimum {}", path, n, min));
+        }
+    }
+
+    if let Some(obj) = value.as_object() {
+        let properties = schema.get("properties").and_then(Value::as_object);
+
+        for name in schema
+            .get("required")
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+            .filter_map(Value::as_str)
+        {
+            if !obj.contains_key(name) {
+                return Err(format!("{}: missing required field {}", path, name));
+            }
+        }
+
+        for (name, field) in obj {
+            match properties.and_then(|p| p.get(name)) {
+                Some(sub) => validate_at(&format!("{}.{}", path, name), sub, field)?,
+                None => match schema.get("additionalProperties") {
+                    Some(Value::Bool(false)) => {
+                        return Err(format!("{}: unexpected field {}", path, name));
+                    }
+                    Some(extra) if extra.is_object() => {
+                        validate_at(&format!("{}.{}", path, name), extra, field)?
+                    }
+                    _ => {}
+                },
+            }
+        }
+    }
+
+    if let (Some(items), Some(arr)) = (schema.get("items"), value.as_array()) {
+        for (i, item) in arr.iter().enumerate() {
+            validate_at(&format!("{}[{}]", path, i), items, item)?;
+        }
+    }
+
+    Ok(())
+}
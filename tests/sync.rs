@@ -69,6 +69,7 @@ fn test_sync_dry_run_with_force() {
 
     assert!(output.status.success());
     assert!(stdout.contains("would sync"));
+    assert!(stdout.contains("reason: --force"));
 }
 
 #[test]
@@ -83,6 +84,10 @@ fn test_sync_detects_recipient_change() {
     );
     std::fs::write(t.dir.path().join(".dugout.toml"), modified).unwrap();
 
+    let output = t.cmd().args(["sync", "--dry-run"]).output().unwrap();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("reason: recipients changed since last sync"));
+
     let output = t.cmd().arg("sync").output().unwrap();
     let stdout = String::from_utf8_lossy(&output.stdout);
 
@@ -91,6 +96,29 @@ fn test_sync_detects_recipient_change() {
     assert!(stdout.contains("1 secrets"));
 }
 
+#[test]
+fn test_sync_dry_run_lists_recipient_changes() {
+    let t = Test::with_secrets("alice", &[("API_KEY", "v1"), ("DB_URL", "postgres://")]);
+    let path = t.dir.path().join(".dugout.toml");
+
+    // A recipient arrives through a merge, without a sync
+    let (carol, _) = support::generate_age_keypair();
+    let config = std::fs::read_to_string(&path).unwrap();
+    let modified = config.replace(
+        "[recipients]\n",
+        &format!("[recipients]\ncarol = \"{}\"\n", carol),
+    );
+    assert_ne!(modified, config);
+    std::fs::write(&path, &modified).unwrap();
+
+    let output = t.cmd().args(["sync", "--dry-run"]).output().unwrap();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(output.status.success());
+    assert!(stdout.contains("+ carol"));
+    assert!(stdout.contains("re-encrypt: API_KEY, DB_URL"));
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), modified);
+}
+
 #[test]
 fn test_sync_detects_missing_hash() {
     let t = Test::with_secrets("alice", &[("SECRET", "value")]);
@@ -100,6 +128,10 @@ fn test_sync_detects_missing_hash() {
     let modified = remove_hash_line(&config);
     std::fs::write(t.dir.path().join(".dugout.toml"), modified).unwrap();
 
+    let output = t.cmd().args(["sync", "--dry-run"]).output().unwrap();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("reason: no recipients hash stored"));
+
     let output = t.cmd().arg("sync").output().unwrap();
     let stdout = String::from_utf8_lossy(&output.stdout);
 
@@ -107,6 +139,40 @@ fn test_sync_detects_missing_hash() {
     assert!(stdout.contains("synced"));
 }
 
+#[test]
+fn test_fix_hash_repairs_missing_hash_without_reencrypting() {
+    let t = Test::with_secrets("alice", &[("SECRET", "value")]);
+    let path = t.dir.path().join(".dugout.toml");
+    let config = std::fs::read_to_string(&path).unwrap();
+    std::fs::write(&path, remove_hash_line(&config)).unwrap();
+
+    // Status flags the missing hash and points at the repair
+    let output = t.cmd().args(["check", "status"]).output().unwrap();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(output.status.success());
+    assert!(stdout.contains("recipients_hash is missing"));
+    assert!(stdout.contains("dugout secrets fix-hash"));
+
+    let before = std::fs::read_to_string(&path).unwrap();
+    t.cmd()
+        .args(["secrets", "fix-hash"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("recipients hash rewritten"));
+
+    // Only the hash line changed
+    let after = std::fs::read_to_string(&path).unwrap();
+    assert!(after.contains("recipients_hash"));
+    assert_eq!(remove_hash_line(&after).trim(), before.trim());
+
+    // needs_sync is now false
+    let output = t.cmd().args(["sync", "--dry-run"]).output().unwrap();
+    assert!(String::from_utf8_lossy(&output.stdout).contains("already in sync"));
+
+    let output = t.cmd().args(["check", "status"]).output().unwrap();
+    assert!(!String::from_utf8_lossy(&output.stdout).contains("recipients_hash is missing"));
+}
+
 #[test]
 fn test_sync_preserves_secret_values() {
     let t = Test::with_secrets(
@@ -3,6 +3,8 @@
 //! These tests verify the Vault API works correctly through the public interface.
 //! Unit tests in src/core/vault.rs already cover crypto roundtrips.
 
+use dugout::OnConflict;
+use dugout::SetOutcome;
 use dugout::Vault;
 use std::env;
 use std::fs;
@@ -98,8 +100,8 @@ fn test_vault_import() {
     // Create a test .env file
     fs::write("test.env", "IMPORT1=value1\nIMPORT2=value2\n").unwrap();
 
-    let imported = env.vault.import("test.env").unwrap();
-    assert_eq!(imported.len(), 2);
+    let imported = env.vault.import("test.env", OnConflict::Error).unwrap();
+    assert_eq!(imported.created.len(), 2);
 
     assert_eq!(env.vault.get("IMPORT1").unwrap().as_str(), "value1");
     assert_eq!(env.vault.get("IMPORT2").unwrap().as_str(), "value2");
@@ -280,11 +282,13 @@ fn test_vault_list_returns_secrets() {
 }
 
 #[test]
-fn test_vault_set_returns_secret() {
+fn test_vault_set_returns_outcome() {
     let mut env = setup();
 
-    let secret = env.vault.set("RETURN_KEY", "return_value", false).unwrap();
+    let outcome = env.vault.set("RETURN_KEY", "return_value", false).unwrap();
+    assert_eq!(outcome, SetOutcome::Created);
 
-    // Verify set() returns the Secret object with correct key
-    assert_eq!(secret.key(), "RETURN_KEY");
+    // Setting the same value again writes nothing
+    let outcome = env.vault.set("RETURN_KEY", "return_value", true).unwrap();
+    assert_eq!(outcome, SetOutcome::Unchanged);
 }